@@ -183,6 +183,17 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// The time without status updates after which the subscription is idle. When all
+    /// subscriptions are taken, an idle one is evicted with an error, preferring
+    /// the transactions unknown to the `TxPool`, then the least recently updated.
+    #[clap(long = "tx-subscription-idle-timeout", default_value = "1m", env)]
+    pub tx_subscription_idle_timeout: humantime::Duration,
+
+    /// The max number of the prevalidated transactions, submitted by the trusted
+    /// submitters, that the `TxPool` can simultaneously store.
+    #[clap(long = "tx-max-prevalidated-number", default_value = "1016", env)]
+    pub tx_max_prevalidated_number: usize,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min_connected_reserved_peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -194,6 +205,98 @@ pub struct Command {
     /// Time to wait after submitting a query before debug info will be logged about query.
     #[clap(long = "query_log_threshold_time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
+
+    /// The max number of submitted transactions that can wait for the `TxPool` at once.
+    /// Submissions above this limit are rejected with the `NODE_BUSY` error.
+    #[clap(long = "submission-queue-depth", default_value = "1024", env)]
+    pub submission_queue_depth: usize,
+
+    /// The max number of GraphQL queries one IP can make per minute.
+    #[clap(long = "max-queries-per-minute", env)]
+    pub max_queries_per_minute: Option<u32>,
+
+    /// The max number of GraphQL mutations one IP can make per minute.
+    /// Mutations above this limit are rejected with the `RATE_LIMITED` error.
+    #[clap(long = "max-mutations-per-minute", env)]
+    pub max_mutations_per_minute: Option<u32>,
+
+    /// The max number of the latest blocks the `feeStatistics` query can scan.
+    #[clap(long = "max-fee-statistics-blocks", default_value = "1000", env)]
+    pub max_fee_statistics_blocks: u32,
+
+    /// Allows the GraphQL `resubmit` mutation to restore the pending transactions
+    /// after a restart. Should be used by the operators of the node only.
+    #[arg(long = "resubmit-enabled", env)]
+    pub resubmit_enabled: bool,
+
+    /// The maximum time of the script execution in the GraphQL `dry_run`.
+    /// The dry-runs that take longer fail, while the VM finishes the execution
+    /// in the background. Unlimited if not set.
+    #[arg(long = "dry-run-timeout", env)]
+    pub dry_run_timeout: Option<humantime::Duration>,
+
+    /// The max number of GraphQL `dry_run` and `dry_run_many` requests executing at once.
+    /// The requests above this limit are rejected with the `NODE_BUSY` error.
+    #[arg(long = "max-concurrent-dry-runs", default_value = "64", env)]
+    pub max_concurrent_dry_runs: usize,
+
+    /// The max total size in bytes of the transactions in one page of the GraphQL
+    /// transaction queries. The page ends early once the next transaction doesn't fit.
+    /// Only the serialized transactions are counted, not their receipts.
+    #[arg(long = "max-page-bytes", env)]
+    pub max_page_bytes: Option<usize>,
+
+    /// The max number of receipts returned by the GraphQL `receipts` of the transaction.
+    /// Longer lists are truncated, and the rest is available from `receiptsConnection`.
+    #[arg(long = "max-receipts", env)]
+    pub max_receipts: Option<usize>,
+
+    /// The interval of the keep-alive messages sent over the idle GraphQL
+    /// subscriptions, so the proxies don't close them.
+    #[arg(long = "subscription-keep-alive-interval", default_value = "15s", env)]
+    pub subscription_keep_alive_interval: humantime::Duration,
+
+    /// Allows the GraphQL `dryRunTrace` and `dryRunCoverage` mutations that record
+    /// each instruction executed by the VM. It is expensive, so it should be enabled
+    /// for the debugging only.
+    #[arg(long = "dry-run-trace-enabled", env)]
+    pub dry_run_trace_enabled: bool,
+
+    /// Allows the test witnesses in the GraphQL `dryRun` and `predicatesValid`, which
+    /// replace the witnesses of the transaction to test the predicates verifying
+    /// the signed messages. For the testing only, never enable it in production.
+    #[arg(long = "test-witnesses-enabled", env)]
+    pub test_witnesses_enabled: bool,
+
+    /// The max gas of one predicate in the GraphQL `estimatePredicates`. Lower than
+    /// the `max_gas_per_predicate` of the chain to protect a public node.
+    #[arg(long = "estimate-predicates-gas-limit", env)]
+    pub estimate_predicates_gas_limit: Option<u64>,
+
+    /// Indexes the transactions by the called contracts for the GraphQL
+    /// `transactionsByContract` query. It slows down the block commit and uses more
+    /// storage, so it should be enabled for the explorers only.
+    #[arg(long = "contract-transactions-index-enabled", env)]
+    pub contract_transactions_index_enabled: bool,
+
+    /// The max size in bytes of the transaction accepted by the GraphQL API.
+    /// Defaults to the largest size of a valid transaction of the chain.
+    #[arg(long = "max-tx-size", env)]
+    pub max_tx_size: Option<usize>,
+
+    /// The time the transaction status stream of the embedded service waits for
+    /// the unknown transaction to appear in the database or the `TxPool`. The stream
+    /// ends with an error when it elapses. Waits forever if not set.
+    #[arg(long = "tx-status-grace-period", env)]
+    pub tx_status_grace_period: Option<humantime::Duration>,
+
+    /// The comma-separated IP addresses of the clients allowed to call the GraphQL
+    /// `submitPrevalidated` mutation, which skips the verification of the signatures
+    /// and predicates. Only the own infrastructure of the operator should be listed.
+    /// It is compared with the peer address of the connection, so listing a reverse
+    /// proxy trusts every client behind it.
+    #[arg(long = "trusted-submitters", value_delimiter = ',', env)]
+    pub trusted_submitters: Vec<net::IpAddr>,
 }
 
 impl Command {
@@ -227,9 +330,28 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_subscription_idle_timeout,
+            tx_max_prevalidated_number,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
+            submission_queue_depth,
+            max_queries_per_minute,
+            max_mutations_per_minute,
+            max_fee_statistics_blocks,
+            resubmit_enabled,
+            dry_run_timeout,
+            max_concurrent_dry_runs,
+            max_page_bytes,
+            max_receipts,
+            subscription_keep_alive_interval,
+            dry_run_trace_enabled,
+            test_witnesses_enabled,
+            estimate_predicates_gas_limit,
+            contract_transactions_index_enabled,
+            max_tx_size,
+            tx_status_grace_period,
+            trusted_submitters,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
@@ -305,6 +427,8 @@ impl Command {
                 metrics,
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
+                tx_subscription_idle_timeout.into(),
+                tx_max_prevalidated_number,
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
@@ -325,6 +449,23 @@ impl Command {
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            submission_queue_depth,
+            max_queries_per_minute,
+            max_mutations_per_minute,
+            max_fee_statistics_blocks,
+            resubmit_enabled,
+            dry_run_timeout: dry_run_timeout.map(Into::into),
+            max_concurrent_dry_runs,
+            max_page_bytes,
+            max_receipts,
+            subscription_keep_alive_interval: subscription_keep_alive_interval.into(),
+            dry_run_trace_enabled,
+            test_witnesses_enabled,
+            estimate_predicates_gas_limit,
+            contract_transactions_index_enabled,
+            max_tx_size,
+            tx_status_grace_period: tx_status_grace_period.map(Into::into),
+            trusted_submitters,
         })
     }
 }
@@ -6,7 +6,14 @@ use crate::client::{
             SpendQueryElementInput,
         },
         contract::ContractBalanceQueryArgs,
-        tx::DryRunArg,
+        tx::{
+            DryRunArg,
+            DryRunAtTimeArg,
+            DryRunWithGasLimitArg,
+            DryRunWithTestWitnessesArg,
+            TestWitness,
+            TxWithTestWitnessesArg,
+        },
         Tai64Timestamp,
         TransactionId,
     },
@@ -32,6 +39,7 @@ use cynic::{
 use fuel_core_types::{
     fuel_asm::{
         Instruction,
+        PanicReason,
         RegisterId,
         Word,
     },
@@ -113,6 +121,10 @@ pub mod pagination;
 pub mod schema;
 pub mod types;
 
+/// The media type of the CBOR encoding of the responses, requested
+/// by [`FuelClient::query_cbor`].
+const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
 #[derive(Debug, Clone)]
 pub struct FuelClient {
     client: reqwest::Client,
@@ -169,6 +181,16 @@ pub fn from_strings_errors_to_std_error(errors: Vec<String>) -> io::Error {
     io::Error::new(io::ErrorKind::Other, e)
 }
 
+fn to_test_witnesses(test_witnesses: &[(u32, Vec<u8>)]) -> Vec<TestWitness> {
+    test_witnesses
+        .iter()
+        .map(|(index, data)| TestWitness {
+            index: (*index).into(),
+            data: HexString(Bytes(data.clone())),
+        })
+        .collect()
+}
+
 impl FuelClient {
     pub fn new(url: impl AsRef<str>) -> anyhow::Result<Self> {
         Self::from_str(url.as_ref())
@@ -193,6 +215,36 @@ impl FuelClient {
         Self::decode_response(response)
     }
 
+    /// Send the GraphQL query to the client, and receive the response encoded as CBOR.
+    /// The data is the same as of [`Self::query`], but the encoding is more compact
+    /// and faster to parse, which matters for the large pages of transactions.
+    pub async fn query_cbor<ResponseData, Vars>(
+        &self,
+        q: Operation<ResponseData, Vars>,
+    ) -> io::Result<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let body = self
+            .client
+            .post(self.url.clone())
+            .header(reqwest::header::ACCEPT, CBOR_CONTENT_TYPE)
+            .json(&q)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .bytes()
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let response: GraphQlResponse<ResponseData> =
+            ciborium::de::from_reader(body.as_ref())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        Self::decode_response(response)
+    }
+
     fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
@@ -331,6 +383,13 @@ impl FuelClient {
         self.query(query).await.map(|r| r.chain.into())
     }
 
+    /// Get the parameters of the chain used by the node, including the chain id
+    /// used to compute the ids of the transactions.
+    pub async fn chain_parameters(&self) -> io::Result<types::ChainParameters> {
+        let query = schema::chain::ChainParametersQuery::build(());
+        self.query(query).await.map(|r| r.chain_parameters.into())
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(&self, tx: &Transaction) -> io::Result<Vec<Receipt>> {
         self.dry_run_opt(tx, None).await
@@ -355,182 +414,850 @@ impl FuelClient {
             .collect()
     }
 
-    /// Estimate predicates for the transaction
-    pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
-        let serialized_tx = tx.to_bytes();
-        let query = schema::tx::EstimatePredicates::build(TxArg {
-            tx: HexString(Bytes(serialized_tx)),
+    /// Dry run that fetches the receipts in their canonical serialization, the same
+    /// bytes that the node computes the receipts root from, and decodes them.
+    pub async fn dry_run_raw(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<Receipt>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunRaw::build(DryRunArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
         });
-        let tx_with_predicate = self.query(query).await.map(|r| r.estimate_predicates)?;
-        let tx_with_predicate: Transaction = tx_with_predicate.try_into()?;
-        *tx = tx_with_predicate;
-        Ok(())
+        let receipts = self.query(query).await.map(|r| r.dry_run)?;
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect()
     }
 
-    pub async fn submit(
+    /// Dry run with the gas limit of the transaction overridden by `gas_limit`
+    pub async fn dry_run_with_gas_limit(
         &self,
         tx: &Transaction,
-    ) -> io::Result<types::primitives::TransactionId> {
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_limit: u64,
+    ) -> io::Result<Vec<Receipt>> {
         let tx = tx.clone().to_bytes();
-        let query = schema::tx::Submit::build(TxArg {
+        let query = schema::tx::DryRunWithGasLimit::build(DryRunWithGasLimitArg {
             tx: HexString(Bytes(tx)),
+            utxo_validation,
+            gas_limit: Some(gas_limit.into()),
         });
-
-        let id = self.query(query).await.map(|r| r.submit)?.id.into();
-        Ok(id)
+        let receipts = self.query(query).await.map(|r| r.dry_run)?;
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect()
     }
 
-    /// Submit the transaction and wait for it either to be included in
-    /// a block or removed from `TxPool`.
-    ///
-    /// This will wait forever if needed, so consider wrapping this call
-    /// with a `tokio::time::timeout`.
-    #[cfg(feature = "subscriptions")]
-    pub async fn submit_and_await_commit(
+    /// Dry run with the time of the simulated block set to `block_time`
+    pub async fn dry_run_at_time(
         &self,
         tx: &Transaction,
-    ) -> io::Result<TransactionStatus> {
-        use cynic::SubscriptionBuilder;
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        block_time: Tai64,
+    ) -> io::Result<Vec<Receipt>> {
         let tx = tx.clone().to_bytes();
-        let s = schema::tx::SubmitAndAwaitSubscription::build(TxArg {
+        let query = schema::tx::DryRunAtTime::build(DryRunAtTimeArg {
             tx: HexString(Bytes(tx)),
+            utxo_validation,
+            block_time: Some(Tai64Timestamp(block_time)),
         });
-
-        let mut stream = self.subscribe(s).await?.map(
-            |r: io::Result<schema::tx::SubmitAndAwaitSubscription>| {
-                let status: TransactionStatus = r?.submit_and_await.try_into()?;
-                Result::<_, io::Error>::Ok(status)
-            },
-        );
-
-        let status = stream.next().await.ok_or(io::Error::new(
-            io::ErrorKind::Other,
-            "Failed to get status from the submission",
-        ))??;
-
-        Ok(status)
+        let receipts = self.query(query).await.map(|r| r.dry_run)?;
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect()
     }
 
-    pub async fn start_session(&self) -> io::Result<String> {
-        let query = schema::StartSession::build(());
+    /// Dry run with the witnesses of the transaction replaced by the `test_witnesses`,
+    /// the pairs of the index and the data of the witness. The node must allow
+    /// the test witnesses in its configuration.
+    pub async fn dry_run_with_test_witnesses(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        test_witnesses: &[(u32, Vec<u8>)],
+    ) -> io::Result<Vec<Receipt>> {
+        let tx = tx.clone().to_bytes();
+        let query =
+            schema::tx::DryRunWithTestWitnesses::build(DryRunWithTestWitnessesArg {
+                tx: HexString(Bytes(tx)),
+                utxo_validation,
+                test_witnesses: Some(to_test_witnesses(test_witnesses)),
+            });
+        let receipts = self.query(query).await.map(|r| r.dry_run)?;
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect()
+    }
 
-        self.query(query)
+    /// Dry run with options to override the node behavior, and decode the reason
+    /// if the transaction reverted or panicked
+    pub async fn dry_run_detailed(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<types::DryRunDetails> {
+        self.dry_run_detailed_for_owner(tx, utxo_validation, None)
             .await
-            .map(|r| r.start_session.into_inner())
     }
 
-    pub async fn end_session(&self, id: &str) -> io::Result<bool> {
-        let query = schema::EndSession::build(IdArg { id: id.into() });
-
-        self.query(query).await.map(|r| r.end_session)
+    /// Dry run like `dry_run_detailed`, and report the net change of each asset
+    /// of the `owner` if it is set
+    pub async fn dry_run_detailed_for_owner(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        owner: Option<&Address>,
+    ) -> io::Result<types::DryRunDetails> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunDetailed::build(schema::tx::DryRunDetailedArgs {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+            owner: owner.map(|owner| (*owner).into()),
+            find_min_gas: None,
+        });
+        let details = self.query(query).await.map(|r| r.dry_run_detailed)?;
+        details.try_into().map_err(Into::into)
     }
 
-    pub async fn reset(&self, id: &str) -> io::Result<bool> {
-        let query = schema::Reset::build(IdArg { id: id.into() });
-
-        self.query(query).await.map(|r| r.reset)
+    /// Dry run like `dry_run_detailed`, and search the minimum gas limit of the script
+    /// with which the dry-run succeeds
+    pub async fn dry_run_find_min_gas(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<types::DryRunDetails> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunDetailed::build(schema::tx::DryRunDetailedArgs {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+            owner: None,
+            find_min_gas: Some(true),
+        });
+        let details = self.query(query).await.map(|r| r.dry_run_detailed)?;
+        details.try_into().map_err(Into::into)
     }
 
-    pub async fn execute(&self, id: &str, op: &Instruction) -> io::Result<bool> {
-        let op = serde_json::to_string(op)?;
-        let query = schema::Execute::build(schema::ExecuteArgs { id: id.into(), op });
-
-        self.query(query).await.map(|r| r.execute)
+    /// Dry run with options to override the node behavior, and return the logs
+    /// annotated with the contract and the call depth that emitted them
+    pub async fn dry_run_events(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<types::DecodedEvent>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunEvents::build(DryRunArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+        });
+        let events = self.query(query).await.map(|r| r.dry_run_events)?;
+        Ok(events.into_iter().map(Into::into).collect())
     }
 
-    pub async fn register(&self, id: &str, register: RegisterId) -> io::Result<Word> {
-        let query = schema::Register::build(RegisterArgs {
-            id: id.into(),
-            register: register.into(),
+    /// Dry run with options to override the node behavior, and return the touched
+    /// contract storage slots with their values before and after the execution
+    pub async fn dry_run_storage_delta(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<types::StorageSlotDelta>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunStorageDelta::build(DryRunArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
         });
-
-        Ok(self.query(query).await?.register.0 as Word)
+        let deltas = self.query(query).await.map(|r| r.dry_run_storage_delta)?;
+        Ok(deltas.into_iter().map(Into::into).collect())
     }
 
-    pub async fn memory(
+    /// Simulate the block made of the `txs` without committing it. The `txs` that
+    /// don't fit into the gas limit of the block are reported as excluded.
+    pub async fn simulate_block(
         &self,
-        id: &str,
-        start: usize,
-        size: usize,
-    ) -> io::Result<Vec<u8>> {
-        let query = schema::Memory::build(MemoryArgs {
-            id: id.into(),
-            start: start.into(),
-            size: size.into(),
+        txs: &[Transaction],
+        height: Option<u32>,
+    ) -> io::Result<types::BlockSimulation> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::SimulateBlock::build(schema::tx::SimulateBlockArg {
+            txs,
+            height: height.map(Into::into),
         });
-
-        let memory = self.query(query).await?.memory;
-
-        Ok(serde_json::from_str(memory.as_str())?)
+        let simulation = self.query(query).await.map(|r| r.simulate_block)?;
+        simulation.try_into().map_err(Into::into)
     }
 
-    pub async fn set_breakpoint(
+    /// Dry run the transaction where any call to the `fail_contract` reverts.
+    /// It only affects this dry run.
+    pub async fn dry_run_with_forced_failure(
         &self,
-        session_id: &str,
-        contract: fuel_types::ContractId,
-        pc: u64,
-    ) -> io::Result<()> {
-        let operation = SetBreakpoint::build(SetBreakpointArgs {
-            id: Id::new(session_id),
-            bp: schema::Breakpoint {
-                contract: contract.into(),
-                pc: U64(pc),
+        tx: &Transaction,
+        fail_contract: &ContractId,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<Receipt>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunWithForcedFailure::build(
+            schema::tx::DryRunWithForcedFailureArg {
+                tx: HexString(Bytes(tx)),
+                fail_contract: (*fail_contract).into(),
+                utxo_validation,
             },
-        });
-
-        let response = self.query(operation).await?;
-        assert!(
-            response.set_breakpoint,
-            "Setting breakpoint returned invalid reply"
         );
-        Ok(())
+        let receipts = self
+            .query(query)
+            .await
+            .map(|r| r.dry_run_with_forced_failure)?;
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect()
     }
 
-    pub async fn set_single_stepping(
+    /// Dry run the `txs` one after another as if they were included into the same
+    /// block. Returns the receipts of each transaction in the order of `txs`.
+    ///
+    /// The transactions are executed in the order of `txs`, unless
+    /// `sort_by_dependencies` is set, in which case the spenders of outputs of other
+    /// transactions from the batch are executed after them.
+    pub async fn dry_run_many(
         &self,
-        session_id: &str,
-        enable: bool,
-    ) -> io::Result<()> {
-        let operation = SetSingleStepping::build(SetSingleSteppingArgs {
-            id: Id::new(session_id),
-            enable,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        sort_by_dependencies: Option<bool>,
+    ) -> io::Result<Vec<Vec<Receipt>>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::DryRunMany::build(schema::tx::DryRunManyArg {
+            txs,
+            utxo_validation,
+            sort_by_dependencies,
         });
-        self.query(operation).await?;
-        Ok(())
+        let results = self.query(query).await.map(|r| r.dry_run_many)?;
+        results
+            .into_iter()
+            .map(|receipts| {
+                receipts
+                    .into_iter()
+                    .map(|receipt| receipt.try_into().map_err(Into::into))
+                    .collect()
+            })
+            .collect()
     }
 
-    pub async fn start_tx(
+    /// Dry run each of the `txs` independently against the same state, so they don't
+    /// see the changes of each other. Returns the receipts of each transaction
+    /// in the order of `txs`.
+    pub async fn dry_run_parallel(
         &self,
-        session_id: &str,
-        tx: &Transaction,
-    ) -> io::Result<RunResult> {
-        let operation = StartTx::build(StartTxArgs {
-            id: Id::new(session_id),
-            tx: serde_json::to_string(tx).expect("Couldn't serialize tx to json"),
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<Vec<Receipt>>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::DryRunParallel::build(schema::tx::DryRunParallelArg {
+            txs,
+            utxo_validation,
         });
-        let response = self.query(operation).await?.start_tx;
-        Ok(response)
+        let results = self.query(query).await.map(|r| r.dry_run_parallel)?;
+        results
+            .into_iter()
+            .map(|receipts| {
+                receipts
+                    .into_iter()
+                    .map(|receipt| receipt.try_into().map_err(Into::into))
+                    .collect()
+            })
+            .collect()
     }
 
-    pub async fn continue_tx(&self, session_id: &str) -> io::Result<RunResult> {
-        let operation = ContinueTx::build(ContinueTxArgs {
-            id: Id::new(session_id),
+    /// Dry run the transaction and return the overflowing instruction
+    /// if it ended with an arithmetic overflow panic
+    pub async fn dry_run_arithmetic_check(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<Option<types::ArithmeticPanic>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunArithmeticCheck::build(TxArg {
+            tx: HexString(Bytes(tx)),
         });
-        let response = self.query(operation).await?.continue_tx;
-        Ok(response)
+        let panic = self
+            .query(query)
+            .await
+            .map(|r| r.dry_run_arithmetic_check)?;
+        Ok(panic.map(Into::into))
     }
 
-    pub async fn transaction(
+    /// Dry run the transaction and summarize its effect for the `owner`
+    pub async fn dry_run_summary(
+        &self,
+        tx: &Transaction,
+        owner: &Address,
+    ) -> io::Result<types::TransactionSummary> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunSummary::build(schema::tx::DryRunSummaryArgs {
+            tx: HexString(Bytes(tx)),
+            owner: (*owner).into(),
+        });
+        let summary = self.query(query).await.map(|r| r.dry_run_summary)?;
+        Ok(summary.into())
+    }
+
+    /// Dry run with options to override the node behavior, and return the tree
+    /// of the calls with the transfers and the logs of each call
+    pub async fn dry_run_execution_graph(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<types::ExecutionGraph> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunExecutionGraph::build(DryRunArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+        });
+        let graph = self.query(query).await.map(|r| r.dry_run_execution_graph)?;
+        Ok(graph.into())
+    }
+
+    /// Dry run the script and record each executed instruction, with the registers
+    /// before the instructions at the `snapshot_at` indexes. The node must enable
+    /// the tracing
+    pub async fn dry_run_trace(
+        &self,
+        tx: &Transaction,
+        snapshot_at: Option<&[u32]>,
+    ) -> io::Result<types::ExecutionTrace> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunTrace::build(schema::tx::DryRunTraceArgs {
+            tx: HexString(Bytes(tx)),
+            snapshot_at: snapshot_at
+                .map(|indexes| indexes.iter().copied().map(Into::into).collect()),
+        });
+        let trace = self.query(query).await.map(|r| r.dry_run_trace)?;
+        trace.try_into().map_err(Into::into)
+    }
+
+    /// Dry run the script and return the ranges of the code executed by each called
+    /// contract. The node must enable the tracing
+    pub async fn dry_run_coverage(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::ExecutionCoverage> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunCoverage::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let coverage = self.query(query).await.map(|r| r.dry_run_coverage)?;
+        Ok(coverage.into())
+    }
+
+    /// Estimate predicates for the transaction
+    pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
+        let serialized_tx = tx.to_bytes();
+        let query = schema::tx::EstimatePredicates::build(TxArg {
+            tx: HexString(Bytes(serialized_tx)),
+        });
+        let tx_with_predicate = self.query(query).await.map(|r| r.estimate_predicates)?;
+        let tx_with_predicate: Transaction = tx_with_predicate.try_into()?;
+        *tx = tx_with_predicate;
+        Ok(())
+    }
+
+    /// Estimate predicates for the transaction like `estimate_predicates`, but keep
+    /// the non-zero `predicate_gas_used` of the inputs as they are
+    pub async fn estimate_missing_predicates(
+        &self,
+        tx: &mut Transaction,
+    ) -> io::Result<()> {
+        let query = schema::tx::EstimateMissingPredicates::build(
+            schema::tx::EstimateMissingPredicatesArgs {
+                tx: HexString(Bytes(tx.to_bytes())),
+                only_missing: Some(true),
+            },
+        );
+        let tx_with_predicate = self.query(query).await.map(|r| r.estimate_predicates)?;
+        *tx = tx_with_predicate.try_into()?;
+        Ok(())
+    }
+
+    /// Estimate predicates for the transaction and the gas of the verification
+    /// of its inputs, including the signature checks
+    pub async fn estimate_predicates_and_fees(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::PredicatesAndFeesEstimate> {
+        let query = schema::tx::EstimatePredicatesAndFees::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        let estimate = self
+            .query(query)
+            .await
+            .map(|r| r.estimate_predicates_and_fees)?;
+        Ok(estimate.try_into()?)
+    }
+
+    /// Check whether the predicates of the transaction allow spending of its inputs
+    pub async fn predicates_valid(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::PredicateValidation> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::PredicatesValid::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let validation = self.query(query).await.map(|r| r.predicates_valid)?;
+        validation.try_into().map_err(Into::into)
+    }
+
+    /// Check the predicates like `predicates_valid`, with the witnesses of
+    /// the transaction replaced by the `test_witnesses`, the pairs of the index and
+    /// the data of the witness. The node must allow the test witnesses in its
+    /// configuration.
+    pub async fn predicates_valid_with_test_witnesses(
+        &self,
+        tx: &Transaction,
+        test_witnesses: &[(u32, Vec<u8>)],
+    ) -> io::Result<types::PredicateValidation> {
+        let tx = tx.clone().to_bytes();
+        let query =
+            schema::tx::PredicatesValidWithTestWitnesses::build(TxWithTestWitnessesArg {
+                tx: HexString(Bytes(tx)),
+                test_witnesses: Some(to_test_witnesses(test_witnesses)),
+            });
+        let validation = self.query(query).await.map(|r| r.predicates_valid)?;
+        validation.try_into().map_err(Into::into)
+    }
+
+    /// Decode the transaction by the node without submitting it, returning
+    /// the parsed fields and the id computed by the node
+    pub async fn decode_transaction(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::DecodedTransaction> {
+        let query = schema::tx::DecodeTransactionQuery::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        let decoded = self.query(query).await?.decode_transaction;
+        Ok(decoded.try_into()?)
+    }
+
+    /// Validate the transaction without submitting it, returning all the failures
+    pub async fn validate_transaction(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<Vec<types::ValidationFailure>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::ValidateTransaction::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let failures = self.query(query).await.map(|r| r.validate_transaction)?;
+        Ok(failures.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the state of the UTXO consumed by each coin and message input
+    /// of the `tx`: unspent, spent, or reserved by a pending transaction.
+    pub async fn transaction_input_status(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<Vec<types::TransactionInputStatus>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::TransactionInputStatusQuery::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+        let statuses = self.query(query).await?.transaction_input_status;
+        Ok(statuses.into_iter().map(Into::into).collect())
+    }
+
+    pub async fn submit(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::Submit::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let id = self.query(query).await.map(|r| r.submit)?.id.into();
+        Ok(id)
+    }
+
+    /// Submit the transaction with the `idempotency_key`. Repeated submissions
+    /// with the same key return the result of the first one.
+    pub async fn submit_with_idempotency_key(
+        &self,
+        tx: &Transaction,
+        idempotency_key: &str,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitWithIdempotencyKey::build(
+            schema::tx::SubmitWithIdempotencyKeyArgs {
+                tx: HexString(Bytes(tx)),
+                idempotency_key: Some(idempotency_key.to_owned()),
+            },
+        );
+
+        let id = self.query(query).await.map(|r| r.submit)?.id.into();
+        Ok(id)
+    }
+
+    /// Submit the transaction and return the outcome of its insertion into the `TxPool`:
+    /// whether it was newly inserted or already known, and which transactions it evicted.
+    pub async fn submit_with_status(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::InsertionStatus> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitWithStatus::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let status = self.query(query).await.map(|r| r.submit)?.insertion.into();
+        Ok(status)
+    }
+
+    /// Submit the already validated transaction, skipping the verification of its
+    /// signatures and predicates by the `TxPool`. Allowed only for the clients
+    /// listed in the `trusted_submitters` of the node.
+    pub async fn submit_prevalidated(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::InsertionStatus> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitPrevalidated::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let status = self
+            .query(query)
+            .await
+            .map(|r| r.submit_prevalidated)?
+            .insertion
+            .into();
+        Ok(status)
+    }
+
+    /// Submit the transaction after its dry run, and return the receipts of
+    /// the dry run with the outcome of the insertion. The receipts are a preview,
+    /// the state may change before the inclusion of the transaction.
+    pub async fn submit_with_preview(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::SubmissionWithPreview> {
+        let tx = tx.clone().to_bytes();
+        let query =
+            schema::tx::SubmitWithPreview::build(schema::tx::SubmitWithPreviewArgs {
+                tx: HexString(Bytes(tx)),
+                preview: Some(true),
+            });
+        let result = self.query(query).await.map(|r| r.submit)?;
+        result.try_into().map_err(Into::into)
+    }
+
+    /// Dry run the transaction and submit it, unless `require_success` is set
+    /// and the dry run reverted. Returns the receipts of the dry run and
+    /// the outcome of the insertion if the transaction was submitted.
+    pub async fn dry_run_then_submit(
+        &self,
+        tx: &Transaction,
+        require_success: Option<bool>,
+    ) -> io::Result<types::DryRunThenSubmitResult> {
+        let tx = tx.clone().to_bytes();
+        let query =
+            schema::tx::DryRunThenSubmit::build(schema::tx::DryRunThenSubmitArgs {
+                tx: HexString(Bytes(tx)),
+                require_success,
+            });
+        let result = self.query(query).await.map(|r| r.dry_run_then_submit)?;
+        result.try_into().map_err(Into::into)
+    }
+
+    /// Replace the pending transactions that spend any of the inputs of the `tx`
+    /// with the `tx`, which must have a higher gas price. Returns the ids of the
+    /// evicted transactions.
+    pub async fn replace_transaction(&self, tx: &Transaction) -> io::Result<Vec<TxId>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::ReplaceTransaction::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let evicted = self.query(query).await?.replace_transaction;
+        Ok(evicted.into_iter().map(Into::into).collect())
+    }
+
+    /// Submit the batch of transactions in the given order and return the outcome
+    /// of each of them: newly inserted, duplicate within the batch, already known,
+    /// or rejected with the reason.
+    pub async fn submit_many(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<types::BatchSubmissionOutcome>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::SubmitMany::build(schema::tx::SubmitManyArgs { txs });
+
+        let outcomes = self.query(query).await?.submit_many;
+        Ok(outcomes.into_iter().map(Into::into).collect())
+    }
+
+    /// Re-insert the previously pending `txs` into the `TxPool` after a restart
+    /// of the node. The committed transactions are skipped. The node must have
+    /// the resubmission enabled.
+    pub async fn resubmit(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<types::ResubmissionOutcome>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::Resubmit::build(schema::tx::ResubmitArgs { txs });
+
+        let outcomes = self.query(query).await?.resubmit;
+        Ok(outcomes.into_iter().map(Into::into).collect())
+    }
+
+    /// Submit the transaction and wait for it either to be included in
+    /// a block or removed from `TxPool`.
+    ///
+    /// This will wait forever if needed, so consider wrapping this call
+    /// with a `tokio::time::timeout`.
+    #[cfg(feature = "subscriptions")]
+    pub async fn submit_and_await_commit(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<TransactionStatus> {
+        use cynic::SubscriptionBuilder;
+        let tx = tx.clone().to_bytes();
+        let s = schema::tx::SubmitAndAwaitSubscription::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let mut stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::tx::SubmitAndAwaitSubscription>| {
+                let status: TransactionStatus = r?.submit_and_await.try_into()?;
+                Result::<_, io::Error>::Ok(status)
+            },
+        );
+
+        let status = stream.next().await.ok_or(io::Error::new(
+            io::ErrorKind::Other,
+            "Failed to get status from the submission",
+        ))??;
+
+        Ok(status)
+    }
+
+    pub async fn start_session(&self) -> io::Result<String> {
+        let query = schema::StartSession::build(());
+
+        self.query(query)
+            .await
+            .map(|r| r.start_session.into_inner())
+    }
+
+    pub async fn end_session(&self, id: &str) -> io::Result<bool> {
+        let query = schema::EndSession::build(IdArg { id: id.into() });
+
+        self.query(query).await.map(|r| r.end_session)
+    }
+
+    pub async fn reset(&self, id: &str) -> io::Result<bool> {
+        let query = schema::Reset::build(IdArg { id: id.into() });
+
+        self.query(query).await.map(|r| r.reset)
+    }
+
+    pub async fn execute(&self, id: &str, op: &Instruction) -> io::Result<bool> {
+        let op = serde_json::to_string(op)?;
+        let query = schema::Execute::build(schema::ExecuteArgs { id: id.into(), op });
+
+        self.query(query).await.map(|r| r.execute)
+    }
+
+    pub async fn register(&self, id: &str, register: RegisterId) -> io::Result<Word> {
+        let query = schema::Register::build(RegisterArgs {
+            id: id.into(),
+            register: register.into(),
+        });
+
+        Ok(self.query(query).await?.register.0 as Word)
+    }
+
+    pub async fn memory(
+        &self,
+        id: &str,
+        start: usize,
+        size: usize,
+    ) -> io::Result<Vec<u8>> {
+        let query = schema::Memory::build(MemoryArgs {
+            id: id.into(),
+            start: start.into(),
+            size: size.into(),
+        });
+
+        let memory = self.query(query).await?.memory;
+
+        Ok(serde_json::from_str(memory.as_str())?)
+    }
+
+    pub async fn set_breakpoint(
+        &self,
+        session_id: &str,
+        contract: fuel_types::ContractId,
+        pc: u64,
+    ) -> io::Result<()> {
+        let operation = SetBreakpoint::build(SetBreakpointArgs {
+            id: Id::new(session_id),
+            bp: schema::Breakpoint {
+                contract: contract.into(),
+                pc: U64(pc),
+            },
+        });
+
+        let response = self.query(operation).await?;
+        assert!(
+            response.set_breakpoint,
+            "Setting breakpoint returned invalid reply"
+        );
+        Ok(())
+    }
+
+    pub async fn set_single_stepping(
+        &self,
+        session_id: &str,
+        enable: bool,
+    ) -> io::Result<()> {
+        let operation = SetSingleStepping::build(SetSingleSteppingArgs {
+            id: Id::new(session_id),
+            enable,
+        });
+        self.query(operation).await?;
+        Ok(())
+    }
+
+    pub async fn start_tx(
+        &self,
+        session_id: &str,
+        tx: &Transaction,
+    ) -> io::Result<RunResult> {
+        let operation = StartTx::build(StartTxArgs {
+            id: Id::new(session_id),
+            tx: serde_json::to_string(tx).expect("Couldn't serialize tx to json"),
+        });
+        let response = self.query(operation).await?.start_tx;
+        Ok(response)
+    }
+
+    pub async fn continue_tx(&self, session_id: &str) -> io::Result<RunResult> {
+        let operation = ContinueTx::build(ContinueTxArgs {
+            id: Id::new(session_id),
+        });
+        let response = self.query(operation).await?.continue_tx;
+        Ok(response)
+    }
+
+    pub async fn transaction(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<TransactionResponse>> {
+        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
+
+        let transaction = self.query(query).await?.transaction;
+
+        Ok(transaction.map(|tx| tx.try_into()).transpose()?)
+    }
+
+    /// Get the transaction like `transaction`, but tell a transaction squeezed out
+    /// of the `TxPool` apart from the unknown one instead of returning `None`.
+    pub async fn transaction_lookup(
+        &self,
+        id: &TxId,
+    ) -> io::Result<types::TransactionLookup> {
+        let query =
+            schema::tx::TransactionLookupQuery::build(TxIdArgs { id: (*id).into() });
+
+        let lookup = self.query(query).await?.transaction_lookup;
+
+        Ok(lookup.try_into()?)
+    }
+
+    /// Get the committed transaction. Returns `None` if the transaction is unknown
+    /// or still pending, without querying the `TxPool` of the node.
+    pub async fn committed_transaction(
         &self,
         id: &TxId,
     ) -> io::Result<Option<TransactionResponse>> {
-        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
+        let query =
+            schema::tx::CommittedTransactionQuery::build(TxIdArgs { id: (*id).into() });
 
-        let transaction = self.query(query).await?.transaction;
+        let transaction = self.query(query).await?.committed_transaction;
 
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Get the receipts of the committed transaction without fetching
+    /// the transaction. Returns `None` if the transaction is unknown or pending.
+    pub async fn transaction_receipts(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<Vec<Receipt>>> {
+        let query =
+            schema::tx::TransactionReceiptsQuery::build(TxIdArgs { id: (*id).into() });
+
+        let receipts = self.query(query).await?.transaction_receipts;
+
+        Ok(receipts
+            .map(|receipts| {
+                receipts
+                    .into_iter()
+                    .map(TryInto::<Receipt>::try_into)
+                    .collect::<Result<Vec<_>, ConversionError>>()
+            })
+            .transpose()?)
+    }
+
+    /// Get the block that includes the transaction. Returns `None` if the transaction
+    /// is unknown or not committed yet.
+    pub async fn transaction_block(&self, id: &TxId) -> io::Result<Option<types::Block>> {
+        let query =
+            schema::tx::TransactionBlockQuery::build(TxIdArgs { id: (*id).into() });
+
+        let block = self
+            .query(query)
+            .await?
+            .transaction
+            .and_then(|tx| tx.block)
+            .map(Into::into);
+
+        Ok(block)
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
@@ -554,6 +1281,96 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Get the current status of the transaction without opening a subscription.
+    /// Returns `None` if the transaction is unknown to the node.
+    pub async fn last_seen_status(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<TransactionStatus>> {
+        let query =
+            schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
+
+        let status = self.query(query).await?.transaction_status;
+        Ok(status.map(TryInto::try_into).transpose()?)
+    }
+
+    /// Get the current statuses of the transactions in the order of the `ids`
+    /// with one request. The status is `None` if the transaction is unknown.
+    pub async fn transaction_statuses(
+        &self,
+        ids: &[TxId],
+    ) -> io::Result<Vec<(TxId, Option<TransactionStatus>)>> {
+        let query = schema::tx::TransactionStatusesQuery::build(schema::tx::TxIdsArgs {
+            ids: ids.iter().map(|id| (*id).into()).collect(),
+        });
+
+        let entries = self.query(query).await?.transaction_statuses;
+        entries
+            .into_iter()
+            .map(|entry| {
+                let status = entry.status.map(TryInto::try_into).transpose()?;
+                Ok((entry.id.into(), status))
+            })
+            .collect()
+    }
+
+    /// Get the estimated number of seconds until the pending transaction is committed,
+    /// or the number of seconds it took to commit the committed transaction.
+    /// Returns `None` if the node can't tell it.
+    pub async fn estimated_confirmation_time(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<u64>> {
+        let query = schema::tx::TransactionConfirmationTimeQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let transaction = self.query(query).await?.transaction;
+        Ok(transaction
+            .and_then(|tx| tx.estimated_confirmation_time)
+            .map(Into::into))
+    }
+
+    /// Get the recorded transitions of the status of the transaction, from the
+    /// oldest to the latest
+    pub async fn transaction_status_history(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Vec<types::TransactionStatusTransition>> {
+        let query = schema::tx::TransactionStatusHistoryQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let history = self.query(query).await?.transaction_status_history;
+        history
+            .into_iter()
+            .map(|transition| transition.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Dry run the script transaction and stream its receipts as the VM produces them.
+    /// Dropping the stream stops the execution on the node.
+    pub async fn dry_run_streaming(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<Receipt>>> {
+        use cynic::SubscriptionBuilder;
+        let tx = tx.clone().to_bytes();
+        let s = schema::tx::DryRunStreamingSubscription::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::tx::DryRunStreamingSubscription>| {
+                let receipt: Receipt = r?.dry_run_streaming.try_into()?;
+                Result::<_, io::Error>::Ok(receipt)
+            },
+        );
+
+        Ok(stream)
+    }
+
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
@@ -576,6 +1393,101 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Resume the subscription to the status of a transaction after the status
+    /// of the `cursor`, see [`TransactionStatus::cursor`]. The missed statuses
+    /// are replayed from the status history before the live updates.
+    pub async fn subscribe_transaction_status_from(
+        &self,
+        id: &TxId,
+        cursor: &str,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionStatus>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::StatusChangeFromCursorSubscription::build(
+            schema::tx::StatusChangeFromCursorArgs {
+                id: (*id).into(),
+                from_cursor: cursor.to_string(),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(|tx| {
+            let status = tx?.status_change.try_into()?;
+            Ok(status)
+        });
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the transactions committed after the subscription that
+    /// reference the `owner` in their inputs or outputs.
+    ///
+    /// If the subscriber lags behind the node, the stream returns an error
+    /// with the `LAGGED` code and continues with the next transactions.
+    pub async fn subscribe_owner_transactions(
+        &self,
+        owner: &Address,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionResponse>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::OwnerTransactionsSubscription::build(schema::tx::OwnerArgs {
+            owner: (*owner).into(),
+        });
+
+        let stream = self.subscribe(s).await?.map(|tx| {
+            let tx: TransactionResponse = tx?.owner_transactions.try_into()?;
+            Ok(tx)
+        });
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Streams the transactions of the blocks from `start_height` to `end_height`
+    /// inclusive, in the order of the blocks and of the execution within each block.
+    ///
+    /// The stream ends at `end_height` or at the latest block, whichever is lower.
+    pub async fn transactions_in_range(
+        &self,
+        start_height: u32,
+        end_height: u32,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionResponse>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::TransactionsInRangeSubscription::build(
+            schema::tx::TransactionsInRangeArgs {
+                start_height: start_height.into(),
+                end_height: end_height.into(),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(|tx| {
+            let tx: TransactionResponse = tx?.transactions_in_range.try_into()?;
+            Ok(tx)
+        });
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the tip of the chain. Emits the height and id of the latest
+    /// block each time a block is committed.
+    ///
+    /// A slow subscriber doesn't receive every intermediate tip, only the
+    /// latest one at the time it reads the stream.
+    pub async fn subscribe_chain_tip(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::ChainTip>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::ChainTipSubscription::build(());
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|tip| Ok(tip?.chain_tip.into()));
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -616,6 +1528,46 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Like [`Self::transactions`], but the page is received encoded as CBOR.
+    pub async fn transactions_cbor(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let query = schema::tx::TransactionsQuery::build(request.into());
+        let transactions = self.query_cbor(query).await?.transactions.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions sorted by block height, whose block time
+    /// is within the inclusive range. The bounds are TAI64 timestamps, like the ones
+    /// of `produce_blocks`.
+    pub async fn transactions_in_time_range(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let start_time = start_time.map(|time| Tai64Timestamp::from(Tai64(time)));
+        let end_time = end_time.map(|time| Tai64Timestamp::from(Tai64(time)));
+        let query = schema::tx::TransactionsInTimeRangeQuery::build(
+            (start_time, end_time, request).into(),
+        );
+        let transactions = self.query(query).await?.transactions.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions sorted by the `order`. The orderings
+    /// other than the block order sort only the latest transactions of the chain.
+    pub async fn transactions_ordered_by(
+        &self,
+        order: types::TransactionOrder,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let query = schema::tx::OrderedTransactionsQuery::build((order, request).into());
+        let transactions = self.query(query).await?.transactions.try_into()?;
+        Ok(transactions)
+    }
+
     /// Returns a paginated set of transactions associated with a txo owner address.
     pub async fn transactions_by_owner(
         &self,
@@ -629,12 +1581,171 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions associated with the owner, together
+    /// with the role of the owner in each of them
+    pub async fn transactions_by_owner_with_roles(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::OwnedTransactionResponse, String>> {
+        let owner: schema::Address = (*owner).into();
+        let query = schema::tx::TransactionsByOwnerQuery::build((owner, request).into());
+
+        let transactions = self.query(query).await?.transactions_by_owner.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions that called the contract.
+    /// Requires the index of the transactions by contract enabled on the node.
+    pub async fn transactions_by_contract(
+        &self,
+        contract_id: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract_id: schema::ContractId = (*contract_id).into();
+        let query =
+            schema::tx::TransactionsByContractQuery::build((contract_id, request).into());
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions included in the block at the `height`.
+    pub async fn transactions_in_block(
+        &self,
+        height: u32,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let height: schema::U32 = height.into();
+        let query = schema::tx::TransactionsInBlockQuery::build((height, request).into());
+
+        let transactions = self.query(query).await?.transactions_in_block.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the ids of the transactions from the last `blocks` blocks that failed
+    /// with the panic `reason`, the newest first.
+    pub async fn transactions_by_panic_reason(
+        &self,
+        reason: PanicReason,
+        blocks: u32,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::tx::TransactionsByPanicReasonQuery::build(
+            schema::tx::TransactionsByPanicReasonArgs {
+                reason: format!("{reason:?}"),
+                blocks: blocks.into(),
+            },
+        );
+
+        let tx_ids = self.query(query).await?.transactions_by_panic_reason;
+        Ok(tx_ids.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the ids of the pending transactions with an input that references
+    /// the `utxo_id`, the highest gas price first.
+    pub async fn pending_transactions_spending_utxo(
+        &self,
+        utxo_id: &UtxoId,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::tx::PendingTransactionsSpendingUtxoQuery::build(
+            schema::tx::UtxoIdArgs {
+                utxo_id: (*utxo_id).into(),
+            },
+        );
+
+        let tx_ids = self.query(query).await?.pending_transactions_spending_utxo;
+        Ok(tx_ids.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the pending transactions with an input that references
+    /// the `utxo_id`, the highest gas price first.
+    pub async fn transactions_spending_utxo(
+        &self,
+        utxo_id: &UtxoId,
+    ) -> io::Result<Vec<Transaction>> {
+        let query =
+            schema::tx::TransactionsSpendingUtxoQuery::build(schema::tx::UtxoIdArgs {
+                utxo_id: (*utxo_id).into(),
+            });
+
+        let transactions = self.query(query).await?.transactions_spending_utxo;
+        transactions
+            .into_iter()
+            .map(|tx| Transaction::try_from(tx).map_err(Into::into))
+            .collect()
+    }
+
+    /// Returns the histogram of the gas prices of the pending transactions,
+    /// from the lowest to the highest price.
+    pub async fn mempool_gas_price_distribution(
+        &self,
+    ) -> io::Result<Vec<types::GasPriceBucket>> {
+        let query = schema::tx::MempoolGasPriceDistributionQuery::build(());
+        let buckets = self.query(query).await?.mempool_gas_price_distribution;
+        Ok(buckets.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the number and the total fee of the pending transactions that
+    /// the `proposed_min_gas_price` would evict. The node isn't changed.
+    pub async fn gas_price_impact(
+        &self,
+        proposed_min_gas_price: u64,
+    ) -> io::Result<types::GasPriceImpact> {
+        let query =
+            schema::tx::GasPriceImpactQuery::build(schema::tx::GasPriceImpactArgs {
+                proposed_min_gas_price: proposed_min_gas_price.into(),
+            });
+        let impact = self.query(query).await?.gas_price_impact;
+        Ok(impact.into())
+    }
+
+    /// Returns the average number of milliseconds between the submission and
+    /// the inclusion of the transactions committed in the last `blocks` blocks.
+    pub async fn average_confirmation_time(&self, blocks: u32) -> io::Result<u64> {
+        let query = schema::tx::AverageConfirmationTimeQuery::build(
+            schema::tx::AverageConfirmationTimeArgs {
+                blocks: blocks.into(),
+            },
+        );
+        let average = self.query(query).await?.average_confirmation_time;
+        Ok(average.into())
+    }
+
+    /// Returns the statistics of the gas prices and the sizes of the transactions
+    /// included into the last `block_window` blocks
+    pub async fn fee_statistics(
+        &self,
+        block_window: u32,
+    ) -> io::Result<types::FeeStatistics> {
+        let query =
+            schema::tx::FeeStatisticsQuery::build(schema::tx::FeeStatisticsArgs {
+                block_window: block_window.into(),
+            });
+        let statistics = self.query(query).await?.fee_statistics;
+        Ok(statistics.into())
+    }
+
+    /// Returns the receipts of the transaction. Fails if the node truncated them
+    /// because of its `max_receipts` limit, then `paginated_receipts` returns them all.
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
         let tx = self.query(query).await?.transaction.ok_or_else(|| {
             io::Error::new(ErrorKind::NotFound, format!("transaction {id} not found"))
         })?;
+        if tx.receipts_truncated {
+            return Err(io::Error::new(
+                ErrorKind::Other,
+                format!(
+                    "the receipts of transaction {id} exceed the limit of the node, \
+                     use `paginated_receipts` instead"
+                ),
+            ))
+        }
 
         let receipts = tx
             .receipts
@@ -648,6 +1759,26 @@ impl FuelClient {
         Ok(receipts)
     }
 
+    /// Returns a paginated set of receipts of the transaction, or `None`
+    /// if the transaction is unknown.
+    pub async fn paginated_receipts(
+        &self,
+        id: &TxId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<Option<PaginatedResult<Receipt, String>>> {
+        let id: TransactionId = (*id).into();
+        let query =
+            schema::tx::TransactionReceiptsConnectionQuery::build((id, request).into());
+
+        let receipts = self
+            .query(query)
+            .await?
+            .transaction
+            .map(|tx| tx.receipts_connection.try_into())
+            .transpose()?;
+        Ok(receipts)
+    }
+
     #[cfg(feature = "test-helpers")]
     pub async fn all_receipts(&self) -> io::Result<Vec<Receipt>> {
         let query = schema::tx::AllReceipts::build(());
@@ -709,6 +1840,25 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Returns the sum of the coinbase amounts paid to the `recipient` over
+    /// the last `blocks` blocks.
+    pub async fn coinbase_revenue(
+        &self,
+        recipient: &Address,
+        blocks: u32,
+    ) -> io::Result<u64> {
+        let query = schema::block::CoinbaseRevenueQuery::build(
+            schema::block::CoinbaseRevenueArgs {
+                recipient: (*recipient).into(),
+                blocks: blocks.into(),
+            },
+        );
+
+        let revenue = self.query(query).await?.coinbase_revenue.into();
+
+        Ok(revenue)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -778,6 +1928,48 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Reserve the coins for `ttl_seconds`, so they are reported as reserved
+    /// to the coin selection of the other transactions of the wallet.
+    /// The `token` of the previous reservations extends them, otherwise a new
+    /// token is created. Returns the token and the time when the reservations expire.
+    pub async fn reserve_coins(
+        &self,
+        coin_ids: &[UtxoId],
+        ttl_seconds: Option<u32>,
+        token: Option<fuel_types::Bytes32>,
+    ) -> io::Result<types::CoinReservation> {
+        let query =
+            schema::coins::ReserveCoinsMutation::build(schema::coins::ReserveCoinsArgs {
+                coin_ids: coin_ids.iter().map(|id| (*id).into()).collect(),
+                ttl_seconds: ttl_seconds.map(Into::into),
+                token: token.map(Into::into),
+            });
+
+        let reservation = self.query(query).await?.reserve_coins.into();
+        Ok(reservation)
+    }
+
+    /// Returns `true` if the coin is reserved with the `token`.
+    pub async fn is_coin_reserved(
+        &self,
+        id: &UtxoId,
+        token: &fuel_types::Bytes32,
+    ) -> io::Result<bool> {
+        let query =
+            schema::coins::CoinReservedQuery::build(schema::coins::CoinReservedArgs {
+                utxo_id: (*id).into(),
+                token: (*token).into(),
+            });
+
+        let reserved = self
+            .query(query)
+            .await?
+            .coin
+            .map(|coin| coin.reserved)
+            .unwrap_or(false);
+        Ok(reserved)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -807,6 +1999,23 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Get the balances of all assets owned by the contract,
+    /// `None` if the contract doesn't exist.
+    pub async fn contract_all_balances(
+        &self,
+        id: &ContractId,
+    ) -> io::Result<Option<Vec<types::ContractBalance>>> {
+        let query = schema::contract::ContractAllBalancesQuery::build(ContractByIdArgs {
+            id: (*id).into(),
+        });
+        let balances = self
+            .query(query)
+            .await?
+            .contract
+            .map(|contract| contract.balances.into_iter().map(Into::into).collect());
+        Ok(balances)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
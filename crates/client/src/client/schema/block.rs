@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    Address,
     BlockId,
     ConnectionArgs,
     PageInfo,
@@ -58,6 +59,23 @@ pub struct BlocksQuery {
     pub blocks: BlockConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinbaseRevenueArgs {
+    pub recipient: Address,
+    pub blocks: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinbaseRevenueArgs"
+)]
+pub struct CoinbaseRevenueQuery {
+    #[arguments(recipient: $recipient, blocks: $blocks)]
+    pub coinbase_revenue: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BlockConnection {
@@ -143,6 +161,19 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainTip {
+    pub height: U32,
+    pub block_id: BlockId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct ChainTipSubscription {
+    pub chain_tip: ChainTip,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
@@ -180,6 +180,20 @@ pub struct ChainInfo {
     pub gas_costs: GasCosts,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainParameters {
+    pub chain_id: U64,
+    pub consensus_parameters: ConsensusParameters,
+    pub gas_costs: GasCosts,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct ChainParametersQuery {
+    pub chain_parameters: ChainParameters,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,8 +3,10 @@ use crate::client::{
         schema,
         Address,
         AssetId,
+        Bytes32,
         Nonce,
         PageInfo,
+        Tai64Timestamp,
         UtxoId,
         U32,
         U64,
@@ -212,6 +214,59 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReserveCoinsArgs {
+    pub coin_ids: Vec<UtxoId>,
+    pub ttl_seconds: Option<U32>,
+    pub token: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinReservation {
+    pub token: Bytes32,
+    pub expires_at: Tai64Timestamp,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "ReserveCoinsArgs"
+)]
+pub struct ReserveCoinsMutation {
+    #[arguments(coinIds: $coin_ids, ttlSeconds: $ttl_seconds, token: $token)]
+    pub reserve_coins: CoinReservation,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinReservedArgs {
+    pub utxo_id: UtxoId,
+    pub token: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Coin",
+    variables = "CoinReservedArgs"
+)]
+pub struct ReservedCoin {
+    #[arguments(token: $token)]
+    pub reserved: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinReservedArgs"
+)]
+pub struct CoinReservedQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub coin: Option<ReservedCoin>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,6 +61,23 @@ pub struct Contract {
     pub salt: Salt,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
+pub struct ContractBalancesFragment {
+    pub balances: Vec<ContractBalance>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractByIdArgs"
+)]
+pub struct ContractAllBalancesQuery {
+    #[arguments(id: $id)]
+    pub contract: Option<ContractBalancesFragment>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
 pub struct ContractIdFragment {
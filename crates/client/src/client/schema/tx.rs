@@ -1,17 +1,33 @@
-use super::block::BlockIdFragment;
+use super::block::{
+    Block,
+    BlockIdFragment,
+};
 use crate::client::{
     schema::{
         schema,
-        tx::transparent_receipt::Receipt,
+        tx::transparent_receipt::{
+            RawReceipt,
+            Receipt,
+            ReceiptType,
+        },
         Address,
+        AssetId,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
+        Nonce,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U32,
+        U64,
+    },
+    types::{
+        OwnedTransactionResponse,
+        TransactionResponse,
     },
-    types::TransactionResponse,
     PageDirection,
     PaginatedResult,
     PaginationRequest,
@@ -49,6 +65,117 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+/// Retrieves the committed transaction in opaque form
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct CommittedTransactionQuery {
+    #[arguments(id: $id)]
+    pub committed_transaction: Option<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionReceiptsQuery {
+    #[arguments(id: $id)]
+    pub transaction_receipts: Option<Vec<Receipt>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionStatusQuery {
+    #[arguments(id: $id)]
+    pub transaction_status: Option<TransactionStatus>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxIdsArgs {
+    pub ids: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionStatusEntry {
+    pub id: TransactionId,
+    pub status: Option<TransactionStatus>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdsArgs"
+)]
+pub struct TransactionStatusesQuery {
+    #[arguments(ids: $ids)]
+    pub transaction_statuses: Vec<TransactionStatusEntry>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Transaction")]
+pub struct TransactionConfirmationTime {
+    pub estimated_confirmation_time: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionConfirmationTimeQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionConfirmationTime>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionStatusTransition {
+    pub time: Tai64Timestamp,
+    pub status: TransactionStatus,
+    pub cursor: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionStatusHistoryQuery {
+    #[arguments(id: $id)]
+    pub transaction_status_history: Vec<TransactionStatusTransition>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Transaction")]
+pub struct TransactionBlockFragment {
+    pub block: Option<Block>,
+}
+
+/// Retrieves the block that includes the transaction
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionBlockQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionBlockFragment>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -60,6 +187,126 @@ pub struct TransactionsQuery {
     pub transactions: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsInTimeRangeConnectionArgs {
+    /// The earliest time of the block of the transaction
+    pub start_time: Option<Tai64Timestamp>,
+    /// The latest time of the block of the transaction
+    pub end_time: Option<Tai64Timestamp>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl
+    From<(
+        Option<Tai64Timestamp>,
+        Option<Tai64Timestamp>,
+        PaginationRequest<String>,
+    )> for TransactionsInTimeRangeConnectionArgs
+{
+    fn from(
+        r: (
+            Option<Tai64Timestamp>,
+            Option<Tai64Timestamp>,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        match r.2.direction {
+            PageDirection::Forward => TransactionsInTimeRangeConnectionArgs {
+                start_time: r.0,
+                end_time: r.1,
+                after: r.2.cursor,
+                before: None,
+                first: Some(r.2.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsInTimeRangeConnectionArgs {
+                start_time: r.0,
+                end_time: r.1,
+                after: None,
+                before: r.2.cursor,
+                first: None,
+                last: Some(r.2.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsInTimeRangeConnectionArgs"
+)]
+pub struct TransactionsInTimeRangeQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last, startTime: $start_time, endTime: $end_time)]
+    pub transactions: TransactionConnection,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionOrder {
+    BlockOrder,
+    GasPriceDesc,
+    FeeDesc,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct OrderedTransactionsConnectionArgs {
+    /// The ordering of the transactions
+    pub order_by: TransactionOrder,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(TransactionOrder, PaginationRequest<String>)>
+    for OrderedTransactionsConnectionArgs
+{
+    fn from(r: (TransactionOrder, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => OrderedTransactionsConnectionArgs {
+                order_by: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => OrderedTransactionsConnectionArgs {
+                order_by: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "OrderedTransactionsConnectionArgs"
+)]
+pub struct OrderedTransactionsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last, orderBy: $order_by)]
+    pub transactions: TransactionConnection,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct TransactionConnection {
@@ -95,6 +342,7 @@ pub struct TransactionEdge {
 pub struct OpaqueTransaction {
     pub raw_payload: HexString,
     pub receipts: Option<Vec<Receipt>>,
+    pub receipts_truncated: bool,
     pub status: Option<TransactionStatus>,
 }
 
@@ -114,6 +362,27 @@ pub struct TransactionIdFragment {
     pub id: TransactionId,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "SubmissionResult")]
+pub struct SubmissionIdFragment {
+    pub id: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct InsertionStatus {
+    pub inserted: bool,
+    pub already_known: bool,
+    pub gas_price: U64,
+    pub removed: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "SubmissionResult")]
+pub struct SubmissionStatusFragment {
+    pub insertion: InsertionStatus,
+}
+
 #[derive(cynic::Enum, Copy, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum ReturnType {
@@ -169,6 +438,8 @@ pub enum TransactionStatus {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct SubmittedStatus {
     pub time: Tai64Timestamp,
+    pub age_seconds: U64,
+    pub queue_position: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -194,33 +465,60 @@ pub struct SqueezedOutStatus {
     pub reason: String,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct UnknownTransaction {
+    pub id: TransactionId,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionLookup {
+    Transaction(OpaqueTransaction),
+    SqueezedOutStatus(SqueezedOutStatus),
+    UnknownTransaction(UnknownTransaction),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionLookupQuery {
+    #[arguments(id: $id)]
+    pub transaction_lookup: TransactionLookup,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
-pub struct TransactionsByOwnerConnectionArgs {
-    /// Select transactions based on related `owner`s
-    pub owner: Address,
+pub struct TxReceiptsConnectionArgs {
+    /// The id of the transaction that produced the receipts
+    pub id: TransactionId,
     /// Skip until cursor (forward pagination)
     pub after: Option<String>,
     /// Skip until cursor (backward pagination)
     pub before: Option<String>,
-    /// Retrieve the first n transactions in order (forward pagination)
+    /// Retrieve the first n receipts in order (forward pagination)
     pub first: Option<i32>,
-    /// Retrieve the last n transactions in order (backward pagination).
+    /// Retrieve the last n receipts in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
 }
 
-impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectionArgs {
-    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+impl From<(TransactionId, PaginationRequest<String>)> for TxReceiptsConnectionArgs {
+    fn from(r: (TransactionId, PaginationRequest<String>)) -> Self {
         match r.1.direction {
-            PageDirection::Forward => TransactionsByOwnerConnectionArgs {
-                owner: r.0,
+            PageDirection::Forward => TxReceiptsConnectionArgs {
+                id: r.0,
                 after: r.1.cursor,
                 before: None,
                 first: Some(r.1.results as i32),
                 last: None,
             },
-            PageDirection::Backward => TransactionsByOwnerConnectionArgs {
-                owner: r.0,
+            PageDirection::Backward => TxReceiptsConnectionArgs {
+                id: r.0,
                 after: None,
                 before: r.1.cursor,
                 first: None,
@@ -234,68 +532,1262 @@ impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectio
 #[cynic(
     schema_path = "./assets/schema.sdl",
     graphql_type = "Query",
-    variables = "TransactionsByOwnerConnectionArgs"
+    variables = "TxReceiptsConnectionArgs"
 )]
-pub struct TransactionsByOwnerQuery {
-    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
-    pub transactions_by_owner: TransactionConnection,
+pub struct TransactionReceiptsConnectionQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionReceiptsConnection>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
-    graphql_type = "Subscription",
-    variables = "TxIdArgs"
+    graphql_type = "Transaction",
+    variables = "TxReceiptsConnectionArgs"
 )]
-pub struct StatusChangeSubscription {
-    #[arguments(id: $id)]
-    pub status_change: TransactionStatus,
+pub struct TransactionReceiptsConnection {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub receipts_connection: ReceiptConnection,
 }
 
-// mutations
-
-#[derive(cynic::QueryVariables)]
-pub struct TxArg {
-    pub tx: HexString,
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptConnection {
+    pub edges: Vec<ReceiptEdge>,
+    pub page_info: PageInfo,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
-#[cynic(
-    schema_path = "./assets/schema.sdl",
-    graphql_type = "Query",
-    variables = "TxArg"
-)]
-pub struct EstimatePredicates {
-    #[arguments(tx: $tx)]
-    pub estimate_predicates: OpaqueTransaction,
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptEdge {
+    pub cursor: String,
+    pub node: Receipt,
 }
 
-#[derive(cynic::QueryVariables)]
-pub struct DryRunArg {
-    pub tx: HexString,
-    pub utxo_validation: Option<bool>,
-}
+impl TryFrom<ReceiptConnection> for PaginatedResult<fuel_tx::Receipt, String> {
+    type Error = ConversionError;
 
-#[derive(cynic::QueryFragment, Debug)]
-#[cynic(
-    schema_path = "./assets/schema.sdl",
-    graphql_type = "Mutation",
-    variables = "DryRunArg"
-)]
-pub struct DryRun {
-    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
-    pub dry_run: Vec<Receipt>,
+    fn try_from(conn: ReceiptConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<fuel_tx::Receipt>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
 }
 
-#[derive(cynic::QueryFragment, Debug)]
-#[cynic(
-    schema_path = "./assets/schema.sdl",
-    graphql_type = "Mutation",
-    variables = "TxArg"
-)]
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByOwnerConnectionArgs {
+    /// Select transactions based on related `owner`s
+    pub owner: Address,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectionArgs {
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByOwnerConnectionArgs {
+                owner: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByOwnerConnectionArgs {
+                owner: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByOwnerConnectionArgs"
+)]
+pub struct TransactionsByOwnerQuery {
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_owner: OwnedTransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByContractConnectionArgs {
+    /// Select transactions that called the contract
+    pub contract_id: ContractId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for TransactionsByContractConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByContractConnectionArgs {
+                contract_id: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByContractConnectionArgs {
+                contract_id: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByContractConnectionArgs"
+)]
+pub struct TransactionsByContractQuery {
+    #[arguments(contractId: $contract_id, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_contract: TransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnedTransactionConnection {
+    pub edges: Vec<OwnedTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+impl TryFrom<OwnedTransactionConnection>
+    for PaginatedResult<TransactionResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: OwnedTransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<TransactionResponse>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+impl TryFrom<OwnedTransactionConnection>
+    for PaginatedResult<OwnedTransactionResponse, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: OwnedTransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<OwnedTransactionResponse>, Self::Error> = conn
+            .edges
+            .into_iter()
+            .map(|e| {
+                Ok(OwnedTransactionResponse {
+                    transaction: e.node.try_into()?,
+                    owner_role: e.owner_role,
+                })
+            })
+            .collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnedTransactionEdge {
+    pub cursor: String,
+    pub node: OpaqueTransaction,
+    pub owner_role: OwnerRole,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum OwnerRole {
+    Sender,
+    Recipient,
+    Both,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsInBlockConnectionArgs {
+    /// The height of the block
+    pub height: U32,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(U32, PaginationRequest<String>)> for TransactionsInBlockConnectionArgs {
+    fn from(r: (U32, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsInBlockConnectionArgs {
+                height: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsInBlockConnectionArgs {
+                height: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsInBlockConnectionArgs"
+)]
+pub struct TransactionsInBlockQuery {
+    #[arguments(height: $height, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_in_block: TransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByPanicReasonArgs {
+    pub reason: String,
+    pub blocks: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByPanicReasonArgs"
+)]
+pub struct TransactionsByPanicReasonQuery {
+    #[arguments(reason: $reason, blocks: $blocks)]
+    pub transactions_by_panic_reason: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UtxoIdArgs {
+    pub utxo_id: UtxoId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "UtxoIdArgs"
+)]
+pub struct PendingTransactionsSpendingUtxoQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub pending_transactions_spending_utxo: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "UtxoIdArgs"
+)]
+pub struct TransactionsSpendingUtxoQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub transactions_spending_utxo: Vec<OpaqueTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasPriceBucket {
+    pub min_gas_price: U64,
+    pub max_gas_price: U64,
+    pub count: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct MempoolGasPriceDistributionQuery {
+    pub mempool_gas_price_distribution: Vec<GasPriceBucket>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasPriceImpact {
+    pub evicted_count: U32,
+    pub total_fee: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct GasPriceImpactArgs {
+    pub proposed_min_gas_price: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "GasPriceImpactArgs"
+)]
+pub struct GasPriceImpactQuery {
+    #[arguments(proposedMinGasPrice: $proposed_min_gas_price)]
+    pub gas_price_impact: GasPriceImpact,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AverageConfirmationTimeArgs {
+    pub blocks: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AverageConfirmationTimeArgs"
+)]
+pub struct AverageConfirmationTimeQuery {
+    #[arguments(blocks: $blocks)]
+    pub average_confirmation_time: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FeeStatistics {
+    pub block_count: U32,
+    pub transaction_count: U32,
+    pub min_gas_price: U64,
+    pub p25_gas_price: U64,
+    pub median_gas_price: U64,
+    pub p75_gas_price: U64,
+    pub p90_gas_price: U64,
+    pub max_gas_price: U64,
+    pub average_transaction_size: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FeeStatisticsArgs {
+    pub block_window: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FeeStatisticsArgs"
+)]
+pub struct FeeStatisticsQuery {
+    #[arguments(blockWindow: $block_window)]
+    pub fee_statistics: FeeStatistics,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TxIdArgs"
+)]
+pub struct StatusChangeSubscription {
+    #[arguments(id: $id)]
+    pub status_change: TransactionStatus,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StatusChangeFromCursorArgs {
+    pub id: TransactionId,
+    pub from_cursor: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "StatusChangeFromCursorArgs"
+)]
+pub struct StatusChangeFromCursorSubscription {
+    #[arguments(id: $id, fromCursor: $from_cursor)]
+    pub status_change: TransactionStatus,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct OwnerArgs {
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "OwnerArgs"
+)]
+pub struct OwnerTransactionsSubscription {
+    #[arguments(owner: $owner)]
+    pub owner_transactions: OpaqueTransaction,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsInRangeArgs {
+    pub start_height: U32,
+    pub end_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TransactionsInRangeArgs"
+)]
+pub struct TransactionsInRangeSubscription {
+    #[arguments(startHeight: $start_height, endHeight: $end_height)]
+    pub transactions_in_range: OpaqueTransaction,
+}
+
+// mutations
+
+#[derive(cynic::QueryVariables)]
+pub struct TxArg {
+    pub tx: HexString,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimatePredicates {
+    #[arguments(tx: $tx)]
+    pub estimate_predicates: OpaqueTransaction,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct EstimateMissingPredicatesArgs {
+    pub tx: HexString,
+    pub only_missing: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EstimateMissingPredicatesArgs"
+)]
+pub struct EstimateMissingPredicates {
+    #[arguments(tx: $tx, onlyMissing: $only_missing)]
+    pub estimate_predicates: OpaqueTransaction,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicatesAndFeesEstimate {
+    pub transaction: OpaqueTransaction,
+    pub predicate_gas: U64,
+    pub signature_gas: U64,
+    pub verification_gas: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimatePredicatesAndFees {
+    #[arguments(tx: $tx)]
+    pub estimate_predicates_and_fees: PredicatesAndFeesEstimate,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicateInputValidation {
+    pub index: i32,
+    pub valid: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicateValidation {
+    pub all_valid: bool,
+    pub per_input: Vec<PredicateInputValidation>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct PredicatesValid {
+    #[arguments(tx: $tx)]
+    pub predicates_valid: PredicateValidation,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TestWitness {
+    pub index: U32,
+    pub data: HexString,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct TxWithTestWitnessesArg {
+    pub tx: HexString,
+    pub test_witnesses: Option<Vec<TestWitness>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxWithTestWitnessesArg"
+)]
+pub struct PredicatesValidWithTestWitnesses {
+    #[arguments(tx: $tx, testWitnesses: $test_witnesses)]
+    pub predicates_valid: PredicateValidation,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunWithTestWitnessesArg {
+    pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+    pub test_witnesses: Option<Vec<TestWitness>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunWithTestWitnessesArg"
+)]
+pub struct DryRunWithTestWitnesses {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation, testWitnesses: $test_witnesses)]
+    pub dry_run: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ValidationFailure {
+    pub path: Option<String>,
+    pub reason: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct ValidateTransaction {
+    #[arguments(tx: $tx)]
+    pub validate_transaction: Vec<ValidationFailure>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Transaction")]
+pub struct DecodedTransaction {
+    pub id: TransactionId,
+    pub gas_price: Option<U64>,
+    pub gas_limit: Option<U64>,
+    pub maturity: Option<U32>,
+    pub is_script: bool,
+    pub is_create: bool,
+    pub is_mint: bool,
+    pub requires_signatures: Option<bool>,
+    pub predicate_inputs_count: Option<i32>,
+    pub is_predicate_only: Option<bool>,
+    pub raw_payload: HexString,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct DecodeTransactionQuery {
+    #[arguments(tx: $tx)]
+    pub decode_transaction: DecodedTransaction,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum InputUtxoState {
+    Unspent,
+    Spent,
+    Reserved,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionInputStatus {
+    pub index: U32,
+    pub utxo_id: Option<UtxoId>,
+    pub nonce: Option<Nonce>,
+    pub state: InputUtxoState,
+    pub reserved_by: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct TransactionInputStatusQuery {
+    #[arguments(tx: $tx)]
+    pub transaction_input_status: Vec<TransactionInputStatus>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunArg {
+    pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRun {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    pub dry_run: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunRaw {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    pub dry_run: Vec<RawReceipt>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunWithGasLimitArg {
+    pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+    pub gas_limit: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunWithGasLimitArg"
+)]
+pub struct DryRunWithGasLimit {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation, gasLimit: $gas_limit)]
+    pub dry_run: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunAtTimeArg {
+    pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+    pub block_time: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunAtTimeArg"
+)]
+pub struct DryRunAtTime {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation, blockTime: $block_time)]
+    pub dry_run: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RevertReason {
+    pub receipt_type: ReceiptType,
+    pub reason_code: U64,
+    pub reason: String,
+    pub contract_id: ContractId,
+    pub pc: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallGas {
+    pub contract_id: ContractId,
+    pub depth: U32,
+    pub gas_forwarded: U64,
+    pub returned: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasBreakdown {
+    pub gas_limit: U64,
+    pub gas_used: U64,
+    pub gas_refunded: U64,
+    pub calls: Vec<CallGas>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunDetails {
+    pub receipts: Vec<Receipt>,
+    pub revert_reason: Option<RevertReason>,
+    pub utxo_validation: bool,
+    pub gas_breakdown: GasBreakdown,
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    pub min_gas_limit: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceChange {
+    pub asset_id: AssetId,
+    pub sent: U64,
+    pub received: U64,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunDetailedArgs {
+    pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+    pub owner: Option<Address>,
+    pub find_min_gas: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunDetailedArgs"
+)]
+pub struct DryRunDetailed {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation, owner: $owner, findMinGas: $find_min_gas)]
+    pub dry_run_detailed: DryRunDetails,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DecodedEvent {
+    pub receipt_type: ReceiptType,
+    pub contract_id: ContractId,
+    pub call_depth: U32,
+    pub data: HexString,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunEvents {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    pub dry_run_events: Vec<DecodedEvent>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageSlotDelta {
+    pub contract_id: ContractId,
+    // `Bytes32` in this module is `fuel_types::Bytes32`, not the scalar.
+    pub key: super::Bytes32,
+    pub before: Option<super::Bytes32>,
+    pub after: super::Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunStorageDelta {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    pub dry_run_storage_delta: Vec<StorageSlotDelta>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DryRunWithForcedFailureArg {
+    pub tx: HexString,
+    pub fail_contract: ContractId,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunWithForcedFailureArg"
+)]
+pub struct DryRunWithForcedFailure {
+    #[arguments(tx: $tx, failContract: $fail_contract, utxoValidation: $utxo_validation)]
+    pub dry_run_with_forced_failure: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DryRunManyArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub sort_by_dependencies: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunManyArg"
+)]
+pub struct DryRunMany {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, sortByDependencies: $sort_by_dependencies)]
+    pub dry_run_many: Vec<Vec<Receipt>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DryRunParallelArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunParallelArg"
+)]
+pub struct DryRunParallel {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation)]
+    pub dry_run_parallel: Vec<Vec<Receipt>>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum SimulatedTransactionStatus {
+    Success,
+    Failure,
+    Skipped,
+    Excluded,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SimulatedTransaction {
+    pub id: TransactionId,
+    pub status: SimulatedTransactionStatus,
+    pub reason: Option<String>,
+    pub receipts: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockSimulation {
+    pub height: U32,
+    pub gas_used: U64,
+    pub fees: U64,
+    pub transactions: Vec<SimulatedTransaction>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SimulateBlockArg {
+    pub txs: Vec<HexString>,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SimulateBlockArg"
+)]
+pub struct SimulateBlock {
+    #[arguments(txs: $txs, height: $height)]
+    pub simulate_block: BlockSimulation,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ArithmeticPanic {
+    pub pc: U64,
+    pub operation: String,
+    pub contract_id: ContractId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct DryRunArithmeticCheck {
+    #[arguments(tx: $tx)]
+    pub dry_run_arithmetic_check: Option<ArithmeticPanic>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunSummaryArgs {
+    pub tx: HexString,
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetAmount {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionSummary {
+    pub sends: Vec<AssetAmount>,
+    pub receives: Vec<AssetAmount>,
+    pub fee: U64,
+    pub contracts_called: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunSummaryArgs"
+)]
+pub struct DryRunSummary {
+    #[arguments(tx: $tx, owner: $owner)]
+    pub dry_run_summary: TransactionSummary,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CallFrameOutcome {
+    Return,
+    Revert,
+    Panic,
+    Incomplete,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ExecutionTransfer {
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub to_contract: Option<ContractId>,
+    pub to_address: Option<Address>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallFrame {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub gas: U64,
+    pub outcome: CallFrameOutcome,
+    pub transfers: Vec<ExecutionTransfer>,
+    pub logs: Vec<DecodedEvent>,
+    /// `None` for the frames nested deeper than the query fetches.
+    #[cynic(recurse = "16")]
+    pub calls: Option<Vec<CallFrame>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ExecutionGraph {
+    pub gas_used: U64,
+    pub root: CallFrame,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunExecutionGraph {
+    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    pub dry_run_execution_graph: ExecutionGraph,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DryRunTraceArgs {
+    pub tx: HexString,
+    pub snapshot_at: Option<Vec<U32>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TraceStep {
+    pub pc: U64,
+    pub index: U32,
+    pub opcode: String,
+    pub registers: Option<Vec<U64>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ExecutionTrace {
+    pub steps: Vec<TraceStep>,
+    pub truncated: bool,
+    pub state: Option<ProgramState>,
+    pub receipts: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunTraceArgs"
+)]
+pub struct DryRunTrace {
+    #[arguments(tx: $tx, snapshotAt: $snapshot_at)]
+    pub dry_run_trace: ExecutionTrace,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PcRange {
+    pub start: U64,
+    pub end: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractCoverage {
+    pub contract_id: ContractId,
+    pub ranges: Vec<PcRange>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ExecutionCoverage {
+    pub contracts: Vec<ContractCoverage>,
+    pub truncated: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct DryRunCoverage {
+    #[arguments(tx: $tx)]
+    pub dry_run_coverage: ExecutionCoverage,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
 pub struct Submit {
     #[arguments(tx: $tx)]
-    pub submit: TransactionIdFragment,
+    pub submit: SubmissionIdFragment,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct SubmitWithStatus {
+    #[arguments(tx: $tx)]
+    pub submit: SubmissionStatusFragment,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct SubmitPrevalidated {
+    #[arguments(tx: $tx)]
+    pub submit_prevalidated: SubmissionStatusFragment,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SubmitWithPreviewArgs {
+    pub tx: HexString,
+    pub preview: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "SubmissionResult")]
+pub struct SubmissionPreviewFragment {
+    pub id: TransactionId,
+    pub insertion: InsertionStatus,
+    pub preview_receipts: Option<Vec<Receipt>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitWithPreviewArgs"
+)]
+pub struct SubmitWithPreview {
+    #[arguments(tx: $tx, preview: $preview)]
+    pub submit: SubmissionPreviewFragment,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DryRunThenSubmitArgs {
+    pub tx: HexString,
+    pub require_success: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunThenSubmitResult {
+    pub receipts: Vec<Receipt>,
+    pub revert_reason: Option<RevertReason>,
+    pub submission: Option<SubmissionStatusFragment>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunThenSubmitArgs"
+)]
+pub struct DryRunThenSubmit {
+    #[arguments(tx: $tx, requireSuccess: $require_success)]
+    pub dry_run_then_submit: DryRunThenSubmitResult,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct ReplaceTransaction {
+    #[arguments(tx: $tx)]
+    pub replace_transaction: Vec<TransactionId>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum ResubmissionStatus {
+    Inserted,
+    AlreadyPending,
+    AlreadyCommitted,
+    Rejected,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ResubmissionOutcome {
+    pub id: TransactionId,
+    pub status: ResubmissionStatus,
+    pub reason: Option<String>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct ResubmitArgs {
+    pub txs: Vec<HexString>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "ResubmitArgs"
+)]
+pub struct Resubmit {
+    #[arguments(txs: $txs)]
+    pub resubmit: Vec<ResubmissionOutcome>,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum BatchSubmissionCategory {
+    NewlyInserted,
+    DuplicateInBatch,
+    AlreadyKnown,
+    Rejected,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BatchSubmissionOutcome {
+    pub id: Option<TransactionId>,
+    pub category: BatchSubmissionCategory,
+    pub reason: Option<String>,
+    pub insertion: Option<InsertionStatus>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct SubmitManyArgs {
+    pub txs: Vec<HexString>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitManyArgs"
+)]
+pub struct SubmitMany {
+    #[arguments(txs: $txs)]
+    pub submit_many: Vec<BatchSubmissionOutcome>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct SubmitWithIdempotencyKeyArgs {
+    pub tx: HexString,
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitWithIdempotencyKeyArgs"
+)]
+pub struct SubmitWithIdempotencyKey {
+    #[arguments(tx: $tx, idempotencyKey: $idempotency_key)]
+    pub submit: SubmissionIdFragment,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -309,6 +1801,17 @@ pub struct SubmitAndAwaitSubscription {
     pub submit_and_await: TransactionStatus,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TxArg"
+)]
+pub struct DryRunStreamingSubscription {
+    #[arguments(tx: $tx)]
+    pub dry_run_streaming: Receipt,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
 pub struct AllReceipts {
@@ -14,6 +14,7 @@ use crate::client::schema::{
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
+    fuel_types::bytes::Deserializable,
 };
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -49,6 +50,22 @@ pub struct Receipt {
     pub sub_id: Option<Bytes32>,
 }
 
+/// The receipt in its canonical serialization.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Receipt")]
+pub struct RawReceipt {
+    pub raw: HexString,
+}
+
+impl TryFrom<RawReceipt> for fuel_tx::Receipt {
+    type Error = ConversionError;
+
+    fn try_from(value: RawReceipt) -> Result<Self, Self::Error> {
+        fuel_tx::Receipt::from_bytes(value.raw.0 .0.as_slice())
+            .map_err(ConversionError::ReceiptFromBytesError)
+    }
+}
+
 #[derive(cynic::Enum, Clone, Copy, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum ReceiptType {
@@ -12,11 +12,16 @@ pub mod node_info;
 pub use balance::Balance;
 pub use block::{
     Block,
+    ChainTip,
     Consensus,
 };
-pub use chain_info::ChainInfo;
+pub use chain_info::{
+    ChainInfo,
+    ChainParameters,
+};
 pub use coins::{
     Coin,
+    CoinReservation,
     CoinType,
     MessageCoin,
 };
@@ -36,15 +41,60 @@ pub use message::{
 };
 pub use node_info::NodeInfo;
 
+pub use crate::client::schema::tx::{
+    BatchSubmissionCategory,
+    CallFrameOutcome,
+    InputUtxoState,
+    OwnerRole,
+    ResubmissionStatus,
+    SimulatedTransactionStatus,
+    TransactionOrder,
+};
+
 use crate::client::schema::{
     tx::{
+        transparent_receipt::ReceiptType as SchemaReceiptType,
+        ArithmeticPanic as SchemaArithmeticPanic,
+        AssetAmount as SchemaAssetAmount,
+        BatchSubmissionOutcome as SchemaBatchSubmissionOutcome,
+        BlockSimulation as SchemaBlockSimulation,
+        CallFrame as SchemaCallFrame,
+        DecodedEvent as SchemaDecodedEvent,
+        DecodedTransaction as SchemaDecodedTransaction,
+        DryRunDetails as SchemaDryRunDetails,
+        DryRunThenSubmitResult as SchemaDryRunThenSubmitResult,
+        ExecutionCoverage as SchemaExecutionCoverage,
+        ExecutionGraph as SchemaExecutionGraph,
+        ExecutionTrace as SchemaExecutionTrace,
+        ExecutionTransfer as SchemaExecutionTransfer,
+        FeeStatistics as SchemaFeeStatistics,
+        GasBreakdown as SchemaGasBreakdown,
+        GasPriceBucket as SchemaGasPriceBucket,
+        GasPriceImpact as SchemaGasPriceImpact,
+        InsertionStatus as SchemaInsertionStatus,
         OpaqueTransaction,
+        PredicateValidation as SchemaPredicateValidation,
+        PredicatesAndFeesEstimate as SchemaPredicatesAndFeesEstimate,
+        ResubmissionOutcome as SchemaResubmissionOutcome,
+        RevertReason as SchemaRevertReason,
+        SimulatedTransaction as SchemaSimulatedTransaction,
+        StorageSlotDelta as SchemaStorageSlotDelta,
+        SubmissionPreviewFragment,
+        TraceStep as SchemaTraceStep,
+        TransactionInputStatus as SchemaTransactionInputStatus,
+        TransactionLookup as SchemaTransactionLookup,
         TransactionStatus as SchemaTxStatus,
+        TransactionStatusTransition as SchemaTransactionStatusTransition,
+        TransactionSummary as SchemaTransactionSummary,
+        ValidationFailure as SchemaValidationFailure,
     },
     ConversionError,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Receipt,
+        Transaction,
+    },
     fuel_types::bytes::Deserializable,
     fuel_vm::ProgramState,
 };
@@ -52,6 +102,7 @@ use serde::{
     Deserialize,
     Serialize,
 };
+use std::ops::Range;
 use tai64::Tai64;
 
 pub mod primitives {
@@ -87,10 +138,32 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// The result of looking up the transaction with `transaction_lookup`.
+#[derive(Debug, Clone)]
+pub enum TransactionLookup {
+    /// The transaction is pending in the `TxPool` or committed.
+    Found(TransactionResponse),
+    /// The transaction was removed from the `TxPool` without being committed.
+    SqueezedOut { reason: String },
+    /// The node never saw the transaction or doesn't remember its removal.
+    Unknown,
+}
+
+/// The transaction of the owner together with the role of the owner in it.
+#[derive(Debug, Clone)]
+pub struct OwnedTransactionResponse {
+    pub transaction: TransactionResponse,
+    pub owner_role: OwnerRole,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionStatus {
     Submitted {
         submitted_at: Tai64,
+        /// The number of seconds since the submission when the status was emitted.
+        age_seconds: u64,
+        /// The estimated position in the `TxPool`, `0` is the next to be included.
+        queue_position: Option<u32>,
     },
     Success {
         block_id: String,
@@ -108,6 +181,744 @@ pub enum TransactionStatus {
     },
 }
 
+impl TransactionStatus {
+    /// The cursor to resume the status subscription after this status.
+    /// `None` for `SqueezedOut`, which doesn't carry its time and is final.
+    pub fn cursor(&self) -> Option<String> {
+        match self {
+            TransactionStatus::Submitted { submitted_at, .. } => {
+                Some(format!("SUBMITTED#{}", submitted_at.0))
+            }
+            TransactionStatus::Success { time, .. } => {
+                Some(format!("SUCCESS#{}", time.0))
+            }
+            TransactionStatus::Failure { time, .. } => {
+                Some(format!("FAILURE#{}", time.0))
+            }
+            TransactionStatus::SqueezedOut { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionStatusTransition {
+    /// The time of the transition to the `status`.
+    pub time: Tai64,
+    pub status: TransactionStatus,
+    /// The cursor to resume the status subscription after this transition.
+    pub cursor: String,
+}
+
+impl TryFrom<SchemaTransactionStatusTransition> for TransactionStatusTransition {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaTransactionStatusTransition) -> Result<Self, Self::Error> {
+        Ok(Self {
+            time: value.time.0,
+            status: value.status.try_into()?,
+            cursor: value.cursor,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArithmeticPanic {
+    pub pc: u64,
+    pub operation: String,
+    pub contract_id: primitives::ContractId,
+}
+
+impl From<SchemaArithmeticPanic> for ArithmeticPanic {
+    fn from(value: SchemaArithmeticPanic) -> Self {
+        Self {
+            pc: value.pc.into(),
+            operation: value.operation,
+            contract_id: value.contract_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevertReason {
+    /// `true` if the reason comes from a `Panic` receipt, `false` for a `Revert` receipt.
+    pub is_panic: bool,
+    pub reason_code: u64,
+    pub reason: String,
+    pub contract_id: primitives::ContractId,
+    pub pc: u64,
+}
+
+impl From<SchemaRevertReason> for RevertReason {
+    fn from(value: SchemaRevertReason) -> Self {
+        Self {
+            is_panic: matches!(value.receipt_type, SchemaReceiptType::Panic),
+            reason_code: value.reason_code.into(),
+            reason: value.reason,
+            contract_id: value.contract_id.into(),
+            pc: value.pc.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedEvent {
+    /// `true` if the event comes from a `LogData` receipt, `false` for a `Log` receipt.
+    pub is_log_data: bool,
+    pub contract_id: primitives::ContractId,
+    pub call_depth: u32,
+    pub data: Vec<u8>,
+}
+
+impl From<SchemaDecodedEvent> for DecodedEvent {
+    fn from(value: SchemaDecodedEvent) -> Self {
+        Self {
+            is_log_data: matches!(value.receipt_type, SchemaReceiptType::LogData),
+            contract_id: value.contract_id.into(),
+            call_depth: value.call_depth.into(),
+            data: value.data.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionTransfer {
+    pub asset_id: primitives::AssetId,
+    pub amount: u64,
+    /// The receiving contract, set for the transfers to contracts.
+    pub to_contract: Option<primitives::ContractId>,
+    /// The receiving address, set for the transfers to outputs.
+    pub to_address: Option<primitives::Address>,
+}
+
+impl From<SchemaExecutionTransfer> for ExecutionTransfer {
+    fn from(value: SchemaExecutionTransfer) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+            to_contract: value.to_contract.map(Into::into),
+            to_address: value.to_address.map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CallFrame {
+    /// The called contract, zeroed for the script.
+    pub contract_id: primitives::ContractId,
+    pub asset_id: primitives::AssetId,
+    pub amount: u64,
+    pub gas: u64,
+    pub outcome: CallFrameOutcome,
+    pub transfers: Vec<ExecutionTransfer>,
+    pub logs: Vec<DecodedEvent>,
+    /// The nested calls. Empty for the frames nested deeper than the client fetches.
+    pub calls: Vec<CallFrame>,
+}
+
+impl From<SchemaCallFrame> for CallFrame {
+    fn from(value: SchemaCallFrame) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+            gas: value.gas.into(),
+            outcome: value.outcome,
+            transfers: value.transfers.into_iter().map(Into::into).collect(),
+            logs: value.logs.into_iter().map(Into::into).collect(),
+            calls: value
+                .calls
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutionGraph {
+    pub gas_used: u64,
+    /// The frame of the script, the root of all the calls.
+    pub root: CallFrame,
+}
+
+impl From<SchemaExecutionGraph> for ExecutionGraph {
+    fn from(value: SchemaExecutionGraph) -> Self {
+        Self {
+            gas_used: value.gas_used.into(),
+            root: value.root.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub pc: u64,
+    /// The index of the instruction in the running code.
+    pub index: u32,
+    /// The mnemonic of the instruction, e.g. `ADDI`.
+    pub opcode: String,
+    /// The registers before the instruction, set only for the requested indexes.
+    pub registers: Option<Vec<u64>>,
+}
+
+impl From<SchemaTraceStep> for TraceStep {
+    fn from(value: SchemaTraceStep) -> Self {
+        Self {
+            pc: value.pc.into(),
+            index: value.index.into(),
+            opcode: value.opcode,
+            registers: value
+                .registers
+                .map(|registers| registers.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutionTrace {
+    pub steps: Vec<TraceStep>,
+    /// `true` if the node stopped the execution after too many instructions.
+    pub truncated: bool,
+    /// `None` if the execution was stopped.
+    pub state: Option<ProgramState>,
+    pub receipts: Vec<Receipt>,
+}
+
+impl TryFrom<SchemaExecutionTrace> for ExecutionTrace {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaExecutionTrace) -> Result<Self, Self::Error> {
+        Ok(Self {
+            steps: value.steps.into_iter().map(Into::into).collect(),
+            truncated: value.truncated,
+            state: value.state.map(TryInto::try_into).transpose()?,
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// The code of the contract executed by the dry-run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractCoverage {
+    pub contract_id: primitives::ContractId,
+    /// The executed ranges of the code in bytes from its start, sorted by the start.
+    pub ranges: Vec<Range<u64>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutionCoverage {
+    /// The called contracts, sorted by the id.
+    pub contracts: Vec<ContractCoverage>,
+    /// `true` if the node stopped the execution after too many instructions.
+    pub truncated: bool,
+}
+
+impl From<SchemaExecutionCoverage> for ExecutionCoverage {
+    fn from(value: SchemaExecutionCoverage) -> Self {
+        Self {
+            contracts: value
+                .contracts
+                .into_iter()
+                .map(|contract| ContractCoverage {
+                    contract_id: contract.contract_id.into(),
+                    ranges: contract
+                        .ranges
+                        .into_iter()
+                        .map(|range| range.start.into()..range.end.into())
+                        .collect(),
+                })
+                .collect(),
+            truncated: value.truncated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InsertionStatus {
+    /// `true` if the transaction was inserted by this submission.
+    pub inserted: bool,
+    /// `true` if the transaction was already in the `TxPool`.
+    pub already_known: bool,
+    pub gas_price: u64,
+    /// The transactions evicted from the `TxPool` by the submitted one.
+    pub removed: Vec<primitives::TransactionId>,
+}
+
+impl From<SchemaInsertionStatus> for InsertionStatus {
+    fn from(value: SchemaInsertionStatus) -> Self {
+        Self {
+            inserted: value.inserted,
+            already_known: value.already_known,
+            gas_price: value.gas_price.into(),
+            removed: value.removed.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageSlotDelta {
+    pub contract_id: primitives::ContractId,
+    pub key: primitives::Bytes32,
+    /// `None` if the slot didn't exist before the dry run.
+    pub before: Option<primitives::Bytes32>,
+    /// The cleared slot is zeroed.
+    pub after: primitives::Bytes32,
+}
+
+impl From<SchemaStorageSlotDelta> for StorageSlotDelta {
+    fn from(value: SchemaStorageSlotDelta) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            key: value.key.into(),
+            before: value.before.map(Into::into),
+            after: value.after.into(),
+        }
+    }
+}
+
+/// The outcome of the resubmission of one transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResubmissionOutcome {
+    pub id: primitives::TransactionId,
+    pub status: ResubmissionStatus,
+    /// The reason of the rejection, set only for the `Rejected` status.
+    pub reason: Option<String>,
+}
+
+impl From<SchemaResubmissionOutcome> for ResubmissionOutcome {
+    fn from(value: SchemaResubmissionOutcome) -> Self {
+        Self {
+            id: value.id.into(),
+            status: value.status,
+            reason: value.reason,
+        }
+    }
+}
+
+/// The outcome of the submission of one transaction of the batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchSubmissionOutcome {
+    /// The id of the transaction, `None` if the node couldn't decode it.
+    pub id: Option<primitives::TransactionId>,
+    pub category: BatchSubmissionCategory,
+    /// The reason of the rejection, set only for the `Rejected` category.
+    pub reason: Option<String>,
+    /// The outcome of the insertion, set only for the `NewlyInserted`
+    /// and the `AlreadyKnown` categories.
+    pub insertion: Option<InsertionStatus>,
+}
+
+impl From<SchemaBatchSubmissionOutcome> for BatchSubmissionOutcome {
+    fn from(value: SchemaBatchSubmissionOutcome) -> Self {
+        Self {
+            id: value.id.map(Into::into),
+            category: value.category,
+            reason: value.reason,
+            insertion: value.insertion.map(Into::into),
+        }
+    }
+}
+
+/// The range of gas prices of the pending transactions, the bounds are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasPriceBucket {
+    pub min_gas_price: u64,
+    pub max_gas_price: u64,
+    pub count: u32,
+}
+
+impl From<SchemaGasPriceBucket> for GasPriceBucket {
+    fn from(value: SchemaGasPriceBucket) -> Self {
+        Self {
+            min_gas_price: value.min_gas_price.into(),
+            max_gas_price: value.max_gas_price.into(),
+            count: value.count.into(),
+        }
+    }
+}
+
+/// The pending transactions priced below the proposed minimum gas price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasPriceImpact {
+    pub evicted_count: u32,
+    pub total_fee: u64,
+}
+
+impl From<SchemaGasPriceImpact> for GasPriceImpact {
+    fn from(value: SchemaGasPriceImpact) -> Self {
+        Self {
+            evicted_count: value.evicted_count.into(),
+            total_fee: value.total_fee.into(),
+        }
+    }
+}
+
+/// The gas prices and the sizes of the transactions included into the recent blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeStatistics {
+    pub block_count: u32,
+    pub transaction_count: u32,
+    pub min_gas_price: u64,
+    pub p25_gas_price: u64,
+    pub median_gas_price: u64,
+    pub p75_gas_price: u64,
+    pub p90_gas_price: u64,
+    pub max_gas_price: u64,
+    pub average_transaction_size: u64,
+}
+
+impl From<SchemaFeeStatistics> for FeeStatistics {
+    fn from(value: SchemaFeeStatistics) -> Self {
+        Self {
+            block_count: value.block_count.into(),
+            transaction_count: value.transaction_count.into(),
+            min_gas_price: value.min_gas_price.into(),
+            p25_gas_price: value.p25_gas_price.into(),
+            median_gas_price: value.median_gas_price.into(),
+            p75_gas_price: value.p75_gas_price.into(),
+            p90_gas_price: value.p90_gas_price.into(),
+            max_gas_price: value.max_gas_price.into(),
+            average_transaction_size: value.average_transaction_size.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunDetails {
+    pub receipts: Vec<Receipt>,
+    pub revert_reason: Option<RevertReason>,
+    /// `true` if the node enforced the existence of the UTXOs during the dry-run.
+    pub utxo_validation: bool,
+    pub gas_breakdown: GasBreakdown,
+    /// The net change of each asset of the requested owner, `None` if no owner
+    /// was requested.
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    /// The minimum gas limit of the script with which the dry-run succeeds,
+    /// `None` if the search wasn't requested or no such limit was found.
+    pub min_gas_limit: Option<u64>,
+}
+
+/// The net change of the balance of one asset, only one of the amounts is non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub asset_id: primitives::AssetId,
+    pub sent: u64,
+    pub received: u64,
+}
+
+impl TryFrom<SchemaDryRunDetails> for DryRunDetails {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaDryRunDetails) -> Result<Self, Self::Error> {
+        Ok(Self {
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            revert_reason: value.revert_reason.map(Into::into),
+            utxo_validation: value.utxo_validation,
+            gas_breakdown: value.gas_breakdown.into(),
+            balance_changes: value.balance_changes.map(|changes| {
+                changes
+                    .into_iter()
+                    .map(|change| BalanceChange {
+                        asset_id: change.asset_id.into(),
+                        sent: change.sent.into(),
+                        received: change.received.into(),
+                    })
+                    .collect()
+            }),
+            min_gas_limit: value.min_gas_limit.map(Into::into),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasBreakdown {
+    /// The gas limit of the script, `0` for other transactions.
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub gas_refunded: u64,
+    /// The contract calls in the execution order.
+    pub calls: Vec<CallGas>,
+}
+
+/// The gas forwarded to the contract call. The receipts don't record
+/// how much of it the call used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallGas {
+    pub contract_id: primitives::ContractId,
+    /// `0` for the calls made by the script.
+    pub depth: u32,
+    pub gas_forwarded: u64,
+    /// `false` if the call reverted or panicked.
+    pub returned: bool,
+}
+
+impl From<SchemaGasBreakdown> for GasBreakdown {
+    fn from(value: SchemaGasBreakdown) -> Self {
+        Self {
+            gas_limit: value.gas_limit.into(),
+            gas_used: value.gas_used.into(),
+            gas_refunded: value.gas_refunded.into(),
+            calls: value
+                .calls
+                .into_iter()
+                .map(|call| CallGas {
+                    contract_id: call.contract_id.into(),
+                    depth: call.depth.into(),
+                    gas_forwarded: call.gas_forwarded.into(),
+                    returned: call.returned,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunThenSubmitResult {
+    pub receipts: Vec<Receipt>,
+    pub revert_reason: Option<RevertReason>,
+    /// `None` if the transaction wasn't submitted because the dry-run reverted.
+    pub submission: Option<InsertionStatus>,
+}
+
+impl TryFrom<SchemaDryRunThenSubmitResult> for DryRunThenSubmitResult {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaDryRunThenSubmitResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            revert_reason: value.revert_reason.map(Into::into),
+            submission: value
+                .submission
+                .map(|submission| submission.insertion.into()),
+        })
+    }
+}
+
+/// The submitted transaction with the receipts of the dry-run made before
+/// its insertion.
+#[derive(Debug, Clone)]
+pub struct SubmissionWithPreview {
+    pub id: primitives::TransactionId,
+    pub insertion: InsertionStatus,
+    /// The actual receipts may differ, if the state changes before the inclusion.
+    pub preview_receipts: Vec<Receipt>,
+}
+
+impl TryFrom<SubmissionPreviewFragment> for SubmissionWithPreview {
+    type Error = ConversionError;
+
+    fn try_from(value: SubmissionPreviewFragment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            insertion: value.insertion.into(),
+            preview_receipts: value
+                .preview_receipts
+                .unwrap_or_default()
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulatedTransaction {
+    pub id: primitives::TransactionId,
+    pub status: SimulatedTransactionStatus,
+    /// The reason of the failure or of the rejection of the transaction.
+    pub reason: Option<String>,
+    /// Empty if the transaction isn't included into the block.
+    pub receipts: Vec<Receipt>,
+}
+
+impl TryFrom<SchemaSimulatedTransaction> for SimulatedTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaSimulatedTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            status: value.status,
+            reason: value.reason,
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockSimulation {
+    pub height: u32,
+    pub gas_used: u64,
+    pub fees: u64,
+    /// In the order of the simulated transactions.
+    pub transactions: Vec<SimulatedTransaction>,
+}
+
+impl TryFrom<SchemaBlockSimulation> for BlockSimulation {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaBlockSimulation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: value.height.into(),
+            gas_used: value.gas_used.into(),
+            fees: value.fees.into(),
+            transactions: value
+                .transactions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredicateInputValidation {
+    pub index: u8,
+    pub valid: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredicateValidation {
+    pub all_valid: bool,
+    pub per_input: Vec<PredicateInputValidation>,
+}
+
+impl TryFrom<SchemaPredicateValidation> for PredicateValidation {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaPredicateValidation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            all_valid: value.all_valid,
+            per_input: value
+                .per_input
+                .into_iter()
+                .map(|input| {
+                    Ok(PredicateInputValidation {
+                        index: input.index.try_into()?,
+                        valid: input.valid,
+                    })
+                })
+                .collect::<Result<_, Self::Error>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredicatesAndFeesEstimate {
+    pub transaction: Transaction,
+    pub predicate_gas: u64,
+    pub signature_gas: u64,
+    pub verification_gas: u64,
+}
+
+impl TryFrom<SchemaPredicatesAndFeesEstimate> for PredicatesAndFeesEstimate {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaPredicatesAndFeesEstimate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction: value.transaction.try_into()?,
+            predicate_gas: value.predicate_gas.into(),
+            signature_gas: value.signature_gas.into(),
+            verification_gas: value.verification_gas.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationFailure {
+    pub path: Option<String>,
+    pub reason: String,
+}
+
+impl From<SchemaValidationFailure> for ValidationFailure {
+    fn from(value: SchemaValidationFailure) -> Self {
+        Self {
+            path: value.path,
+            reason: value.reason,
+        }
+    }
+}
+
+/// The state of the UTXO consumed by one input of the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionInputStatus {
+    /// The index of the input in the transaction.
+    pub index: u32,
+    /// The id of the coin, `None` for the message inputs.
+    pub utxo_id: Option<primitives::UtxoId>,
+    /// The nonce of the message, `None` for the coin inputs.
+    pub nonce: Option<primitives::Nonce>,
+    pub state: InputUtxoState,
+    /// The pending transactions spending the UTXO, set only for the `Reserved` state.
+    pub reserved_by: Vec<primitives::TransactionId>,
+}
+
+impl From<SchemaTransactionInputStatus> for TransactionInputStatus {
+    fn from(value: SchemaTransactionInputStatus) -> Self {
+        Self {
+            index: value.index.into(),
+            utxo_id: value.utxo_id.map(Into::into),
+            nonce: value.nonce.map(Into::into),
+            state: value.state,
+            reserved_by: value.reserved_by.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetAmount {
+    pub asset_id: primitives::AssetId,
+    pub amount: u64,
+}
+
+impl From<SchemaAssetAmount> for AssetAmount {
+    fn from(value: SchemaAssetAmount) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSummary {
+    pub sends: Vec<AssetAmount>,
+    pub receives: Vec<AssetAmount>,
+    pub fee: u64,
+    pub contracts_called: Vec<primitives::ContractId>,
+}
+
+impl From<SchemaTransactionSummary> for TransactionSummary {
+    fn from(value: SchemaTransactionSummary) -> Self {
+        Self {
+            sends: value.sends.into_iter().map(Into::into).collect(),
+            receives: value.receives.into_iter().map(Into::into).collect(),
+            fee: value.fee.into(),
+            contracts_called: value
+                .contracts_called
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<SchemaTxStatus> for TransactionStatus {
     type Error = ConversionError;
 
@@ -115,6 +926,8 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
         Ok(match status {
             SchemaTxStatus::SubmittedStatus(s) => TransactionStatus::Submitted {
                 submitted_at: s.time.0,
+                age_seconds: s.age_seconds.into(),
+                queue_position: s.queue_position.map(Into::into),
             },
             SchemaTxStatus::SuccessStatus(s) => TransactionStatus::Success {
                 block_id: s.block.id.0.to_string(),
@@ -137,6 +950,77 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
     }
 }
 
+/// The transaction decoded by the node, with the fields as the node parsed them.
+#[derive(Debug, Clone)]
+pub struct DecodedTransaction {
+    /// The id computed by the node for its chain id.
+    pub id: primitives::TransactionId,
+    /// `None` for the `Mint` transactions.
+    pub gas_price: Option<u64>,
+    /// `None` for the `Mint` transactions.
+    pub gas_limit: Option<u64>,
+    /// `None` for the `Mint` transactions.
+    pub maturity: Option<u32>,
+    pub is_script: bool,
+    pub is_create: bool,
+    pub is_mint: bool,
+    /// `None` for the `Mint` transactions.
+    pub requires_signatures: Option<bool>,
+    /// `None` for the `Mint` transactions.
+    pub predicate_inputs_count: Option<u32>,
+    /// `true` if the transaction has predicate inputs and doesn't require signatures.
+    pub is_predicate_only: Option<bool>,
+    /// The transaction decoded from the canonical encoding returned by the node.
+    pub transaction: Transaction,
+}
+
+impl TryFrom<SchemaDecodedTransaction> for DecodedTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaDecodedTransaction) -> Result<Self, Self::Error> {
+        let bytes = value.raw_payload.0 .0;
+        let transaction = Transaction::from_bytes(bytes.as_slice())
+            .map_err(ConversionError::TransactionFromBytesError)?;
+
+        Ok(Self {
+            id: value.id.into(),
+            gas_price: value.gas_price.map(Into::into),
+            gas_limit: value.gas_limit.map(Into::into),
+            maturity: value.maturity.map(Into::into),
+            is_script: value.is_script,
+            is_create: value.is_create,
+            is_mint: value.is_mint,
+            requires_signatures: value.requires_signatures,
+            predicate_inputs_count: value
+                .predicate_inputs_count
+                .map(|count| count as u32),
+            is_predicate_only: value.is_predicate_only,
+            transaction,
+        })
+    }
+}
+
+impl TryFrom<SchemaTransactionLookup> for TransactionLookup {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaTransactionLookup) -> Result<Self, Self::Error> {
+        Ok(match value {
+            SchemaTransactionLookup::Transaction(tx) => {
+                TransactionLookup::Found(tx.try_into()?)
+            }
+            SchemaTransactionLookup::SqueezedOutStatus(status) => {
+                TransactionLookup::SqueezedOut {
+                    reason: status.reason,
+                }
+            }
+            SchemaTransactionLookup::UnknownTransaction(_) => TransactionLookup::Unknown,
+            SchemaTransactionLookup::Unknown => {
+                return Err(Self::Error::UnknownVariant("SchemaTransactionLookup"))
+            }
+        })
+    }
+}
+
 impl TryFrom<OpaqueTransaction> for TransactionResponse {
     type Error = ConversionError;
 
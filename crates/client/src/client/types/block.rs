@@ -61,6 +61,12 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Debug)]
+pub struct ChainTip {
+    pub height: u32,
+    pub block_id: BlockId,
+}
+
 // GraphQL Translation
 
 impl From<schema::block::Header> for Header {
@@ -143,3 +149,12 @@ impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
         }
     }
 }
+
+impl From<schema::block::ChainTip> for ChainTip {
+    fn from(value: schema::block::ChainTip) -> Self {
+        Self {
+            height: value.height.into(),
+            block_id: value.block_id.into(),
+        }
+    }
+}
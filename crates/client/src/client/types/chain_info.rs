@@ -1,6 +1,7 @@
 use crate::client::{
     schema,
     types::{
+        primitives::ChainId,
         Block,
         ConsensusParameters,
         GasCosts,
@@ -30,3 +31,19 @@ impl From<schema::chain::ChainInfo> for ChainInfo {
         }
     }
 }
+
+pub struct ChainParameters {
+    pub chain_id: ChainId,
+    pub consensus_parameters: ConsensusParameters,
+    pub gas_costs: GasCosts,
+}
+
+impl From<schema::chain::ChainParameters> for ChainParameters {
+    fn from(value: schema::chain::ChainParameters) -> Self {
+        Self {
+            chain_id: value.chain_id.0.into(),
+            consensus_parameters: value.consensus_parameters.into(),
+            gas_costs: value.gas_costs.into(),
+        }
+    }
+}
@@ -3,11 +3,13 @@ use crate::client::{
     types::primitives::{
         Address,
         AssetId,
+        Bytes32,
         Nonce,
         UtxoId,
     },
     PaginatedResult,
 };
+use tai64::Tai64;
 
 #[derive(Debug)]
 pub enum CoinType {
@@ -36,6 +38,15 @@ pub struct Coin {
     pub owner: Address,
 }
 
+/// The reservations of the coins made by the `reserve_coins`.
+#[derive(Debug, PartialEq)]
+pub struct CoinReservation {
+    /// The token of the reservations, required to check or extend them.
+    pub token: Bytes32,
+    /// The time when the reservations expire.
+    pub expires_at: Tai64,
+}
+
 #[derive(Debug, PartialEq)]
 pub struct MessageCoin {
     pub amount: u64,
@@ -72,6 +83,15 @@ impl From<schema::coins::Coin> for Coin {
     }
 }
 
+impl From<schema::coins::CoinReservation> for CoinReservation {
+    fn from(value: schema::coins::CoinReservation) -> Self {
+        Self {
+            token: value.token.into(),
+            expires_at: value.expires_at.0,
+        }
+    }
+}
+
 impl From<schema::coins::MessageCoin> for MessageCoin {
     fn from(value: schema::coins::MessageCoin) -> Self {
         Self {
@@ -132,6 +132,8 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// The column of the table of all transactions that called the contract
+    TransactionsByContractBlockIdx = 25,
 }
 
 impl Column {
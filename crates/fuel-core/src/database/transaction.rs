@@ -1,5 +1,8 @@
 use crate::{
-    database::Database,
+    database::{
+        Column,
+        Database,
+    },
     state::in_memory::transaction::MemoryTransactionView,
 };
 use fuel_core_storage::{
@@ -23,6 +26,13 @@ pub struct DatabaseTransaction {
     database: Database,
 }
 
+impl DatabaseTransaction {
+    /// Returns the keys of the `column` modified or removed within the transaction.
+    pub fn changed_keys(&self, column: Column) -> Vec<Vec<u8>> {
+        self.changes.changed_keys(column)
+    }
+}
+
 impl AsRef<Database> for DatabaseTransaction {
     fn as_ref(&self) -> &Database {
         &self.database
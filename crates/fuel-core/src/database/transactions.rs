@@ -18,6 +18,7 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         BlockHeight,
+        ContractId,
     },
     services::txpool::TransactionStatus,
 };
@@ -84,6 +85,44 @@ impl Database {
         )
     }
 
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx] => transaction id`
+    /// of the transactions that called the contract. The order and the cursor are the same
+    /// as for the `owned_transactions`.
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(TxPointer, Bytes32)>> + '_ {
+        let prefix = Address::new(*contract_id);
+        let start = start.map(|cursor| {
+            owned_tx_index_key(&prefix, cursor.block_height, cursor.tx_idx)
+        });
+        self.iter_all_filtered::<OwnedTransactionIndexKey, Bytes32, _, _>(
+            Column::TransactionsByContractBlockIdx,
+            Some(prefix),
+            start,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
+        })
+    }
+
+    pub fn record_tx_id_contract(
+        &self,
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        tx_id: &Bytes32,
+    ) -> DatabaseResult<Option<Bytes32>> {
+        self.insert(
+            owned_tx_index_key(&Address::new(**contract_id), block_height, tx_idx),
+            Column::TransactionsByContractBlockIdx,
+            tx_id,
+        )
+    }
+
     pub fn update_tx_status(
         &self,
         id: &Bytes32,
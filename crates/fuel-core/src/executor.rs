@@ -2,6 +2,7 @@ use crate::database::{
     transaction::DatabaseTransaction,
     transactions::TransactionIndex,
     vm_database::VmDatabase,
+    Column,
     Database,
 };
 use fuel_core_executor::{
@@ -12,6 +13,8 @@ use fuel_core_storage::{
     tables::{
         Coins,
         ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -22,6 +25,7 @@ use fuel_core_storage::{
         StorageTransaction,
         Transaction as StorageTransactionTrait,
     },
+    ContractsStateKey,
     StorageAsMut,
     StorageAsRef,
     StorageInspect,
@@ -40,6 +44,7 @@ use fuel_core_types::{
         contract::ContractUtxoInfo,
     },
     fuel_asm::{
+        op,
         RegId,
         Word,
     },
@@ -79,6 +84,7 @@ use fuel_core_types::{
     },
     fuel_types::{
         BlockHeight,
+        ContractId,
         MessageId,
     },
     fuel_vm::{
@@ -102,12 +108,15 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            BlockSimulation,
             Error as ExecutorError,
             ExecutionKind,
             ExecutionResult,
             ExecutionType,
             ExecutionTypes,
             Result as ExecutorResult,
+            SimulatedTransaction,
+            StorageSlotDelta,
             TransactionExecutionResult,
             TransactionExecutionStatus,
             TransactionValidityError,
@@ -119,6 +128,7 @@ use fuel_core_types::{
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     borrow::Cow,
+    collections::HashSet,
     ops::{
         Deref,
         DerefMut,
@@ -270,6 +280,226 @@ where
         &self,
         component: Components<Transaction>,
         utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: vec![component.transactions_source],
+            gas_limit: component.gas_limit,
+        };
+        self.dry_run_on(component, &self.database, utxo_validation)
+    }
+
+    /// Executes the transactions the same way as [`Self::dry_run`], one after another
+    /// in exactly the given order, as if they were included into the same block.
+    ///
+    /// The changes of the contract storage and the UTXOs made by a transaction are
+    /// visible to the following ones, so a transaction can spend the outputs of
+    /// a previous one. Returns the receipts of each transaction in the same order.
+    pub fn dry_run_many(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        self.dry_run_on(component, &self.database, utxo_validation)
+    }
+
+    /// Executes each transaction the same way as [`Self::dry_run`], independently
+    /// of the others. Unlike [`Self::dry_run_many`], no transaction sees the changes
+    /// of the other ones.
+    ///
+    /// Each transaction runs on its own fork of the current state in the same block
+    /// context, and the fork is dropped after it. Returns the receipts of each
+    /// transaction in the same order.
+    pub fn dry_run_parallel(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        component
+            .transactions_source
+            .into_iter()
+            .map(|tx| {
+                let component = Components {
+                    header_to_produce: component.header_to_produce.clone(),
+                    transactions_source: vec![tx],
+                    gas_limit: component.gas_limit,
+                };
+                let receipts =
+                    self.dry_run_on(component, &self.database, utxo_validation)?;
+                Ok(receipts.into_iter().flatten().collect())
+            })
+            .collect()
+    }
+
+    /// Executes the transaction the same way as [`Self::dry_run`], but any call
+    /// to the `fail_contract` reverts.
+    ///
+    /// The code of the `fail_contract` is replaced with the code that reverts
+    /// only in the state of the dry run, which is never committed. If the contract
+    /// doesn't exist, the result is the same as of [`Self::dry_run`].
+    pub fn dry_run_with_forced_failure(
+        &self,
+        component: Components<Transaction>,
+        fail_contract: ContractId,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let mut dry_run_db = self.database.transaction();
+        if dry_run_db
+            .as_ref()
+            .storage::<ContractsRawCode>()
+            .contains_key(&fail_contract)?
+        {
+            let revert = op::rvrt(RegId::ONE).to_bytes();
+            dry_run_db
+                .as_mut()
+                .storage::<ContractsRawCode>()
+                .insert(&fail_contract, revert.as_slice())?;
+        }
+
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: vec![component.transactions_source],
+            gas_limit: component.gas_limit,
+        };
+        self.dry_run_on(component, dry_run_db.as_ref(), utxo_validation)
+        // drop `dry_run_db` without committing to avoid altering state.
+    }
+
+    /// Executes the `transactions` as a block the same way as the block production,
+    /// without committing the result.
+    ///
+    /// The transactions are taken in the given order while they fit into the gas limit
+    /// of the block, the same way as the `TxPool` selects them. The transactions that
+    /// don't fit are excluded, and the invalid ones are skipped by the execution.
+    /// The repeated transaction is skipped as a duplicate of its first occurrence.
+    pub fn simulate_block(
+        &self,
+        component: Components<Vec<Transaction>>,
+    ) -> ExecutorResult<BlockSimulation> {
+        let height = *component.header_to_produce.height();
+        let chain_id = &self.config.transaction_parameters.chain_id;
+
+        // The ids of all transactions in the given order with the outcome decided
+        // before the execution, `None` for the transactions passed to the execution.
+        let mut outcomes = vec![];
+        let mut seen = HashSet::new();
+        let mut selected = vec![];
+        let mut used_gas: Word = 0;
+        for tx in component.transactions_source {
+            let id = tx.id(chain_id);
+            // The executor reports its outcomes by the id, so each id is executed once
+            if !seen.insert(id) {
+                let reason = ExecutorError::TransactionIdCollision(id).to_string();
+                outcomes.push((id, Some(SimulatedTransaction::Skipped { id, reason })));
+                continue
+            }
+            // The invalid transactions don't take the block space and are rejected
+            // by the execution.
+            let max_gas = match tx
+                .clone()
+                .into_checked_basic(height, &self.config.transaction_parameters)
+                .map(CheckedTransaction::from)
+            {
+                Ok(CheckedTransaction::Script(script)) => script.metadata().fee.max_gas(),
+                Ok(CheckedTransaction::Create(create)) => create.metadata().fee.max_gas(),
+                Ok(CheckedTransaction::Mint(_)) | Err(_) => 0,
+            };
+            match used_gas.checked_add(max_gas) {
+                Some(new_used_gas) if new_used_gas <= component.gas_limit => {
+                    used_gas = new_used_gas;
+                    outcomes.push((id, None));
+                    selected.push(tx);
+                }
+                _ => outcomes.push((id, Some(SimulatedTransaction::Excluded { id }))),
+            }
+        }
+
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(selected),
+            gas_limit: component.gas_limit,
+        };
+        let (
+            ExecutionResult {
+                block,
+                skipped_transactions,
+                tx_status,
+            },
+            temporary_db,
+        ) = self
+            .execute_inner(
+                ExecutionTypes::Production(component),
+                &self.database,
+                self.config.as_ref().into(),
+            )?
+            .into();
+
+        // The executor processes the selected transactions in order, so their
+        // outcomes are matched by the position. The coinbase is the first status.
+        let mut results = tx_status.into_iter().skip(1).peekable();
+        let mut skipped = skipped_transactions.into_iter().peekable();
+
+        let mut gas_used: Word = 0;
+        let mut transactions = Vec::with_capacity(outcomes.len());
+        for (id, outcome) in outcomes {
+            if let Some(outcome) = outcome {
+                transactions.push(outcome);
+                continue
+            }
+            if let Some((_, err)) = skipped.next_if(|(skipped_id, _)| *skipped_id == id) {
+                transactions.push(SimulatedTransaction::Skipped {
+                    id,
+                    reason: err.to_string(),
+                });
+                continue
+            }
+            let result = results
+                .next_if(|status| status.id == id)
+                .ok_or(ExecutorError::InvalidTransactionOutcome { transaction_id: id })?
+                .result;
+            let receipts = StorageInspect::<Receipts>::get(temporary_db.as_ref(), &id)?
+                .map(|receipts| receipts.into_owned())
+                .unwrap_or_default();
+            gas_used = gas_used.saturating_add(
+                receipts
+                    .iter()
+                    .filter_map(|receipt| match receipt {
+                        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                        _ => None,
+                    })
+                    .sum(),
+            );
+            transactions.push(SimulatedTransaction::Included {
+                id,
+                result,
+                receipts,
+            });
+        }
+
+        // The coinbase transaction collects the fees of the block.
+        let fees = match block.transactions().first() {
+            Some(Transaction::Mint(mint)) => mint
+                .outputs()
+                .iter()
+                .filter_map(|output| output.amount())
+                .sum(),
+            _ => 0,
+        };
+
+        Ok(BlockSimulation {
+            height,
+            gas_used,
+            fees,
+            transactions,
+        })
+        // drop `temporary_db` without committing to avoid altering state.
+    }
+
+    fn dry_run_on(
+        &self,
+        component: Components<Vec<Transaction>>,
+        database: &Database,
+        utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
@@ -279,9 +509,9 @@ where
 
         let component = Components {
             header_to_produce: component.header_to_produce,
-            transactions_source: OnceTransactionsSource::new(vec![
+            transactions_source: OnceTransactionsSource::new(
                 component.transactions_source,
-            ]),
+            ),
             gas_limit: component.gas_limit,
         };
 
@@ -293,7 +523,7 @@ where
             },
             temporary_db,
         ) = self
-            .execute_without_commit(ExecutionTypes::DryRun(component), options)?
+            .execute_inner(ExecutionTypes::DryRun(component), database, options)?
             .into();
 
         // If one of the transactions fails, return an error.
@@ -315,6 +545,81 @@ where
             .map_err(Into::into)
         // drop `temporary_db` without committing to avoid altering state.
     }
+
+    /// Executes the transaction the same way as [`Self::dry_run`] and returns the changes
+    /// of the contract storage slots along with their values before the execution.
+    pub fn dry_run_storage_delta(
+        &self,
+        component: Components<Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<StorageSlotDelta>> {
+        // fallback to service config value if no utxo_validation override is provided
+        let utxo_validation =
+            utxo_validation.unwrap_or(self.config.utxo_validation_default);
+
+        let options = ExecutionOptions { utxo_validation };
+
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(vec![
+                component.transactions_source,
+            ]),
+            gas_limit: component.gas_limit,
+        };
+
+        // The result of the execution is committed into the intermediate transaction
+        // to find the touched slots. The intermediate transaction itself is dropped.
+        let dry_run_db = self.database.transaction();
+        let (
+            ExecutionResult {
+                skipped_transactions,
+                ..
+            },
+            temporary_db,
+        ) = self
+            .execute_inner(ExecutionTypes::DryRun(component), &dry_run_db, options)?
+            .into();
+
+        // If one of the transactions fails, return an error.
+        if let Some((_, err)) = skipped_transactions.into_iter().next() {
+            return Err(err)
+        }
+        temporary_db.commit()?;
+
+        let mut deltas = dry_run_db
+            .changed_keys(Column::ContractsState)
+            .into_iter()
+            .map(|raw_key| -> ExecutorResult<StorageSlotDelta> {
+                let contract_id =
+                    ContractId::try_from(&raw_key[..32]).expect("The slice has size 32");
+                let key =
+                    Bytes32::try_from(&raw_key[32..64]).expect("The slice has size 32");
+                let state_key = ContractsStateKey::new(&contract_id, &key);
+
+                // The prior value is read from the state before the execution.
+                let before =
+                    StorageInspect::<ContractsState>::get(&self.database, &state_key)?
+                        .map(Cow::into_owned);
+                let after = StorageInspect::<ContractsState>::get(
+                    dry_run_db.as_ref(),
+                    &state_key,
+                )?
+                .map(Cow::into_owned)
+                .unwrap_or_default();
+
+                Ok(StorageSlotDelta {
+                    contract_id,
+                    key,
+                    before,
+                    after,
+                })
+            })
+            .collect::<ExecutorResult<Vec<_>>>()?;
+        deltas.sort_by_key(|delta| (delta.contract_id, delta.key));
+
+        Ok(deltas)
+        // drop `dry_run_db` without committing to avoid altering state.
+    }
 }
 
 mod private {
@@ -469,6 +774,11 @@ where
         // save the associated owner for each transaction in the block
         self.index_tx_owners_for_block(&result.block, &mut block_db_transaction)?;
 
+        // save the called contracts for each transaction in the block
+        if self.config.index_contract_transactions {
+            self.index_tx_contracts_for_block(&result.block, &mut block_db_transaction)?;
+        }
+
         // ------------ GraphQL API Functionality   END ------------
 
         // insert block into database
@@ -1583,6 +1893,45 @@ where
         Ok(())
     }
 
+    /// Associate all transactions within a block to the contracts they called
+    fn index_tx_contracts_for_block(
+        &self,
+        block: &Block,
+        block_db_transaction: &mut DatabaseTransaction,
+    ) -> ExecutorResult<()> {
+        let block_height = *block.header().height();
+        let db = block_db_transaction.deref_mut();
+        for (tx_idx, tx) in block.transactions().iter().enumerate() {
+            let tx_id = tx.id(&self.config.transaction_parameters.chain_id);
+            let receipts = db
+                .storage::<Receipts>()
+                .get(&tx_id)?
+                .map(Cow::into_owned)
+                .unwrap_or_default();
+            let mut contracts = receipts
+                .iter()
+                .filter_map(|receipt| match receipt {
+                    Receipt::Call { to, .. } => Some(*to),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            // dedupe contracts called several times prior to indexing
+            contracts.sort();
+            contracts.dedup();
+
+            for contract_id in contracts {
+                db.record_tx_id_contract(
+                    &contract_id,
+                    block_height,
+                    tx_idx as TransactionIndex,
+                    &tx_id,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
     /// Index the tx id by owner for all of the inputs and outputs
     fn persist_owners_index(
         &self,
@@ -8,12 +8,23 @@ use fuel_core_types::{
     fuel_vm::GasCosts,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    time::Duration,
+};
 
+pub mod coin_reservations;
+pub mod dry_run_limit;
+pub mod idempotency;
 #[cfg(feature = "metrics")]
 pub(crate) mod metrics_extension;
 pub mod ports;
+pub mod rate_limit;
 pub mod service;
+pub mod submission_queue;
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -24,11 +35,79 @@ pub struct Config {
     pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
+    /// The maximum number of submitted transactions waiting for the `TxPool` at once.
+    pub submission_queue_depth: usize,
+    /// The maximum number of queries from one IP within the rate limit window.
+    pub max_queries_per_minute: Option<u32>,
+    /// The maximum number of mutations from one IP within the rate limit window.
+    pub max_mutations_per_minute: Option<u32>,
+    /// The maximum number of blocks scanned by `feeStatistics`.
+    pub max_fee_statistics_blocks: u32,
+    /// Allows the `resubmit` mutation.
+    pub resubmit_enabled: bool,
+    /// The maximum wall-clock time of the script execution in `dry_run`.
+    pub dry_run_timeout: Option<Duration>,
+    /// The maximum number of `dry_run` and `dry_run_many` executing at once.
+    pub max_concurrent_dry_runs: usize,
+    /// The maximum total size in bytes of the transactions of one page.
+    pub max_page_bytes: Option<usize>,
+    /// The maximum number of receipts returned by `receipts` of the transaction.
+    pub max_receipts: Option<usize>,
+    /// The interval of the keep-alive comments sent to the idle subscriptions.
+    pub subscription_keep_alive_interval: Duration,
+    /// Allows the `dry_run_trace` and `dry_run_coverage` mutations.
+    pub dry_run_trace_enabled: bool,
+    /// Allows the test witnesses in `dry_run` and `predicates_valid`.
+    pub test_witnesses_enabled: bool,
+    /// The maximum gas of one predicate in the estimation of the predicates.
+    pub estimate_predicates_gas_limit: Option<u64>,
+    /// Allows the `transactions_by_contract` query.
+    pub contract_transactions_index_enabled: bool,
+    /// The maximum size in bytes of the transaction passed to the endpoints.
+    pub max_tx_size: usize,
+    /// The addresses of the clients allowed to call `submit_prevalidated`. It is
+    /// the peer address of the connection, so behind a reverse proxy it is the proxy.
+    pub trusted_submitters: Vec<IpAddr>,
+    /// The maximum amount of gas used by the transactions of the block.
+    pub block_gas_limit: u64,
     pub transaction_parameters: ConsensusParameters,
     pub gas_costs: GasCosts,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
 }
 
+/// The upper bound of the fixed-size fields of the transaction, and of one of its
+/// inputs, outputs, storage slots, or witnesses, without their variable-size data.
+const MAX_FIXED_PART_SIZE: u64 = 256;
+
+/// The size of the signature, the usual content of the witnesses besides
+/// the bytecode of the contract.
+const SIGNATURE_SIZE: u64 = 64;
+
+/// The default `max_tx_size`: the upper bound of the size of the transaction valid
+/// under the consensus `params`. Each part of the transaction takes its largest size,
+/// and the parts of the scripts and of the contract creations are summed, so it bounds
+/// both kinds. One witness is the bytecode of the largest contract, and the other
+/// witnesses are signatures.
+pub fn max_transaction_size(params: &ConsensusParameters) -> usize {
+    let input = MAX_FIXED_PART_SIZE
+        .saturating_add(params.max_predicate_length)
+        .saturating_add(params.max_predicate_data_length)
+        .saturating_add(params.max_message_data_length);
+    let witnesses = params.contract_max_size.saturating_add(
+        params
+            .max_witnesses
+            .saturating_mul(MAX_FIXED_PART_SIZE + SIGNATURE_SIZE),
+    );
+    let size = MAX_FIXED_PART_SIZE
+        .saturating_add(params.max_script_length)
+        .saturating_add(params.max_script_data_length)
+        .saturating_add(params.max_storage_slots.saturating_mul(MAX_FIXED_PART_SIZE))
+        .saturating_add(params.max_inputs.saturating_mul(input))
+        .saturating_add(params.max_outputs.saturating_mul(MAX_FIXED_PART_SIZE))
+        .saturating_add(witnesses);
+    usize::try_from(size).unwrap_or(usize::MAX)
+}
+
 pub trait IntoApiResult<T> {
     fn into_api_result<NewT, E>(self) -> Result<Option<NewT>, E>
     where
@@ -0,0 +1,95 @@
+use fuel_core_types::{
+    fuel_tx::UtxoId,
+    fuel_types::Bytes32,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// How long the coin is reserved if the TTL isn't specified.
+pub const DEFAULT_COIN_RESERVATION_TTL: Duration = Duration::from_secs(60);
+
+/// The longest TTL of the reservation, the longer ones are shortened to it.
+pub const MAX_COIN_RESERVATION_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// The maximum number of the coins reserved by one call.
+pub const MAX_RESERVED_COINS_PER_CALL: usize = 256;
+
+/// The maximum number of the reservations kept by the node at once.
+pub const MAX_COIN_RESERVATIONS: usize = 100_000;
+
+/// The in-memory advisory reservations of the coins, used by the wallets to not
+/// select the same coin for several transactions built at once.
+///
+/// Each reservation belongs to the token returned to the caller, and is visible
+/// only with this token. So the callers can't extend, override, or observe
+/// the reservations of each other.
+///
+/// The reservations aren't enforced by the `TxPool` or the consensus, and are
+/// lost on the restart of the node.
+#[derive(Clone, Default)]
+pub struct CoinReservations {
+    /// The time when the reservation of the coin made with the token expires.
+    expirations: Arc<Mutex<HashMap<(Bytes32, UtxoId), Instant>>>,
+}
+
+impl CoinReservations {
+    /// Creates the token of the new reservations.
+    pub fn new_token() -> Bytes32 {
+        rand::random::<[u8; 32]>().into()
+    }
+
+    /// Reserves the `coins` with the `token` for the `ttl`, capped by
+    /// the [`MAX_COIN_RESERVATION_TTL`]. The reservation of the coin already
+    /// reserved with the same `token` is extended.
+    ///
+    /// Fails if the node would keep more than [`MAX_COIN_RESERVATIONS`] reservations.
+    ///
+    /// Returns the applied TTL.
+    pub fn reserve(
+        &self,
+        token: Bytes32,
+        coins: Vec<UtxoId>,
+        ttl: Duration,
+    ) -> anyhow::Result<Duration> {
+        let ttl = ttl.min(MAX_COIN_RESERVATION_TTL);
+        let expires_at = Instant::now() + ttl;
+
+        let mut expirations = self.expirations.lock();
+        Self::evict_expired(&mut expirations);
+        let new = coins
+            .iter()
+            .filter(|coin| !expirations.contains_key(&(token, **coin)))
+            .count();
+        if expirations.len() + new > MAX_COIN_RESERVATIONS {
+            return Err(anyhow::anyhow!(
+                "The node already keeps the maximum number of the coin reservations"
+            ))
+        }
+        for coin in coins {
+            expirations.insert((token, coin), expires_at);
+        }
+        Ok(ttl)
+    }
+
+    /// Returns `true` if the `coin` has the reservation made with the `token`
+    /// that didn't expire yet.
+    pub fn is_reserved(&self, token: &Bytes32, coin: &UtxoId) -> bool {
+        self.expirations
+            .lock()
+            .get(&(*token, *coin))
+            .map(|expires_at| *expires_at > Instant::now())
+            .unwrap_or(false)
+    }
+
+    fn evict_expired(expirations: &mut HashMap<(Bytes32, UtxoId), Instant>) {
+        let now = Instant::now();
+        expirations.retain(|_, expires_at| *expires_at > now);
+    }
+}
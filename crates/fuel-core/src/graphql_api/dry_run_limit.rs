@@ -0,0 +1,39 @@
+use crate::graphql_api::submission_queue::NODE_BUSY;
+use async_graphql::ErrorExtensions;
+use std::sync::Arc;
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+
+/// The limit of the dry-runs executed at once.
+///
+/// Each dry-run executes the transaction in its own VM, so many concurrent dry-runs
+/// can exhaust the CPU of the node. When all `limit` permits are taken, new dry-runs
+/// are rejected immediately with the [`NODE_BUSY`] error instead of waiting.
+/// Clients should treat this error as retryable.
+#[derive(Clone)]
+pub struct DryRunLimiter {
+    permits: Arc<Semaphore>,
+    limit: usize,
+}
+
+impl DryRunLimiter {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(limit)),
+            limit,
+        }
+    }
+
+    /// Takes a permit for one dry-run. The permit is released when it is dropped.
+    pub fn try_acquire(&self) -> async_graphql::Result<OwnedSemaphorePermit> {
+        self.permits.clone().try_acquire_owned().map_err(|_| {
+            async_graphql::Error::new(format!(
+                "The node is busy, {} dry-runs are already executing",
+                self.limit
+            ))
+            .extend_with(|_, e| e.set("code", NODE_BUSY))
+        })
+    }
+}
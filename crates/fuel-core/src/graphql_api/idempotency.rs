@@ -0,0 +1,196 @@
+use crate::schema::tx::types::InsertionStatus;
+use async_graphql::ErrorExtensions;
+use fuel_core_types::fuel_types::Bytes32;
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    net::IpAddr,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The `code` extension of the error returned when the idempotency key was already
+/// used for another transaction.
+pub const IDEMPOTENCY_CONFLICT: &str = "IDEMPOTENCY_CONFLICT";
+
+/// The `code` extension of the error returned when the submission with the same
+/// idempotency key is still in progress.
+pub const IDEMPOTENCY_IN_PROGRESS: &str = "IDEMPOTENCY_IN_PROGRESS";
+
+/// The maximum number of idempotency keys remembered by the node.
+pub const IDEMPOTENCY_CACHE_CAPACITY: usize = 10_000;
+
+/// How long the node remembers the idempotency key after the submission.
+pub const IDEMPOTENCY_KEY_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The idempotency key namespaced by the address of the client, if it is known.
+type Key = (Option<IpAddr>, String);
+
+struct Entry {
+    tx_id: Bytes32,
+    /// The result of the submission, or `None` while it is in progress.
+    result: Option<async_graphql::Result<InsertionStatus>>,
+    inserted_at: Instant,
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: HashMap<Key, Entry>,
+    /// The keys in the order of insertion, so the oldest ones are evicted first.
+    order: VecDeque<Key>,
+}
+
+/// The outcome of the [`IdempotencyCache::reserve`].
+pub enum Reservation {
+    /// The result of the previous submission made with the key.
+    Known(async_graphql::Result<InsertionStatus>),
+    /// The key is reserved for the new submission.
+    Reserved(IdempotencyGuard),
+}
+
+/// The reservation of the idempotency key for the submission in progress.
+///
+/// The result is remembered by [`IdempotencyGuard::complete`]. If the guard is dropped
+/// without it, the key is released, so the retry is processed again.
+pub struct IdempotencyGuard {
+    cache: IdempotencyCache,
+    key: Key,
+    completed: bool,
+}
+
+impl IdempotencyGuard {
+    /// Remembers the `result` of the submission made with the reserved key.
+    pub fn complete(mut self, result: async_graphql::Result<InsertionStatus>) {
+        let mut inner = self.cache.inner.lock();
+        if let Some(entry) = inner.entries.get_mut(&self.key) {
+            entry.result = Some(result);
+        }
+        self.completed = true;
+    }
+}
+
+impl Drop for IdempotencyGuard {
+    fn drop(&mut self) {
+        if self.completed {
+            return
+        }
+        let mut inner = self.cache.inner.lock();
+        if matches!(inner.entries.get(&self.key), Some(entry) if entry.result.is_none()) {
+            inner.entries.remove(&self.key);
+            inner.order.retain(|key| key != &self.key);
+        }
+    }
+}
+
+/// The bounded in-memory cache of the results of submissions made with
+/// an idempotency key.
+///
+/// The keys are namespaced by the address of the client, so the clients can't
+/// observe or block the submissions of each other. The key is reserved atomically
+/// before the submission, so the concurrent submissions with the same key are
+/// not processed twice. A repeated submission with the same key within the TTL
+/// returns the original result instead of being processed again. The oldest keys
+/// are evicted when the cache reaches its capacity.
+#[derive(Clone)]
+pub struct IdempotencyCache {
+    inner: Arc<Mutex<Inner>>,
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl IdempotencyCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner: Default::default(),
+            capacity,
+            ttl,
+        }
+    }
+
+    /// Reserves the `key` of the `client` for the submission of the `tx_id`, or
+    /// returns the result of the previous submission made with the `key`.
+    ///
+    /// Fails with the [`IDEMPOTENCY_CONFLICT`] error if the `key` was used
+    /// for a transaction other than `tx_id`, and with the [`IDEMPOTENCY_IN_PROGRESS`]
+    /// error if the previous submission with the `key` hasn't finished yet.
+    pub fn reserve(
+        &self,
+        client: Option<IpAddr>,
+        key: &str,
+        tx_id: &Bytes32,
+    ) -> async_graphql::Result<Reservation> {
+        let key = (client, key.to_string());
+        let mut inner = self.inner.lock();
+        self.evict_expired(&mut inner);
+
+        match inner.entries.get(&key) {
+            Some(entry) if entry.tx_id != *tx_id => {
+                let message = format!(
+                    "Idempotency key `{}` is already used by the transaction {:#x}",
+                    key.1, entry.tx_id
+                );
+                return Err(async_graphql::Error::new(message)
+                    .extend_with(|_, e| e.set("code", IDEMPOTENCY_CONFLICT)))
+            }
+            Some(Entry {
+                result: Some(result),
+                ..
+            }) => return Ok(Reservation::Known(result.clone())),
+            Some(_) => {
+                let message = format!(
+                    "The submission with the idempotency key `{}` is in progress",
+                    key.1
+                );
+                return Err(async_graphql::Error::new(message)
+                    .extend_with(|_, e| e.set("code", IDEMPOTENCY_IN_PROGRESS)))
+            }
+            None => {}
+        }
+
+        while inner.entries.len() >= self.capacity {
+            match inner.order.pop_front() {
+                Some(oldest) => {
+                    inner.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+        inner.order.push_back(key.clone());
+        inner.entries.insert(
+            key.clone(),
+            Entry {
+                tx_id: *tx_id,
+                result: None,
+                inserted_at: Instant::now(),
+            },
+        );
+
+        Ok(Reservation::Reserved(IdempotencyGuard {
+            cache: self.clone(),
+            key,
+            completed: false,
+        }))
+    }
+
+    fn evict_expired(&self, inner: &mut Inner) {
+        while let Some(oldest) = inner.order.front() {
+            let expired = inner
+                .entries
+                .get(oldest)
+                .map(|entry| entry.inserted_at.elapsed() >= self.ttl)
+                .unwrap_or(true);
+            if !expired {
+                break
+            }
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.entries.remove(&oldest);
+            }
+        }
+    }
+}
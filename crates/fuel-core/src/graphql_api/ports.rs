@@ -35,6 +35,7 @@ use fuel_core_types::{
         Message,
     },
     fuel_tx::{
+        Input,
         Receipt,
         Transaction,
         TxId,
@@ -47,17 +48,27 @@ use fuel_core_types::{
         BlockHeight,
         ContractId,
         Nonce,
+        Word,
     },
     services::{
+        executor::{
+            BlockSimulation,
+            StorageSlotDelta,
+        },
         graphql_api::ContractBalance,
         txpool::{
+            ArcPoolTx,
             InsertionResult,
             TransactionStatus,
+            TransactionStatusRecord,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 /// The database port expected by GraphQL API service.
 pub trait DatabasePort:
@@ -102,6 +113,14 @@ pub trait DatabaseTransactions:
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the ids of the transactions that called the contract.
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -160,15 +179,52 @@ pub trait TxPoolPort: Send + Sync {
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    /// Returns the submission times of the transactions with the `ids` in the same
+    /// order, taken under one lock. `None` for the transactions not in the `TxPool`.
+    fn submission_times(&self, ids: Vec<TxId>) -> Vec<Option<Tai64>>;
+
+    /// Returns the time since the `UNIX_EPOCH` when the committed transaction was
+    /// submitted to the `TxPool`, if the `TxPool` recorded it.
+    fn committed_submission_time(&self, id: TxId) -> Option<Duration>;
+
+    /// Returns the statuses of the transaction recorded by the `TxPool`: the
+    /// submissions and the removals, which aren't persisted.
+    fn status_history(&self, id: TxId) -> Vec<TransactionStatusRecord>;
+
+    /// Returns the pending transactions that spend any of the coins or messages
+    /// of the `inputs`.
+    fn conflicting_transactions(&self, inputs: &[Input]) -> Vec<ArcPoolTx>;
+
+    /// Returns the pending transactions with an input that references the `utxo_id`.
+    fn transactions_spending_utxo(&self, utxo_id: UtxoId) -> Vec<ArcPoolTx>;
+
+    /// Returns all pending transactions, taken at once.
+    fn pending_transactions(&self) -> Vec<ArcPoolTx>;
+
+    /// Returns the gas prices of all pending transactions, taken at once.
+    fn gas_prices(&self) -> Vec<Word>;
+
+    /// Returns the position of the pending transaction in the order of the inclusion,
+    /// from the highest gas price, or `None` if the transaction isn't pending.
+    fn queue_position(&self, id: TxId) -> Option<usize>;
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>>;
 
+    /// Inserts the `txs` without verifying their signatures and predicates.
+    fn insert_prevalidated(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>>;
+
+    /// Subscribes to the status updates of the transaction. Fails if the txpool
+    /// has too many subscriptions.
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
-    ) -> BoxFuture<'_, BoxStream<TxStatusMessage>>;
+    ) -> BoxFuture<'_, anyhow::Result<BoxStream<TxStatusMessage>>>;
 }
 
 #[async_trait]
@@ -177,6 +233,43 @@ pub trait DryRunExecution {
         &self,
         transaction: Transaction,
         height: Option<BlockHeight>,
+        block_time: Option<Tai64>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Receipt>>;
+
+    async fn dry_run_many(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Vec<Receipt>>>;
+
+    async fn dry_run_parallel(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Vec<Receipt>>>;
+
+    async fn simulate_block(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        gas_limit: Word,
+    ) -> anyhow::Result<BlockSimulation>;
+
+    async fn dry_run_storage_delta(
+        &self,
+        transaction: Transaction,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<StorageSlotDelta>>;
+
+    async fn dry_run_with_forced_failure(
+        &self,
+        transaction: Transaction,
+        fail_contract: ContractId,
+        height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<Receipt>>;
 }
@@ -192,6 +285,22 @@ pub trait ConsensusModulePort: Send + Sync {
     ) -> anyhow::Result<()>;
 }
 
+/// The event of the stream of the newly committed blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockEvent {
+    /// The block at the height was committed.
+    Committed(BlockHeight),
+    /// The stream lagged behind the block importer, and the number of
+    /// the committed blocks were skipped.
+    Lagged(u64),
+}
+
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns the stream of the newly committed blocks. The blocks skipped because
+    /// the stream lagged behind are reported with the [`BlockEvent::Lagged`].
+    fn block_events(&self) -> BoxStream<BlockEvent>;
+}
+
 /// Trait that specifies queries supported by the database.
 pub trait DatabaseMessageProof: Send + Sync {
     /// Gets the [`MerkleProof`] for the message block at `message_block_height` height
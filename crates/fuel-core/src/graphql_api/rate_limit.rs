@@ -0,0 +1,402 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextParseQuery,
+        NextPrepareRequest,
+    },
+    parser::types::{
+        DocumentOperations,
+        ExecutableDocument,
+        OperationDefinition,
+        OperationType,
+        Selection,
+        SelectionSet,
+    },
+    ErrorExtensions,
+    Name,
+    Pos,
+    Request,
+    ServerResult,
+    Variables,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    net::{
+        IpAddr,
+        Ipv6Addr,
+    },
+    sync::{
+        Arc,
+        OnceLock,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The `code` extension of the error returned when the client exceeds its rate limit.
+pub const RATE_LIMITED: &str = "RATE_LIMITED";
+
+/// The window in which the requests of the client are counted against its limit.
+pub const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// The maximum number of the tracked windows. When it is reached, the window
+/// that started the earliest is dropped to track a new client.
+pub const RATE_LIMITER_CAPACITY: usize = 10_000;
+
+/// The length of the prefix of the IPv6 addresses identifying the client. One host
+/// usually gets the whole /64 network, so it can't rotate the addresses inside it.
+const IPV6_CLIENT_PREFIX_LEN: u32 = 64;
+
+/// The subscriptions doing the work of a mutation. They count against the limit
+/// of the mutations, so they can't be used to bypass it.
+pub const MUTATING_SUBSCRIPTIONS: &[&str] = &["submitAndAwait", "dryRunStreaming"];
+
+/// The address of the client that sent the request, attached to the request data.
+#[derive(Clone, Copy, Debug)]
+pub struct ClientAddr(pub IpAddr);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum OperationKind {
+    Query,
+    Mutation,
+}
+
+impl OperationKind {
+    fn name(&self) -> &'static str {
+        match self {
+            OperationKind::Query => "query",
+            OperationKind::Mutation => "mutation",
+        }
+    }
+}
+
+/// The client and the kind of its requests. The clients without the [`ClientAddr`]
+/// have no address, and the IPv6 clients are identified by their network prefix.
+type ClientKey = (Option<IpAddr>, OperationKind);
+
+struct Window {
+    started_at: Instant,
+    requests: u32,
+}
+
+/// The windows of the clients, with the order in which they were started.
+#[derive(Default)]
+struct Windows {
+    windows: HashMap<ClientKey, Window>,
+    /// The keys with the start of their windows, the earliest first. The entries
+    /// of the windows restarted or dropped since then are skipped.
+    starts: VecDeque<(ClientKey, Instant)>,
+}
+
+impl Windows {
+    /// Returns the window of the `key` at the moment `now`. The expired windows
+    /// are dropped first, and if there are still `RATE_LIMITER_CAPACITY` windows,
+    /// the window that started the earliest is dropped for the new one.
+    fn window(&mut self, key: ClientKey, now: Instant) -> &mut Window {
+        while let Some((_, started_at)) = self.starts.front() {
+            if now.duration_since(*started_at) < RATE_LIMIT_WINDOW {
+                break
+            }
+            self.pop_earliest();
+        }
+        while !self.windows.contains_key(&key)
+            && self.windows.len() >= RATE_LIMITER_CAPACITY
+        {
+            self.pop_earliest();
+        }
+
+        let window = self.windows.entry(key).or_insert_with(|| {
+            self.starts.push_back((key, now));
+            Window {
+                started_at: now,
+                requests: 0,
+            }
+        });
+        if now.duration_since(window.started_at) >= RATE_LIMIT_WINDOW {
+            window.started_at = now;
+            window.requests = 0;
+            self.starts.push_back((key, now));
+        }
+        window
+    }
+
+    /// Drops the window of the earliest entry of the `starts`, unless it was
+    /// restarted after the entry.
+    fn pop_earliest(&mut self) {
+        if let Some((key, started_at)) = self.starts.pop_front() {
+            if self
+                .windows
+                .get(&key)
+                .map_or(false, |window| window.started_at == started_at)
+            {
+                self.windows.remove(&key);
+            }
+        }
+    }
+}
+
+/// The address identifying the client. The IPv6 addresses are truncated to
+/// the `IPV6_CLIENT_PREFIX_LEN`, except the IPv4 addresses mapped into them.
+fn client_id(addr: IpAddr) -> IpAddr {
+    match addr {
+        IpAddr::V4(_) => addr,
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => IpAddr::V4(v4),
+            None => {
+                let mask = u128::MAX << (128 - IPV6_CLIENT_PREFIX_LEN);
+                IpAddr::V6(Ipv6Addr::from(u128::from(v6) & mask))
+            }
+        },
+    }
+}
+
+/// The per-IP limit of the queries and the mutations within the [`RATE_LIMIT_WINDOW`].
+///
+/// The queries and the mutations are counted separately, so the cheap queries
+/// don't use up the limit of the more expensive mutations. When the client reaches
+/// the limit, the next requests of the same kind are rejected with the [`RATE_LIMITED`]
+/// error until the window ends. The `retry_after` extension of the error holds
+/// the number of seconds left in the window.
+///
+/// The subscriptions aren't limited, except the [`MUTATING_SUBSCRIPTIONS`] counted
+/// as mutations. The requests without the [`ClientAddr`] share one limit, so they
+/// can't bypass it. The IPv6 clients are limited per /64 network, and at most
+/// [`RATE_LIMITER_CAPACITY`] windows are tracked at once.
+#[derive(Clone)]
+pub struct RateLimiter {
+    windows: Arc<Mutex<Windows>>,
+    max_queries: Option<u32>,
+    max_mutations: Option<u32>,
+}
+
+impl RateLimiter {
+    pub fn new(max_queries: Option<u32>, max_mutations: Option<u32>) -> Self {
+        Self {
+            windows: Default::default(),
+            max_queries,
+            max_mutations,
+        }
+    }
+
+    /// Counts the request of the `client` against its limit. The requests of
+    /// the unknown clients are counted together.
+    fn check(
+        &self,
+        client: Option<IpAddr>,
+        kind: OperationKind,
+    ) -> async_graphql::Result<()> {
+        let limit = match kind {
+            OperationKind::Query => self.max_queries,
+            OperationKind::Mutation => self.max_mutations,
+        };
+        let limit = match limit {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        let now = Instant::now();
+        let mut windows = self.windows.lock();
+        let window = windows.window((client.map(client_id), kind), now);
+
+        if window.requests >= limit {
+            let left =
+                RATE_LIMIT_WINDOW.saturating_sub(now.duration_since(window.started_at));
+            let retry_after = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+            return Err(async_graphql::Error::new(format!(
+                "Too many {} requests, at most {limit} are allowed per {} seconds; \
+                retry after {retry_after} seconds",
+                kind.name(),
+                RATE_LIMIT_WINDOW.as_secs()
+            ))
+            .extend_with(|_, e| {
+                e.set("code", RATE_LIMITED);
+                e.set("retry_after", retry_after);
+            }))
+        }
+        window.requests += 1;
+        Ok(())
+    }
+}
+
+impl ExtensionFactory for RateLimiter {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(RateLimitExtInner {
+            limiter: self.clone(),
+            operation_name: OnceLock::new(),
+        })
+    }
+}
+
+/// The kind of the `operation` counted against the limits, or `None` if it isn't
+/// limited. The subscriptions selecting the [`MUTATING_SUBSCRIPTIONS`] are
+/// counted as mutations.
+fn operation_kind(
+    doc: &ExecutableDocument,
+    operation: &OperationDefinition,
+) -> Option<OperationKind> {
+    match operation.ty {
+        OperationType::Query => Some(OperationKind::Query),
+        OperationType::Mutation => Some(OperationKind::Mutation),
+        OperationType::Subscription => {
+            selects_mutating_subscription(
+                doc,
+                &operation.selection_set.node,
+                &mut HashSet::new(),
+            )
+            .then_some(OperationKind::Mutation)
+        }
+    }
+}
+
+/// Returns `true` if the `selection_set` selects one of the [`MUTATING_SUBSCRIPTIONS`],
+/// directly or through the fragments. Each fragment is visited once, since
+/// the document isn't validated yet and may have cyclic fragments.
+fn selects_mutating_subscription(
+    doc: &ExecutableDocument,
+    selection_set: &SelectionSet,
+    visited: &mut HashSet<Name>,
+) -> bool {
+    selection_set
+        .items
+        .iter()
+        .any(|selection| match &selection.node {
+            Selection::Field(field) => {
+                MUTATING_SUBSCRIPTIONS.contains(&field.node.name.node.as_str())
+            }
+            Selection::FragmentSpread(spread) => {
+                let name = &spread.node.fragment_name.node;
+                visited.insert(name.clone())
+                    && doc.fragments.get(name).map_or(false, |fragment| {
+                        selects_mutating_subscription(
+                            doc,
+                            &fragment.node.selection_set.node,
+                            visited,
+                        )
+                    })
+            }
+            Selection::InlineFragment(fragment) => selects_mutating_subscription(
+                doc,
+                &fragment.node.selection_set.node,
+                visited,
+            ),
+        })
+}
+
+pub(crate) struct RateLimitExtInner {
+    limiter: RateLimiter,
+    /// The name of the operation to execute, taken from the request.
+    operation_name: OnceLock<Option<String>>,
+}
+
+#[async_trait::async_trait]
+impl Extension for RateLimitExtInner {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let _ = self.operation_name.set(request.operation_name.clone());
+        next.run(ctx, request).await
+    }
+
+    /// Counts the operation to execute against the limit of the client. It is done
+    /// right after the parsing, since the subscriptions are not passed through
+    /// the `execute` of the extensions.
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let doc = next.run(ctx, query, variables).await?;
+        let operation_name = self.operation_name.get().and_then(Option::as_deref);
+        let operation = match &doc.operations {
+            DocumentOperations::Single(operation) => Some(operation),
+            DocumentOperations::Multiple(operations) => operations
+                .iter()
+                .find(|(name, _)| {
+                    operation_name.is_none() || Some(name.as_str()) == operation_name
+                })
+                .map(|(_, operation)| operation),
+        };
+
+        if let Some(kind) =
+            operation.and_then(|operation| operation_kind(&doc, &operation.node))
+        {
+            let client = ctx
+                .data_opt::<ClientAddr>()
+                .map(|ClientAddr(client)| *client);
+            self.limiter
+                .check(client, kind)
+                .map_err(|error| error.into_server_error(Pos::default()))?;
+        }
+
+        Ok(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn ipv6_clients_are_limited_per_network() {
+        let limiter = RateLimiter::new(None, Some(1));
+        let first: IpAddr = "2001:db8:1:2::1".parse().unwrap();
+        let same_network: IpAddr = "2001:db8:1:2:ffff::2".parse().unwrap();
+        let other_network: IpAddr = "2001:db8:1:3::1".parse().unwrap();
+
+        limiter.check(Some(first), OperationKind::Mutation).unwrap();
+        limiter
+            .check(Some(same_network), OperationKind::Mutation)
+            .unwrap_err();
+        limiter
+            .check(Some(other_network), OperationKind::Mutation)
+            .unwrap();
+    }
+
+    #[test]
+    fn ipv4_mapped_clients_are_limited_per_address() {
+        let limiter = RateLimiter::new(None, Some(1));
+        let first: IpAddr = "::ffff:10.0.0.1".parse().unwrap();
+        let second: IpAddr = "::ffff:10.0.0.2".parse().unwrap();
+
+        limiter.check(Some(first), OperationKind::Mutation).unwrap();
+        limiter.check(Some(second), OperationKind::Mutation).unwrap();
+        limiter
+            .check(Some("10.0.0.1".parse().unwrap()), OperationKind::Mutation)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn earliest_window_is_dropped_when_capacity_is_reached() {
+        let limiter = RateLimiter::new(None, Some(1));
+        let client = |index: usize| {
+            Some(IpAddr::V4(Ipv4Addr::from(0x0a00_0000 + index as u32)))
+        };
+        for index in 0..=RATE_LIMITER_CAPACITY {
+            limiter.check(client(index), OperationKind::Mutation).unwrap();
+        }
+
+        let windows = limiter.windows.lock();
+        assert_eq!(windows.windows.len(), RATE_LIMITER_CAPACITY);
+        assert!(!windows
+            .windows
+            .contains_key(&(client(0), OperationKind::Mutation)));
+        assert!(windows
+            .windows
+            .contains_key(&(client(RATE_LIMITER_CAPACITY), OperationKind::Mutation)));
+    }
+}
@@ -2,12 +2,27 @@
 use crate::graphql_api::metrics_extension::MetricsExtension;
 use crate::{
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
         ConsensusModulePort,
         DatabasePort,
         TxPoolPort,
     },
-    graphql_api::Config,
+    graphql_api::{
+        coin_reservations::CoinReservations,
+        dry_run_limit::DryRunLimiter,
+        idempotency::{
+            IdempotencyCache,
+            IDEMPOTENCY_CACHE_CAPACITY,
+            IDEMPOTENCY_KEY_TTL,
+        },
+        rate_limit::{
+            ClientAddr,
+            RateLimiter,
+        },
+        submission_queue::SubmissionQueue,
+        Config,
+    },
     schema::{
         CoreSchema,
         CoreSchemaBuilder,
@@ -24,16 +39,21 @@ use async_graphql::{
 };
 use axum::{
     extract::{
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
     },
     http::{
         header::{
+            ACCEPT,
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            CONTENT_TYPE,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -62,23 +82,31 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
+    compression::CompressionLayer,
     set_header::SetResponseHeaderLayer,
     trace::TraceLayer,
 };
 
 pub type Service = fuel_core_services::ServiceRunner<GraphqlService>;
 
+/// The media type of the CBOR encoding of the GraphQL responses. The responses are
+/// encoded as CBOR instead of JSON if the `Accept` header of the request lists it.
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
 pub type Database = Box<dyn DatabasePort>;
 
-pub type BlockProducer = Box<dyn BlockProducerPort>;
+/// Shared, so the dry-runs can run in their own tasks that outlive the request.
+pub type BlockProducer = Arc<dyn BlockProducerPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -123,7 +151,7 @@ impl RunnableService for GraphqlService {
 
         let server = axum::Server::from_tcp(listener)
             .unwrap()
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -155,6 +183,7 @@ impl RunnableTask for Task {
 }
 
 // Need a seperate Data Object for each Query endpoint, cannot be avoided
+#[allow(clippy::too_many_arguments)]
 pub fn new_service(
     config: Config,
     schema: CoreSchemaBuilder,
@@ -162,17 +191,34 @@ pub fn new_service(
     txpool: TxPool,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
+    block_importer: BlockImporter,
     _log_threshold_ms: Duration,
 ) -> anyhow::Result<Service> {
     let network_addr = config.addr;
+    let keep_alive = SubscriptionKeepAlive(config.subscription_keep_alive_interval);
+    let submission_queue = SubmissionQueue::new(config.submission_queue_depth);
+    let dry_run_limiter = DryRunLimiter::new(config.max_concurrent_dry_runs);
+    let idempotency_cache =
+        IdempotencyCache::new(IDEMPOTENCY_CACHE_CAPACITY, IDEMPOTENCY_KEY_TTL);
+    let rate_limiter = RateLimiter::new(
+        config.max_queries_per_minute,
+        config.max_mutations_per_minute,
+    );
 
     let builder = schema
         .data(config)
+        .data(submission_queue)
+        .data(dry_run_limiter)
+        .data(idempotency_cache)
+        .data(CoinReservations::default())
         .data(database)
         .data(txpool)
         .data(producer)
-        .data(consensus_module);
-    let builder = builder.extension(async_graphql::extensions::Tracing);
+        .data(consensus_module)
+        .data(block_importer);
+    let builder = builder
+        .extension(async_graphql::extensions::Tracing)
+        .extension(rate_limiter);
 
     #[cfg(feature = "metrics")]
     let builder = builder.extension(MetricsExtension::new(_log_threshold_ms));
@@ -181,7 +227,14 @@ pub fn new_service(
 
     let router = Router::new()
         .route("/playground", get(graphql_playground))
-        .route("/graphql", post(graphql_handler).options(ok))
+        // The responses are compressed if the client accepts it. The subscriptions
+        // are left uncompressed, so the encoder doesn't hold back the events.
+        .route(
+            "/graphql",
+            post(graphql_handler)
+                .options(ok)
+                .layer(CompressionLayer::new()),
+        )
         .route(
             "/graphql-sub",
             post(graphql_subscription_handler).options(ok),
@@ -189,6 +242,7 @@ pub fn new_service(
         .route("/metrics", get(metrics))
         .route("/health", get(health))
         .layer(Extension(schema))
+        .layer(Extension(keep_alive))
         .layer(TraceLayer::new_for_http())
         .layer(SetResponseHeaderLayer::<_>::overriding(
             ACCESS_CONTROL_ALLOW_ORIGIN,
@@ -224,21 +278,69 @@ async fn health() -> Json<serde_json::Value> {
 }
 
 async fn graphql_handler(
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
     schema: Extension<CoreSchema>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Json<Response> {
-    schema.execute(req.0).await.into()
+) -> axum::response::Response {
+    let req = req.0.data(ClientAddr(client.ip()));
+    let response = schema.execute(req).await;
+    if accepts_cbor(&headers) {
+        cbor_response(&response)
+    } else {
+        Json(response).into_response()
+    }
 }
 
+/// Returns `true` if the `Accept` header lists the [`CBOR_CONTENT_TYPE`].
+fn accepts_cbor(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media_type| {
+            media_type
+                .split(';')
+                .next()
+                .map(|media_type| media_type.trim() == CBOR_CONTENT_TYPE)
+                .unwrap_or(false)
+        })
+}
+
+/// Encodes the `response` as CBOR. The data model is the same as of the JSON
+/// response, only the encoding differs.
+fn cbor_response(response: &Response) -> axum::response::Response {
+    let mut body = vec![];
+    match ciborium::ser::into_writer(response, &mut body) {
+        Ok(()) => ([(CONTENT_TYPE, CBOR_CONTENT_TYPE)], body).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
+/// The interval of the keep-alive comments sent to the idle subscriptions.
+#[derive(Clone, Copy)]
+struct SubscriptionKeepAlive(Duration);
+
 async fn graphql_subscription_handler(
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
     schema: Extension<CoreSchema>,
+    Extension(SubscriptionKeepAlive(interval)): Extension<SubscriptionKeepAlive>,
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
+    // The endpoint also executes the queries and the mutations,
+    // so they are limited the same as on the `/graphql`.
+    let req = req.0.data(ClientAddr(client.ip()));
     let stream = schema
-        .execute_stream(req.0)
+        .execute_stream(req)
         .map(|r| Ok(Event::default().json_data(r).unwrap()));
-    Sse::new(stream)
-        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
+    // The keep-alive comment is sent only if no event was sent during the interval,
+    // and it never reorders the events of the subscription.
+    Sse::new(stream).keep_alive(
+        axum::response::sse::KeepAlive::new()
+            .interval(interval)
+            .text("keep-alive-text"),
+    )
 }
 
 async fn ok() -> anyhow::Result<(), ()> {
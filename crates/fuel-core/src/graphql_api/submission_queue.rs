@@ -0,0 +1,41 @@
+use async_graphql::ErrorExtensions;
+use std::sync::Arc;
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+
+/// The `code` extension of the error returned when the submission queue is full.
+pub const NODE_BUSY: &str = "NODE_BUSY";
+
+/// The bounded queue in front of the `TxPool` insertion.
+///
+/// It limits the number of submitted transactions that are concurrently waiting
+/// for the `TxPool`. When all `depth` slots are taken, new submissions are rejected
+/// immediately with the [`NODE_BUSY`] error instead of blocking. Clients should treat
+/// this error as retryable and resubmit the transaction after a backoff.
+#[derive(Clone)]
+pub struct SubmissionQueue {
+    slots: Arc<Semaphore>,
+    depth: usize,
+}
+
+impl SubmissionQueue {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(depth)),
+            depth,
+        }
+    }
+
+    /// Reserves a slot in the queue. The slot is released when the permit is dropped.
+    pub fn try_reserve(&self) -> async_graphql::Result<OwnedSemaphorePermit> {
+        self.slots.clone().try_acquire_owned().map_err(|_| {
+            async_graphql::Error::new(format!(
+                "The node is busy, the submission queue is full with {} pending transactions",
+                self.depth
+            ))
+            .extend_with(|_, e| e.set("code", NODE_BUSY))
+        })
+    }
+}
@@ -19,7 +19,10 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
-    tables::Messages,
+    tables::{
+        Messages,
+        SpentMessages,
+    },
     Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
@@ -56,6 +59,8 @@ mod test;
 pub trait MessageQueryData: Send + Sync {
     fn message(&self, message_id: &Nonce) -> StorageResult<Message>;
 
+    fn message_is_spent(&self, message_id: &Nonce) -> StorageResult<bool>;
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -85,6 +90,10 @@ impl<D: DatabasePort + ?Sized> MessageQueryData for D {
             .map(Cow::into_owned)
     }
 
+    fn message_is_spent(&self, id: &Nonce) -> StorageResult<bool> {
+        self.storage::<SpentMessages>().contains_key(id)
+    }
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -1,4 +1,7 @@
-use crate::schema::tx::types::TransactionStatus as ApiTxStatus;
+use crate::schema::tx::types::{
+    SubmittedStatus,
+    TransactionStatus as ApiTxStatus,
+};
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
@@ -14,6 +17,11 @@ use futures::{
 #[cfg(test)]
 mod test;
 
+/// The error that ends the stream of the subscription evicted by the `TxPool`
+/// to free its slot for a new subscription.
+pub(crate) const SUBSCRIPTION_EVICTED: &str =
+    "The subscription was evicted to free its slot for a new one";
+
 #[cfg_attr(test, mockall::automock)]
 pub(crate) trait TxnStatusChangeState {
     /// Return the transaction status from the tx pool and database.
@@ -68,11 +76,20 @@ where
             }
 
             match status {
+                // The id allows the `Submitted` status to look up its position
+                // in the `TxPool` when it is emitted.
+                TxStatusMessage::Status(TxPoolTxStatus::Submitted { time }) => {
+                    Ok(ApiTxStatus::Submitted(SubmittedStatus {
+                        time,
+                        tx_id: Some(transaction_id),
+                    }))
+                }
                 TxStatusMessage::Status(status) => Ok(status.into()),
                 // Map a failed status to an error for the api.
                 TxStatusMessage::FailedStatus => {
                     Err(anyhow::anyhow!("Failed to get transaction status"))
                 }
+                TxStatusMessage::Evicted => Err(anyhow::anyhow!(SUBSCRIPTION_EVICTED)),
             }
         })
 }
@@ -177,7 +177,7 @@ fn transaction_status_change_model(
                 }
             },
             // In case of a failed status, push the error to the output vector and break
-            TxStatusMessage::FailedStatus => {
+            TxStatusMessage::FailedStatus | TxStatusMessage::Evicted => {
                 out.push(Err(Error));
                 ControlFlow::Break(out)
             }
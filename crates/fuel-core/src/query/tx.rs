@@ -20,7 +20,10 @@ use fuel_core_types::{
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -55,6 +58,13 @@ pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
 }
 
 impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
@@ -78,4 +88,21 @@ impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
             })
             .into_boxed()
     }
+
+    fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>> {
+        self.contract_transactions_ids(contract_id, start, direction)
+            .map(|result| {
+                result.and_then(|(tx_pointer, tx_id)| {
+                    let tx = self.transaction(&tx_id)?;
+
+                    Ok((tx_pointer, tx))
+                })
+            })
+            .into_boxed()
+    }
 }
@@ -3,12 +3,15 @@ use async_graphql::{
     connection::{
         query,
         Connection,
+        ConnectionNameType,
         CursorType,
         Edge,
+        EdgeNameType,
         EmptyFields,
     },
     MergedObject,
     MergedSubscription,
+    ObjectType,
     OutputType,
     Schema,
     SchemaBuilder,
@@ -65,14 +68,19 @@ pub struct Query(
 
 #[cfg(feature = "dap")]
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    coins::CoinMutation,
+);
 
 #[cfg(not(feature = "dap"))]
 #[derive(MergedObject, Default)]
-pub struct Mutation(tx::TxMutation, block::BlockMutation);
+pub struct Mutation(tx::TxMutation, block::BlockMutation, coins::CoinMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, block::BlockSubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -86,6 +94,14 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+/// The limit of the total size of the values of one page.
+pub struct ByteBudget<SchemaValue> {
+    /// The maximum number of bytes of the values of the page.
+    pub max_bytes: usize,
+    /// Returns the size of the value in bytes.
+    pub size_of: fn(&SchemaValue) -> usize,
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
     after: Option<String>,
     before: Option<String>,
@@ -93,6 +109,29 @@ async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
     last: Option<i32>,
     entries: F,
 ) -> async_graphql::Result<Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields>>
+where
+    SchemaKey: CursorType + Send + Sync,
+    <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
+    SchemaValue: OutputType,
+    F: FnOnce(&Option<SchemaKey>, IterDirection) -> StorageResult<Entries>,
+    Entries: Iterator<Item = StorageResult<(SchemaKey, SchemaValue)>>,
+    SchemaKey: Eq,
+{
+    query_pagination_with_budget(after, before, first, last, None, entries).await
+}
+
+/// Same as `query_pagination`, but if the `budget` is set, the page is cut short
+/// before the value that would exceed it, and `has_next_page` is set, so the client
+/// continues from the end cursor of the page. The first value of the page is always
+/// included, so the pagination progresses even if one value exceeds the budget.
+async fn query_pagination_with_budget<F, Entries, SchemaKey, SchemaValue>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    budget: Option<ByteBudget<SchemaValue>>,
+    entries: F,
+) -> async_graphql::Result<Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields>>
 where
     SchemaKey: CursorType + Send + Sync,
     <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
@@ -103,6 +142,45 @@ where
     F: FnOnce(&Option<SchemaKey>, IterDirection) -> StorageResult<Entries>,
     Entries: Iterator<Item = StorageResult<(SchemaKey, SchemaValue)>>,
     SchemaKey: Eq,
+{
+    query_pagination_with_edge_fields(after, before, first, last, budget, entries, |_| {
+        EmptyFields
+    })
+    .await
+}
+
+/// Same as `query_pagination_with_budget`, but each edge has the additional fields
+/// returned by the `edge_fields` for its value.
+#[allow(clippy::too_many_arguments)]
+async fn query_pagination_with_edge_fields<
+    F,
+    Entries,
+    SchemaKey,
+    SchemaValue,
+    EdgeFields,
+    ConnectionName,
+    EdgeName,
+>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    budget: Option<ByteBudget<SchemaValue>>,
+    entries: F,
+    edge_fields: impl Fn(&SchemaValue) -> EdgeFields,
+) -> async_graphql::Result<
+    Connection<SchemaKey, SchemaValue, EmptyFields, EdgeFields, ConnectionName, EdgeName>,
+>
+where
+    SchemaKey: CursorType + Send + Sync,
+    <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
+    SchemaValue: OutputType,
+    EdgeFields: ObjectType,
+    ConnectionName: ConnectionNameType,
+    EdgeName: EdgeNameType,
+    F: FnOnce(&Option<SchemaKey>, IterDirection) -> StorageResult<Entries>,
+    Entries: Iterator<Item = StorageResult<(SchemaKey, SchemaValue)>>,
+    SchemaKey: Eq,
 {
     match (after.as_ref(), before.as_ref(), first, last) {
         (_, _, Some(first), Some(last)) => {
@@ -177,8 +255,10 @@ where
             });
 
             let mut count = count + 1 /* for `has_next_page` */;
+            let mut taken = 0;
+            let mut bytes = 0usize;
             let entries = entries.take(count).take_while(|result| {
-                if let Ok((key, _)) = result {
+                if let Ok((key, value)) = result {
                     if let Some(end) = end.as_ref() {
                         // take until we've reached the end
                         if key == end {
@@ -186,6 +266,14 @@ where
                             return false
                         }
                     }
+                    if let Some(budget) = budget.as_ref() {
+                        bytes = bytes.saturating_add((budget.size_of)(value));
+                        if taken > 0 && bytes > budget.max_bytes {
+                            has_next_page = true;
+                            return false
+                        }
+                    }
+                    taken += 1;
                     count -= 1;
                     has_next_page |= count == 0;
                     count != 0
@@ -200,13 +288,12 @@ where
 
             let mut connection = Connection::new(has_previous_page, has_next_page);
 
-            connection.edges.extend(
-                entries
-                    .into_iter()
-                    .map(|(key, value)| Edge::new(key, value)),
-            );
+            connection.edges.extend(entries.into_iter().map(|(key, value)| {
+                let fields = edge_fields(&value);
+                Edge::with_additional_fields(key, value, fields)
+            }));
 
-            Ok::<Connection<SchemaKey, SchemaValue>, anyhow::Error>(connection)
+            Ok::<_, anyhow::Error>(connection)
         },
     )
     .await
@@ -4,7 +4,9 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        ports::BlockEvent,
         service::{
+            BlockImporter,
             ConsensusModule,
             Database,
         },
@@ -18,6 +20,7 @@ use crate::{
     },
     schema::{
         scalars::{
+            Address,
             BlockId,
             Signature,
             U32,
@@ -35,8 +38,10 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
+use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -50,9 +55,23 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
+    fuel_tx::{
+        field::Outputs,
+        AssetId,
+        Output,
+        Transaction as FuelTx,
+    },
     fuel_types,
     fuel_types::BlockHeight,
 };
+use futures::{
+    Stream,
+    StreamExt,
+};
+use tokio::sync::watch;
+
+/// The maximum number of blocks scanned by `coinbaseRevenue`.
+pub const MAX_COINBASE_REVENUE_SCAN_BLOCKS: u32 = 1_000;
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -227,6 +246,62 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// Returns the sum of the coinbase amounts paid to the `recipient` over
+    /// the last `blocks` blocks.
+    ///
+    /// The first transaction of each block is its mint transaction. Only its
+    /// coin outputs of the base asset sent to the `recipient` are counted, so
+    /// the blocks produced with another coinbase recipient contribute nothing.
+    /// At most `MAX_COINBASE_REVENUE_SCAN_BLOCKS` blocks can be scanned.
+    async fn coinbase_revenue(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The address that receives the coinbase")] recipient: Address,
+        #[graphql(desc = "The number of the latest blocks to scan")] blocks: U32,
+    ) -> async_graphql::Result<U64> {
+        if blocks.0 > MAX_COINBASE_REVENUE_SCAN_BLOCKS {
+            return Err(anyhow!(
+                "At most {} blocks can be scanned, got {}",
+                MAX_COINBASE_REVENUE_SCAN_BLOCKS,
+                blocks.0
+            )
+            .into())
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let recipient: fuel_types::Address = recipient.into();
+        let mut revenue = 0u64;
+        for block in query
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(blocks.0 as usize)
+        {
+            let block = block?;
+            // The genesis block doesn't have a mint transaction
+            let mint_id = match block.transactions().first() {
+                Some(id) => *id,
+                None => continue,
+            };
+            let mint = match query.transaction(&mint_id)? {
+                FuelTx::Mint(mint) => mint,
+                _ => continue,
+            };
+            for output in mint.outputs() {
+                if let Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                } = output
+                {
+                    if *to == recipient && *asset_id == AssetId::BASE {
+                        revenue = revenue.saturating_add(*amount);
+                    }
+                }
+            }
+        }
+
+        Ok(revenue.into())
+    }
 }
 
 #[derive(Default)]
@@ -316,6 +391,66 @@ impl BlockMutation {
     }
 }
 
+/// The latest block of the chain.
+#[derive(SimpleObject)]
+pub struct ChainTip {
+    pub height: U32,
+    pub block_id: BlockId,
+}
+
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of the tip of the chain, emitted each time a block is committed
+    /// after the subscription. It is lighter than the blocks, for the clients that
+    /// only need to know when to refresh their view.
+    ///
+    /// Only the latest tip is kept for each subscriber, so a slow subscriber skips
+    /// the blocks committed between its polls and receives the latest tip.
+    async fn chain_tip<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = async_graphql::Result<ChainTip>> + 'a {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let query: &Database = ctx.data_unchecked();
+        latest_heights(block_importer.block_events()).map(move |height| {
+            let id: fuel_types::Bytes32 = query.block_id(&height)?.into();
+            Ok(ChainTip {
+                height: (*height).into(),
+                block_id: id.into(),
+            })
+        })
+    }
+}
+
+/// Forwards the heights of the committed `blocks` into the channel that holds only
+/// the latest one, so the heights committed before the subscriber polls again
+/// are coalesced. The skipped blocks don't matter for the same reason. The task
+/// stops when the subscriber is dropped.
+fn latest_heights(mut blocks: BoxStream<BlockEvent>) -> impl Stream<Item = BlockHeight> {
+    let (sender, receiver) = watch::channel(None);
+    tokio::spawn(async move {
+        loop {
+            let height = tokio::select! {
+                event = blocks.next() => match event {
+                    Some(BlockEvent::Committed(height)) => height,
+                    Some(BlockEvent::Lagged(_)) => continue,
+                    None => return,
+                },
+                _ = sender.closed() => return,
+            };
+            sender.send_replace(Some(height));
+        }
+    });
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.changed().await.ok()?;
+        let height = (*receiver.borrow_and_update())?;
+        Some((height, receiver))
+    })
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
         Block(block)
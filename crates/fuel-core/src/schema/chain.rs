@@ -26,6 +26,10 @@ use fuel_core_types::{
 
 pub struct ChainInfo;
 
+/// The parameters that the node uses to compute the ids of the transactions
+/// and to check and charge them.
+pub struct ChainParameters;
+
 pub struct ConsensusParameters(fuel_tx::ConsensusParameters);
 
 pub struct GasCosts(fuel_vm::GasCosts);
@@ -567,6 +571,26 @@ impl ChainInfo {
     }
 }
 
+#[Object]
+impl ChainParameters {
+    /// The id of the chain, hashed into the id of each transaction. The ids computed
+    /// with another chain id don't match the ids computed by the node.
+    async fn chain_id(&self, ctx: &Context<'_>) -> U64 {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        (*config.transaction_parameters.chain_id).into()
+    }
+
+    async fn consensus_parameters(&self, ctx: &Context<'_>) -> ConsensusParameters {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        ConsensusParameters(config.transaction_parameters)
+    }
+
+    async fn gas_costs(&self, ctx: &Context<'_>) -> GasCosts {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        GasCosts(config.gas_costs.clone())
+    }
+}
+
 #[derive(Default)]
 pub struct ChainQuery;
 
@@ -575,4 +599,11 @@ impl ChainQuery {
     async fn chain(&self) -> ChainInfo {
         ChainInfo
     }
+
+    /// Returns the parameters of the chain used by the node, without the state
+    /// of the chain. The clients computing the ids of the transactions locally
+    /// use the `chain_id` to match the ids of the node.
+    async fn chain_parameters(&self) -> ChainParameters {
+        ChainParameters
+    }
 }
@@ -4,10 +4,18 @@ use crate::{
         SpendQuery,
     },
     fuel_core_graphql_api::{
+        coin_reservations::{
+            CoinReservations,
+            DEFAULT_COIN_RESERVATION_TTL,
+            MAX_RESERVED_COINS_PER_CALL,
+        },
         Config as GraphQLConfig,
         IntoApiResult,
     },
-    graphql_api::service::Database,
+    graphql_api::service::{
+        Database,
+        TxPool,
+    },
     query::{
         asset_query::AssetSpendTarget,
         CoinQueryData,
@@ -15,7 +23,9 @@ use crate::{
     schema::scalars::{
         Address,
         AssetId,
+        Bytes32,
         Nonce,
+        Tai64Timestamp,
         UtxoId,
         U32,
         U64,
@@ -28,6 +38,7 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    SimpleObject,
 };
 use fuel_core_types::{
     entities::{
@@ -38,8 +49,10 @@ use fuel_core_types::{
         },
     },
     fuel_tx,
+    tai64::Tai64,
 };
 use itertools::Itertools;
+use std::time::Duration;
 
 pub struct Coin(pub(crate) CoinModel);
 
@@ -74,6 +87,19 @@ impl Coin {
     async fn tx_created_idx(&self) -> U64 {
         u64::from(self.0.tx_pointer.tx_index()).into()
     }
+
+    /// `true` if the coin is reserved by the `reserve_coins` mutation with
+    /// the `token`. The coin spent by a pending transaction isn't reserved anymore.
+    async fn reserved(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The token of the reservation")] token: Bytes32,
+    ) -> bool {
+        let reservations = ctx.data_unchecked::<CoinReservations>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        reservations.is_reserved(&token.0, &self.0.utxo_id)
+            && txpool.transactions_spending_utxo(self.0.utxo_id).is_empty()
+    }
 }
 
 pub struct MessageCoin(pub(crate) MessageCoinModel);
@@ -265,6 +291,74 @@ impl CoinQuery {
     }
 }
 
+/// The reservations of the coins made by the `reserve_coins` mutation.
+#[derive(SimpleObject)]
+pub struct CoinReservation {
+    /// The token of the reservations, required to check or extend them.
+    token: Bytes32,
+    /// The time when the reservations expire.
+    expires_at: Tai64Timestamp,
+}
+
+#[derive(Default)]
+pub struct CoinMutation;
+
+#[async_graphql::Object]
+impl CoinMutation {
+    /// Reserves the coins for `ttl_seconds`, 60 seconds by default, so the coin
+    /// selection of the wallet can skip the coins used by its other transactions.
+    /// The reservation ends when the TTL expires or when a submitted transaction
+    /// spends the coin.
+    ///
+    /// The reservation is advisory and kept in memory. The node doesn't prevent
+    /// the transactions from spending the reserved coins.
+    ///
+    /// The reservations belong to the `token`, and only the callers knowing it
+    /// see them in the `reserved` of the coins. A new token is created if it isn't
+    /// specified, and the same token extends the reservations. At most
+    /// `MAX_RESERVED_COINS_PER_CALL` coins are reserved by one call, and
+    /// `MAX_COIN_RESERVATIONS` are kept by the node at once.
+    ///
+    /// Returns the token and the time when the reservations expire.
+    async fn reserve_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The IDs of the coins")] coin_ids: Vec<UtxoId>,
+        #[graphql(desc = "The number of seconds the coins stay reserved")]
+        ttl_seconds: Option<U32>,
+        #[graphql(desc = "The token to extend")] token: Option<Bytes32>,
+    ) -> async_graphql::Result<CoinReservation> {
+        let query: &Database = ctx.data_unchecked();
+        let reservations = ctx.data_unchecked::<CoinReservations>();
+        if coin_ids.len() > MAX_RESERVED_COINS_PER_CALL {
+            return Err(anyhow!(
+                "At most {MAX_RESERVED_COINS_PER_CALL} coins can be reserved at once, \
+                got {}",
+                coin_ids.len()
+            )
+            .into())
+        }
+
+        let coin_ids: Vec<fuel_tx::UtxoId> =
+            coin_ids.into_iter().map(|id| id.0).collect();
+        for utxo_id in &coin_ids {
+            query.coin(*utxo_id)?;
+        }
+
+        let ttl = ttl_seconds
+            .map(|ttl| Duration::from_secs(ttl.0 as u64))
+            .unwrap_or(DEFAULT_COIN_RESERVATION_TTL);
+        let token = token
+            .map(|token| token.0)
+            .unwrap_or_else(CoinReservations::new_token);
+        let ttl = reservations.reserve(token, coin_ids, ttl)?;
+        Ok(CoinReservation {
+            token: token.into(),
+            expires_at: Tai64Timestamp(Tai64(Tai64::now().0 + ttl.as_secs())),
+        })
+    }
+}
+
 impl From<CoinModel> for Coin {
     fn from(value: CoinModel) -> Self {
         Coin(value)
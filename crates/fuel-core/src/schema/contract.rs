@@ -22,6 +22,7 @@ use async_graphql::{
     InputObject,
     Object,
 };
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::{
     fuel_types,
     services::graphql_api,
@@ -56,6 +57,20 @@ impl Contract {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// The committed balances of all assets owned by the contract,
+    /// ordered by the asset id.
+    async fn balances(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<ContractBalance>> {
+        let context: &Database = ctx.data_unchecked();
+        context
+            .contract_balances(self.0, None, IterDirection::Forward)
+            .map(|balance| balance.map(Into::into))
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
 }
 
 #[derive(Default)]
@@ -124,6 +124,9 @@ impl ScalarType for Tai64Timestamp {
 pub struct SortedTxCursor {
     pub block_height: BlockHeight,
     pub tx_id: Bytes32,
+    /// The key of the ordering other than the block order, so the page can be
+    /// continued even if the transaction of the cursor is no longer sorted.
+    pub sort_key: Option<u64>,
 }
 
 impl SortedTxCursor {
@@ -131,6 +134,14 @@ impl SortedTxCursor {
         Self {
             block_height,
             tx_id,
+            sort_key: None,
+        }
+    }
+
+    pub fn with_sort_key(block_height: BlockHeight, tx_id: Bytes32, key: u64) -> Self {
+        Self {
+            sort_key: Some(key),
+            ..Self::new(block_height, tx_id)
         }
     }
 }
@@ -139,19 +150,29 @@ impl CursorType for SortedTxCursor {
     type Error = String;
 
     fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
-        let (block_height, tx_id) =
+        let (block_height, rest) =
             s.split_once('#').ok_or("Incorrect format provided")?;
-
-        Ok(Self::new(
-            u32::from_str(block_height)
-                .map_err(|_| "Failed to decode block_height")?
-                .into(),
-            Bytes32::decode_cursor(tx_id)?,
-        ))
+        let block_height = u32::from_str(block_height)
+            .map_err(|_| "Failed to decode block_height")?
+            .into();
+
+        match rest.split_once('#') {
+            Some((tx_id, sort_key)) => Ok(Self::with_sort_key(
+                block_height,
+                Bytes32::decode_cursor(tx_id)?,
+                u64::from_str(sort_key).map_err(|_| "Failed to decode sort_key")?,
+            )),
+            None => Ok(Self::new(block_height, Bytes32::decode_cursor(rest)?)),
+        }
     }
 
     fn encode_cursor(&self) -> String {
-        format!("{}#{}", self.block_height, self.tx_id)
+        match self.sort_key {
+            Some(sort_key) => {
+                format!("{}#{}#{}", self.block_height, self.tx_id, sort_key)
+            }
+            None => format!("{}#{}", self.block_height, self.tx_id),
+        }
     }
 }
 
@@ -346,6 +367,21 @@ mod tests {
         assert_eq!(*parsed_data.0, [0x01; 2]);
     }
 
+    #[test]
+    fn sorted_tx_cursor_roundtrips_with_and_without_sort_key() {
+        let tx_id = Bytes32::from_str(
+            "0x0101010101010101010101010101010101010101010101010101010101010101",
+        )
+        .unwrap();
+        for cursor in [
+            SortedTxCursor::new(7u32.into(), tx_id),
+            SortedTxCursor::with_sort_key(7u32.into(), tx_id, 42),
+        ] {
+            let decoded = SortedTxCursor::decode_cursor(&cursor.encode_cursor());
+            assert_eq!(decoded, Ok(cursor));
+        }
+    }
+
     #[test]
     fn hex_string_only_parses_valid_hex() {
         let hex_data = "0xZZZZ";
@@ -1,34 +1,58 @@
 use crate::{
     fuel_core_graphql_api::{
         service::{
+            BlockImporter,
             BlockProducer,
             Database,
             TxPool,
         },
         IntoApiResult,
     },
-    graphql_api::Config,
+    graphql_api::{
+        dry_run_limit::DryRunLimiter,
+        idempotency::{
+            IdempotencyCache,
+            Reservation,
+        },
+        rate_limit::ClientAddr,
+        submission_queue::SubmissionQueue,
+        Config,
+    },
     query::{
         transaction_status_change,
         BlockQueryData,
+        CoinQueryData,
+        MessageQueryData,
+        SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
+        SUBSCRIPTION_EVICTED,
     },
-    schema::scalars::{
-        Address,
-        HexString,
-        SortedTxCursor,
-        TransactionId,
-        TxPointer,
+    schema::{
+        scalars::{
+            Address,
+            ContractId,
+            HexString,
+            SortedTxCursor,
+            Tai64Timestamp,
+            TransactionId,
+            TxPointer,
+            UtxoId,
+            U32,
+            U64,
+        },
+        ByteBudget,
     },
 };
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
+        CursorType,
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
     Object,
     Subscription,
 };
@@ -39,15 +63,29 @@ use fuel_core_storage::{
 };
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
+    fuel_asm::PanicReason,
     fuel_tx::{
+        field::{
+            GasLimit,
+            GasPrice,
+            Inputs,
+        },
         Cacheable,
+        Chargeable,
+        ConsensusParameters,
+        Input,
+        Receipt,
+        Script,
+        ScriptExecutionResult,
         Transaction as FuelTx,
+        TransactionFee,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types,
     fuel_types::bytes::Deserializable,
-    fuel_vm::checked_transaction::EstimatePredicates,
     services::txpool,
+    tai64::Tai64,
 };
 use futures::{
     Stream,
@@ -55,18 +93,540 @@ use futures::{
 };
 use itertools::Itertools;
 use std::{
+    cmp::{
+        Ordering,
+        Reverse,
+    },
+    collections::{
+        BinaryHeap,
+        HashSet,
+    },
+    future::Future,
     iter,
     sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tokio_stream::StreamExt;
+use tracing::{
+    field,
+    Instrument,
+};
 use types::Transaction;
 
-use self::types::TransactionStatus;
+use self::{
+    dry_run_trace::{
+        ExecutionCoverage,
+        ExecutionTrace,
+    },
+    execution_graph::ExecutionGraph,
+    predicate_estimation::PredicatesAndFeesEstimate,
+    predicate_validation::PredicateValidation,
+    summary::TransactionSummary,
+    test_witnesses::TestWitness,
+    types::{
+        get_tx_status,
+        get_tx_status_history,
+        get_tx_statuses,
+        ArithmeticPanic,
+        BatchSubmissionCategory,
+        BatchSubmissionOutcome,
+        BlockSimulation,
+        DecodedEvent,
+        DryRunDetails,
+        DryRunThenSubmitResult,
+        FeeStatistics,
+        GasPriceBucket,
+        GasPriceImpact,
+        InputUtxoState,
+        InsertionStatus,
+        OwnedTransactionConnection,
+        OwnedTransactionFields,
+        OwnerRole,
+        ResubmissionOutcome,
+        ResubmissionStatus,
+        RevertReason,
+        SqueezedOutStatus,
+        StorageSlotDelta,
+        SubmissionResult,
+        TransactionInputStatus,
+        TransactionLookup,
+        TransactionOrder,
+        TransactionStatus,
+        TransactionStatusEntry,
+        TransactionStatusTransition,
+        TxStatusCursor,
+        UnknownTransaction,
+    },
+    validation::{
+        validate_transaction,
+        ValidationFailure,
+    },
+};
 
+pub mod dependency_order;
+#[cfg(feature = "debug")]
+pub mod dry_run_stream;
+pub mod dry_run_trace;
+pub mod execution_graph;
+pub mod gas_breakdown;
 pub mod input;
 pub mod output;
+pub mod owner_transactions;
+pub mod predicate_estimation;
+pub mod predicate_validation;
 pub mod receipt;
+pub mod summary;
+pub mod test_witnesses;
 pub mod types;
+pub mod validation;
+
+/// The maximum number of blocks scanned by `transactionsByPanicReason`.
+pub const MAX_PANIC_REASON_SCAN_BLOCKS: u32 = 1_000;
+
+/// The maximum number of blocks scanned by `averageConfirmationTime`.
+pub const MAX_CONFIRMATION_TIME_SCAN_BLOCKS: u32 = 1_000;
+
+/// The number of the latest transactions sorted by the orderings of `transactions`
+/// other than the block order.
+pub const SORTED_TRANSACTIONS_WINDOW: usize = 1_000;
+
+/// The maximum number of the dry-runs made by the gas search of `dryRunDetailed`.
+pub const MAX_GAS_SEARCH_ITERATIONS: usize = 32;
+
+/// The maximum number of buckets returned by `mempoolGasPriceDistribution`.
+pub const MEMPOOL_GAS_PRICE_BUCKETS: u64 = 10;
+
+/// The error code of the transactions larger than the `max_tx_size`.
+pub const TRANSACTION_TOO_LARGE: &str = "TRANSACTION_TOO_LARGE";
+
+/// The error code of the transactions rejected because the `TxPool` is full.
+pub const POOL_FULL: &str = "POOL_FULL";
+
+/// The error code of the calls restricted to the `trusted_submitters`.
+pub const UNAUTHORIZED: &str = "UNAUTHORIZED";
+
+/// The error code of the dry-runs of the transactions already included in a block.
+pub const ALREADY_COMMITTED: &str = "ALREADY_COMMITTED";
+
+/// The error code of the dry-runs that exceed the `dry_run_timeout` of the node.
+pub const DRY_RUN_TIMEOUT: &str = "DRY_RUN_TIMEOUT";
+
+/// The number of the latest blocks used to estimate when the `TxPool` frees space.
+pub const POOL_TURNOVER_BLOCKS: usize = 10;
+
+/// The maximum number of the ids passed to `transactionStatuses`.
+pub const MAX_TRANSACTION_STATUSES: usize = 100;
+
+/// The byte budget of the pages of transactions, if the node limits it. Only
+/// the transactions themselves are counted. Their receipts and statuses are
+/// resolved later, only if the selection asks for them, so they aren't counted.
+fn transaction_page_budget(config: &Config) -> Option<ByteBudget<Transaction>> {
+    config.max_page_bytes.map(|max_bytes| ByteBudget {
+        max_bytes,
+        size_of: Transaction::serialized_size,
+    })
+}
+
+/// Returns `true` if the selection of the connection of transactions requires
+/// only their ids, from the `nodes` and from the `node` of the `edges`.
+fn connection_requires_only_ids(ctx: &Context<'_>) -> bool {
+    ctx.field().selection_set().all(|field| match field.name() {
+        "nodes" => Transaction::requires_only_id(field),
+        "edges" => field
+            .selection_set()
+            .filter(|edge_field| edge_field.name() == "node")
+            .all(Transaction::requires_only_id),
+        _ => true,
+    })
+}
+
+/// Returns the cursors of the transactions of the blocks with the time within
+/// the inclusive range, in the order of the blocks, starting from the `start`.
+fn block_ordered_transactions(
+    query: &Database,
+    start: Option<SortedTxCursor>,
+    direction: IterDirection,
+    start_time: Option<Tai64>,
+    end_time: Option<Tai64>,
+) -> impl Iterator<Item = StorageResult<SortedTxCursor>> + '_ {
+    let block_id = start.map(|sorted| sorted.block_height);
+    // The time of the blocks grows with the height, so the blocks
+    // before the range are skipped, and the first block past it ends
+    // the iteration.
+    let (before_range, past_range) = match direction {
+        IterDirection::Forward => (start_time, end_time),
+        IterDirection::Reverse => (end_time, start_time),
+    };
+    let is_before = move |time: Tai64, bound: Tai64| match direction {
+        IterDirection::Forward => time < bound,
+        IterDirection::Reverse => time > bound,
+    };
+    let all_block_ids = query
+        .compressed_blocks(block_id, direction)
+        .skip_while(move |block| match (block, before_range) {
+            (Ok(block), Some(bound)) => is_before(block.header().time(), bound),
+            _ => false,
+        })
+        .take_while(move |block| match (block, past_range) {
+            (Ok(block), Some(bound)) => !is_before(bound, block.header().time()),
+            _ => true,
+        });
+
+    all_block_ids
+        .map(move |block| {
+            block.map(|fuel_block| {
+                let (header, mut txs) = fuel_block.into_inner();
+
+                if direction == IterDirection::Reverse {
+                    txs.reverse();
+                }
+
+                txs.into_iter().zip(iter::repeat(*header.height()))
+            })
+        })
+        .flatten_ok()
+        .map(|result| {
+            result.map(|(tx_id, block_height)| {
+                SortedTxCursor::new(block_height, tx_id.into())
+            })
+        })
+        .skip_while(move |result| {
+            if let Ok(sorted) = result {
+                if let Some(start) = start {
+                    return sorted != &start
+                }
+            }
+            false
+        })
+}
+
+/// The position of the transaction in the orderings other than `BLOCK_ORDER`:
+/// the descending key of the ordering, and the latest blocks first for the ties.
+fn sorted_position(sorted: &SortedTxCursor) -> impl Ord {
+    Reverse((sorted.sort_key, sorted.block_height, sorted.tx_id))
+}
+
+/// The transaction with its cursor, compared only by the `sorted_position`.
+struct SortedEntry(SortedTxCursor, FuelTx);
+
+impl PartialEq for SortedEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for SortedEntry {}
+
+impl Ord for SortedEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sorted_position(&self.0).cmp(&sorted_position(&other.0))
+    }
+}
+
+impl PartialOrd for SortedEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns at most `limit` smallest `items` in the ascending order. Only `limit`
+/// items are kept in memory, so the whole input is never sorted.
+fn smallest<T: Ord>(
+    items: impl Iterator<Item = StorageResult<T>>,
+    limit: usize,
+) -> StorageResult<Vec<T>> {
+    let mut heap = BinaryHeap::with_capacity(limit.saturating_add(1));
+    for item in items {
+        heap.push(item?);
+        if heap.len() > limit {
+            heap.pop();
+        }
+    }
+    Ok(heap.into_sorted_vec())
+}
+
+/// Returns at most `limit` transactions from the `start` in the `direction` of
+/// the `order`, among the latest `SORTED_TRANSACTIONS_WINDOW` transactions of
+/// the blocks with the time within the inclusive range. The `start` itself is
+/// included if it is still in the window. The cursors hold the sort key, and
+/// the ties keep the latest blocks first.
+///
+/// Only the best `limit` transactions are kept while the window is read, instead
+/// of sorting the whole window. The receipts are read only for the `FEE_DESC`
+/// ordering, which needs the gas used.
+#[allow(clippy::too_many_arguments)]
+fn sorted_transactions(
+    query: &Database,
+    order: TransactionOrder,
+    start_time: Option<Tai64>,
+    end_time: Option<Tai64>,
+    start: Option<SortedTxCursor>,
+    direction: IterDirection,
+    limit: usize,
+    params: &ConsensusParameters,
+) -> StorageResult<Vec<(SortedTxCursor, FuelTx)>> {
+    let window = block_ordered_transactions(
+        query,
+        None,
+        IterDirection::Reverse,
+        start_time,
+        end_time,
+    )
+    .take(SORTED_TRANSACTIONS_WINDOW)
+    .map(|sorted| {
+        let sorted = sorted?;
+        let tx = query.transaction(&sorted.tx_id.0)?;
+        let key = match order {
+            TransactionOrder::BlockOrder => 0,
+            TransactionOrder::GasPriceDesc => match &tx {
+                FuelTx::Script(script) => *script.gas_price(),
+                FuelTx::Create(create) => *create.gas_price(),
+                FuelTx::Mint(_) => 0,
+            },
+            TransactionOrder::FeeDesc => {
+                let receipts = query
+                    .receipts(&sorted.tx_id.0)
+                    .into_api_result::<Vec<Receipt>, StorageError>()?
+                    .unwrap_or_default();
+                paid_fee(&tx, &receipts, params)
+            }
+        };
+        let sorted =
+            SortedTxCursor::with_sort_key(sorted.block_height, sorted.tx_id, key);
+        Ok(SortedEntry(sorted, tx))
+    })
+    // The page starts at the position of the cursor, which stays valid when
+    // its transaction leaves the window.
+    .filter(|entry| match (entry, start) {
+        (Ok(SortedEntry(sorted, _)), Some(start)) => {
+            let position = sorted_position(sorted);
+            match direction {
+                IterDirection::Forward => position >= sorted_position(&start),
+                IterDirection::Reverse => position <= sorted_position(&start),
+            }
+        }
+        _ => true,
+    });
+
+    let entries = match direction {
+        IterDirection::Forward => smallest(window, limit)?,
+        IterDirection::Reverse => {
+            smallest(window.map(|entry| entry.map(Reverse)), limit)?
+                .into_iter()
+                .map(|Reverse(entry)| entry)
+                .collect()
+        }
+    };
+    Ok(entries
+        .into_iter()
+        .map(|SortedEntry(sorted, tx)| (sorted, tx))
+        .collect())
+}
+
+/// Returns the fee paid by the committed `tx` for the gas used in its `receipts`.
+fn paid_fee(tx: &FuelTx, receipts: &[Receipt], params: &ConsensusParameters) -> u64 {
+    let gas_used = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap_or_default();
+    let fee = match tx {
+        FuelTx::Script(script) => TransactionFee::checked_from_values(
+            params,
+            script.metered_bytes_size() as u64,
+            script.gas_used_by_predicates(),
+            gas_used,
+            *script.gas_price(),
+        ),
+        FuelTx::Create(create) => TransactionFee::checked_from_values(
+            params,
+            create.metered_bytes_size() as u64,
+            create.gas_used_by_predicates(),
+            gas_used,
+            *create.gas_price(),
+        ),
+        FuelTx::Mint(_) => None,
+    };
+    fee.map(|fee| fee.max_fee()).unwrap_or_default()
+}
+
+/// Inserts the `tx` into the `TxPool`. The transaction that is already in
+/// the `TxPool` is reported as known instead of the error.
+async fn insert_into_txpool(
+    ctx: &Context<'_>,
+    tx: &FuelTx,
+) -> async_graphql::Result<InsertionStatus> {
+    let txpool = ctx.data_unchecked::<TxPool>();
+    let result = txpool
+        .insert(vec![Arc::new(tx.clone())])
+        .await
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("The `TxPool` didn't return the insertion result"))
+        .and_then(|result| result);
+    match result {
+        Ok(insertion) => Ok(InsertionStatus::from_insertion(&insertion)),
+        Err(err) if is_already_known(&err) => Ok(InsertionStatus::from_known_tx(tx)),
+        Err(err) => Err(insertion_error(ctx, err)),
+    }
+}
+
+/// Converts the error of the insertion into the `TxPool`. The rejection because
+/// the `TxPool` (or its part for the prevalidated transactions) is full is not
+/// a problem of the transaction, so it gets the `POOL_FULL` code and the `retry_after`
+/// extension with the number of seconds until the next block is expected to free
+/// the space. The other errors are returned as is.
+fn insertion_error(ctx: &Context<'_>, err: anyhow::Error) -> async_graphql::Error {
+    if !matches!(
+        err.downcast_ref::<txpool::Error>(),
+        Some(txpool::Error::NotInsertedLimitHit)
+            | Some(txpool::Error::NotInsertedPrevalidatedLimitHit)
+    ) {
+        return err.into()
+    }
+
+    let query: &Database = ctx.data_unchecked();
+    let retry_after = expected_block_interval(query);
+    async_graphql::Error::new(format!("{err}; retry after {retry_after} seconds"))
+        .extend_with(|_, e| {
+            e.set("code", POOL_FULL);
+            e.set("retry_after", retry_after);
+        })
+}
+
+/// Fails with the `ALREADY_COMMITTED` code and the `block_height` extension if
+/// the transaction is already included in a block, since simulating it again
+/// only gives the results of a fork of the chain.
+fn ensure_not_committed(query: &Database, tx_id: &TxId) -> async_graphql::Result<()> {
+    let block_id = match query.tx_status(tx_id) {
+        Ok(txpool::TransactionStatus::Success { block_id, .. })
+        | Ok(txpool::TransactionStatus::Failed { block_id, .. }) => block_id,
+        Ok(_) | Err(StorageError::NotFound(_, _)) => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+    let height = *query.block(&block_id)?.header().height();
+    Err(async_graphql::Error::new(format!(
+        "The transaction {tx_id} is already committed at block {height}"
+    ))
+    .extend_with(|_, e| {
+        e.set("code", ALREADY_COMMITTED);
+        e.set("block_height", *height);
+    }))
+}
+
+/// Executes the dry-run under the limits of the node. All the resolvers executing
+/// dry-runs go through it, so the limits apply to each of them the same way.
+///
+/// Fails with the `NODE_BUSY` code if the `DryRunLimiter` has no free permits, and
+/// with the `DRY_RUN_TIMEOUT` code if the `execution` exceeds the `dry_run_timeout`.
+/// The VM can't be interrupted, so the timed out execution still runs to the end
+/// in the background, bounded by the gas limit of the transaction. The `execution`
+/// runs in its own task holding the permit, so the permit is released only when
+/// the VM stops, not when the timeout elapses.
+async fn limited_dry_run<T, E, F>(
+    ctx: &Context<'_>,
+    execution: impl FnOnce(BlockProducer) -> F,
+) -> async_graphql::Result<T>
+where
+    F: Future<Output = Result<T, E>> + Send + 'static,
+    T: Send + 'static,
+    E: Into<async_graphql::Error> + Send + 'static,
+{
+    let permit = ctx.data_unchecked::<DryRunLimiter>().try_acquire()?;
+    let execution = execution(ctx.data_unchecked::<BlockProducer>().clone());
+    let execution = tokio::spawn(
+        async move {
+            let _permit = permit;
+            execution.await
+        }
+        .in_current_span(),
+    );
+    let result = match ctx.data_unchecked::<Config>().dry_run_timeout {
+        Some(timeout) => {
+            tokio::time::timeout(timeout, execution)
+                .await
+                .map_err(|_| dry_run_timeout_error(timeout))?
+        }
+        None => execution.await,
+    };
+    result?.map_err(Into::into)
+}
+
+/// The error of the dry-run that exceeded the `timeout` of the node.
+fn dry_run_timeout_error(timeout: Duration) -> async_graphql::Error {
+    async_graphql::Error::new(format!(
+        "The dry-run exceeded the time limit of {} milliseconds",
+        timeout.as_millis()
+    ))
+    .extend_with(|_, e| e.set("code", DRY_RUN_TIMEOUT))
+}
+
+/// Executes the dry-run of the `tx` with `limited_dry_run` and returns its receipts.
+async fn dry_run_receipts(
+    ctx: &Context<'_>,
+    tx: FuelTx,
+    utxo_validation: Option<bool>,
+) -> async_graphql::Result<Vec<Receipt>> {
+    limited_dry_run(ctx, |block_producer| async move {
+        block_producer
+            .dry_run_tx(tx, None, None, utxo_validation)
+            .await
+    })
+    .await
+}
+
+/// Returns `true` if the submission was rejected because the `TxPool` is full.
+fn is_pool_full<T>(result: &async_graphql::Result<T>) -> bool {
+    matches!(
+        result,
+        Err(err) if err
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("code"))
+            == Some(&POOL_FULL.into())
+    )
+}
+
+/// The average interval in seconds between the last `POOL_TURNOVER_BLOCKS` blocks,
+/// rounded up. At least one second, if there are not enough blocks to measure it.
+pub(crate) fn expected_block_interval(query: &Database) -> u64 {
+    let times: Vec<_> = query
+        .compressed_blocks(None, IterDirection::Reverse)
+        .take(POOL_TURNOVER_BLOCKS + 1)
+        .filter_map(|block| block.ok())
+        .map(|block| block.header().time().0)
+        .collect();
+    let interval = match (times.first(), times.last()) {
+        (Some(latest), Some(oldest)) if times.len() > 1 => {
+            let intervals = times.len() as u64 - 1;
+            (latest.saturating_sub(*oldest) + intervals - 1) / intervals
+        }
+        _ => 0,
+    };
+    interval.max(1)
+}
+
+/// The average number of the transactions besides the coinbase included into
+/// the last `POOL_TURNOVER_BLOCKS` blocks, rounded up. At least one.
+pub(crate) fn expected_transactions_per_block(query: &Database) -> u64 {
+    let counts: Vec<_> = query
+        .compressed_blocks(None, IterDirection::Reverse)
+        .take(POOL_TURNOVER_BLOCKS)
+        .filter_map(|block| block.ok())
+        .map(|block| block.transactions().len().saturating_sub(1) as u64)
+        .collect();
+    let blocks = counts.len() as u64;
+    let average = match blocks {
+        0 => 0,
+        _ => (counts.iter().sum::<u64>() + blocks - 1) / blocks,
+    };
+    average.max(1)
+}
 
 #[derive(Default)]
 pub struct TxQuery;
@@ -92,6 +652,157 @@ impl TxQuery {
         }
     }
 
+    /// Looks up the transaction like `transaction`, but tells why it isn't found
+    /// instead of returning `null`. Returns `SqueezedOutStatus` with the reason if
+    /// the transaction was removed from the `TxPool` without being committed, so
+    /// there is no point to wait for it, and `UnknownTransaction` if the node never
+    /// saw the transaction or doesn't remember its removal.
+    async fn transaction_lookup(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<TransactionLookup> {
+        let query: &Database = ctx.data_unchecked();
+        let id = id.0;
+        let txpool = ctx.data_unchecked::<TxPool>();
+
+        if let Some(transaction) = txpool.transaction(id) {
+            return Ok(TransactionLookup::Found(Transaction(transaction, id)))
+        }
+        match query
+            .status(&id)
+            .into_api_result::<txpool::TransactionStatus, async_graphql::Error>()?
+        {
+            Some(txpool::TransactionStatus::Success { .. })
+            | Some(txpool::TransactionStatus::Failed { .. }) => {
+                let tx = query.transaction(&id)?;
+                return Ok(TransactionLookup::Found(Transaction::from_tx(id, tx)))
+            }
+            _ => {}
+        }
+
+        // The removals from the `TxPool` are kept only in the status history
+        let reason = get_tx_status_history(id, query, txpool)?
+            .pop()
+            .and_then(|record| match record.status {
+                txpool::TransactionStatus::SqueezedOut { reason } => Some(reason),
+                _ => None,
+            });
+        Ok(match reason {
+            Some(reason) => TransactionLookup::SqueezedOut(SqueezedOutStatus { reason }),
+            None => TransactionLookup::Unknown(UnknownTransaction { id: id.into() }),
+        })
+    }
+
+    /// Returns the committed transaction, `null` if the transaction is unknown
+    /// or still pending. Unlike `transaction`, it reads only the database and
+    /// doesn't lock the `TxPool`, so it doesn't contend with the insertions.
+    async fn committed_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<Transaction>> {
+        let query: &Database = ctx.data_unchecked();
+        let id = id.0;
+        query
+            .transaction(&id)
+            .map(|tx| Transaction::from_tx(id, tx))
+            .into_api_result()
+    }
+
+    /// Returns the receipts of the committed transaction, `null` if the transaction
+    /// is unknown or still pending. The receipts are read without the transaction,
+    /// for the clients that need only the receipts.
+    async fn transaction_receipts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<Vec<receipt::Receipt>>> {
+        let query: &Database = ctx.data_unchecked();
+        let receipts = query
+            .receipts(&id.0)
+            .into_api_result::<Vec<_>, async_graphql::Error>()?;
+        Ok(receipts.map(|receipts| receipts.into_iter().map(receipt::Receipt).collect()))
+    }
+
+    /// Returns the current status of the transaction, `null` if the transaction
+    /// is unknown. Unlike `statusChange`, it doesn't wait for the next update.
+    async fn transaction_status(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionStatus>> {
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        get_tx_status(id.0, query, txpool).map_err(Into::into)
+    }
+
+    /// Returns the current statuses of the transactions in the order of the `ids`,
+    /// with the `null` status for the unknown transactions. It replaces
+    /// the separate `transaction_status` queries of the clients tracking many
+    /// transactions. At most `MAX_TRANSACTION_STATUSES` ids are accepted.
+    async fn transaction_statuses(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The IDs of the transactions")] ids: Vec<TransactionId>,
+    ) -> async_graphql::Result<Vec<TransactionStatusEntry>> {
+        if ids.len() > MAX_TRANSACTION_STATUSES {
+            return Err(anyhow!(
+                "At most {MAX_TRANSACTION_STATUSES} ids are accepted, got {}",
+                ids.len()
+            )
+            .into())
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let tx_ids = ids.iter().map(|id| id.0).collect_vec();
+        let statuses = get_tx_statuses(&tx_ids, query, txpool)?;
+        Ok(ids
+            .into_iter()
+            .zip(statuses)
+            .map(|(id, status)| TransactionStatusEntry { id, status })
+            .collect())
+    }
+
+    /// Returns the recorded transitions of the status of the transaction, from
+    /// the oldest to the latest, in the order they were recorded. The list is empty
+    /// for the unknown transactions.
+    ///
+    /// The submissions and the removals from the `TxPool` are kept in memory for
+    /// the latest `STATUS_HISTORY_CAPACITY` transactions, at most
+    /// `MAX_STATUS_HISTORY_PER_TX` per transaction, and are lost on restart.
+    /// The commit of the transaction is built from its status in the database,
+    /// so it is always the last transition of the committed transactions.
+    async fn transaction_status_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Vec<TransactionStatusTransition>> {
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let history = get_tx_status_history(id.0, query, txpool)?;
+        Ok(history.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the transactions sorted by the height of their block.
+    ///
+    /// If `start_time` or `end_time` is set, only the transactions of the blocks
+    /// with the time within the inclusive range are returned. The iteration stops
+    /// at the first block past the range.
+    ///
+    /// The orderings other than `BLOCK_ORDER` sort only the latest
+    /// `SORTED_TRANSACTIONS_WINDOW` transactions within the time range, because
+    /// sorting the whole chain requires an index. The window is read again for each
+    /// page, and only the transactions of the page are kept. Their cursors hold the sort key, so the next page continues
+    /// after the position of the cursor even if the window moved with the new blocks.
+    /// The cursors of one ordering can't be used with another one.
+    ///
+    /// Only the committed transactions are returned, because every ordering reads
+    /// the transactions from the blocks. The pending transactions of the `TxPool`
+    /// are never returned. The `committed_only` has no effect and is kept only for
+    /// compatibility.
+    #[allow(clippy::too_many_arguments)]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -99,56 +810,130 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "The earliest time of the block of the transaction")]
+        start_time: Option<Tai64Timestamp>,
+        #[graphql(desc = "The latest time of the block of the transaction")]
+        end_time: Option<Tai64Timestamp>,
+        #[graphql(desc = "The ordering of the transactions, `BLOCK_ORDER` by default")]
+        order_by: Option<TransactionOrder>,
+        #[graphql(desc = "Has no effect, only the committed transactions are returned")]
+        _committed_only: Option<bool>,
     ) -> async_graphql::Result<
         Connection<SortedTxCursor, Transaction, EmptyFields, EmptyFields>,
     > {
         let db_query: &Database = ctx.data_unchecked();
         let tx_query: &Database = ctx.data_unchecked();
-        crate::schema::query_pagination(
+        let config = ctx.data_unchecked::<Config>();
+        let order_by = order_by.unwrap_or(TransactionOrder::BlockOrder);
+        let start_time = start_time.map(|time| time.0);
+        let end_time = end_time.map(|time| time.0);
+        // The ids are known from the blocks, so the transactions are not read
+        // if the query doesn't need their bodies.
+        let only_ids = connection_requires_only_ids(ctx);
+        // The page, the entry of the cursor skipped by the pagination, and
+        // the next entry for the `has_next_page`.
+        let page_limit = first
+            .or(last)
+            .and_then(|count| usize::try_from(count).ok())
+            .map_or(SORTED_TRANSACTIONS_WINDOW, |count| count.saturating_add(2));
+        crate::schema::query_pagination_with_budget(
             after,
             before,
             first,
             last,
+            transaction_page_budget(config),
             |start: &Option<SortedTxCursor>, direction| {
                 let start = *start;
-                let block_id = start.map(|sorted| sorted.block_height);
-                let all_block_ids = db_query.compressed_blocks(block_id, direction);
-
-                let all_txs = all_block_ids
-                    .map(move |block| {
-                        block.map(|fuel_block| {
-                            let (header, mut txs) = fuel_block.into_inner();
+                if order_by != TransactionOrder::BlockOrder {
+                    let sorted = sorted_transactions(
+                        db_query,
+                        order_by,
+                        start_time,
+                        end_time,
+                        start,
+                        direction,
+                        page_limit,
+                        &config.transaction_parameters,
+                    )?;
+                    let sorted = sorted
+                        .into_iter()
+                        .map(|(sorted, tx)| -> StorageResult<_> {
+                            Ok((sorted, Transaction::from_tx(sorted.tx_id.0, tx)))
+                        });
+                    return Ok(itertools::Either::Left(sorted))
+                }
 
-                            if direction == IterDirection::Reverse {
-                                txs.reverse();
+                let all_txs = block_ordered_transactions(
+                    db_query, start, direction, start_time, end_time,
+                );
+                let all_txs =
+                    all_txs.map(move |result: StorageResult<SortedTxCursor>| {
+                        result.and_then(|sorted| {
+                            if only_ids {
+                                return Ok((sorted, Transaction::id_only(sorted.tx_id.0)))
                             }
+                            let tx = tx_query.transaction(&sorted.tx_id.0)?;
 
-                            txs.into_iter().zip(iter::repeat(*header.height()))
-                        })
-                    })
-                    .flatten_ok()
-                    .map(|result| {
-                        result.map(|(tx_id, block_height)| {
-                            SortedTxCursor::new(block_height, tx_id.into())
+                            Ok((sorted, Transaction::from_tx(sorted.tx_id.0, tx)))
                         })
-                    })
-                    .skip_while(move |result| {
-                        if let Ok(sorted) = result {
-                            if let Some(start) = start {
-                                return sorted != &start
-                            }
-                        }
-                        false
                     });
-                let all_txs = all_txs.map(|result: StorageResult<SortedTxCursor>| {
-                    result.and_then(|sorted| {
-                        let tx = tx_query.transaction(&sorted.tx_id.0)?;
 
-                        Ok((sorted, Transaction::from_tx(sorted.tx_id.0, tx)))
-                    })
-                });
+                Ok(itertools::Either::Right(all_txs))
+            },
+        )
+        .await
+    }
+
+    /// Returns the transactions of the block at the `height` page by page.
+    /// The cursor is the index of the transaction within the block.
+    async fn transactions_in_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the block")] height: U32,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, Transaction, EmptyFields, EmptyFields>>
+    {
+        let query: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let block_id = query
+            .block_id(&height.0.into())
+            .into_api_result::<_, async_graphql::Error>()?;
+        let tx_ids = match block_id {
+            Some(block_id) => query.block(&block_id)?.into_inner().1,
+            None => vec![],
+        };
+
+        crate::schema::query_pagination_with_budget(
+            after,
+            before,
+            first,
+            last,
+            transaction_page_budget(config),
+            |start: &Option<U32>, direction| {
+                let mut tx_ids: Vec<_> = tx_ids.into_iter().enumerate().collect();
+                let start = start.map(|index| index.0 as usize);
+
+                match direction {
+                    IterDirection::Forward => {
+                        let start = start.unwrap_or_default().min(tx_ids.len());
+                        tx_ids.drain(..start);
+                    }
+                    IterDirection::Reverse => {
+                        if let Some(start) = start {
+                            tx_ids.truncate(start.saturating_add(1));
+                        }
+                        tx_ids.reverse();
+                    }
+                }
 
-                Ok(all_txs)
+                let txs = tx_ids.into_iter().map(|(index, tx_id)| {
+                    let tx = query.transaction(&tx_id)?;
+                    Ok((U32(index as u32), Transaction::from_tx(tx_id, tx)))
+                });
+                Ok(txs)
             },
         )
         .await
@@ -162,8 +947,7 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
-    {
+    ) -> async_graphql::Result<OwnedTransactionConnection> {
         // Rocksdb doesn't support reverse iteration over a prefix
         if matches!(last, Some(last) if last > 0) {
             return Err(
@@ -175,11 +959,12 @@ impl TxQuery {
         let config = ctx.data_unchecked::<Config>();
         let owner = fuel_types::Address::from(owner);
 
-        crate::schema::query_pagination(
+        crate::schema::query_pagination_with_edge_fields(
             after,
             before,
             first,
             last,
+            transaction_page_budget(config),
             |start: &Option<TxPointer>, direction| {
                 let start = (*start).map(Into::into);
                 let txs =
@@ -194,83 +979,1392 @@ impl TxQuery {
                         });
                 Ok(txs)
             },
+            |tx: &Transaction| OwnedTransactionFields {
+                owner_role: OwnerRole::of(&tx.0, &owner),
+            },
         )
         .await
     }
 
-    /// Estimate the predicate gas for the provided transaction
-    async fn estimate_predicates(
+    /// Returns the transactions that called the contract, from the oldest
+    /// to the newest. Available only if the `contract_transactions_index_enabled`
+    /// is set in the configuration of the node, the blocks produced before enabling
+    /// it are not indexed.
+    async fn transactions_by_contract(
         &self,
         ctx: &Context<'_>,
-        tx: HexString,
-    ) -> async_graphql::Result<Transaction> {
-        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        contract_id: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
         let config = ctx.data_unchecked::<Config>();
+        if !config.contract_transactions_index_enabled {
+            return Err(anyhow!(
+                "The contract transactions index must be enabled to use this endpoint"
+            )
+            .into())
+        }
 
-        tx.estimate_predicates(&config.transaction_parameters, &config.gas_costs)?;
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
 
-        Ok(Transaction::from_tx(
-            tx.id(&config.transaction_parameters.chain_id),
-            tx,
-        ))
-    }
+        let query: &Database = ctx.data_unchecked();
+        let contract_id = fuel_types::ContractId::from(contract_id);
 
-    #[cfg(feature = "test-helpers")]
-    /// Returns all possible receipts for test purposes.
-    async fn all_receipts(&self) -> Vec<receipt::Receipt> {
-        receipt::all_receipts()
-            .into_iter()
-            .map(Into::into)
-            .collect()
+        crate::schema::query_pagination_with_budget(
+            after,
+            before,
+            first,
+            last,
+            transaction_page_budget(config),
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract_id, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.transaction_parameters.chain_id);
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        )
+        .await
     }
-}
-
-#[derive(Default)]
-pub struct TxMutation;
 
-#[Object]
-impl TxMutation {
-    /// Execute a dry-run of the transaction using a fork of current state, no changes are committed.
-    async fn dry_run(
+    /// Returns the ids of the transactions from the last `blocks` blocks that failed
+    /// with the panic `reason`, e.g. `ArithmeticOverflow`. The newest transactions
+    /// go first. At most `MAX_PANIC_REASON_SCAN_BLOCKS` blocks can be scanned.
+    /// Fails if the `reason` isn't the name of a `PanicReason`.
+    async fn transactions_by_panic_reason(
         &self,
         ctx: &Context<'_>,
-        tx: HexString,
-        // If set to false, disable input utxo validation, overriding the configuration of the node.
-        // This allows for non-existent inputs to be used without signature validation
-        // for read-only calls.
-        utxo_validation: Option<bool>,
-    ) -> async_graphql::Result<Vec<receipt::Receipt>> {
-        let block_producer = ctx.data_unchecked::<BlockProducer>();
-        let config = ctx.data_unchecked::<Config>();
+        #[graphql(desc = "The name of the panic reason")] reason: String,
+        #[graphql(desc = "The number of the latest blocks to scan")] blocks: U32,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        if blocks.0 > MAX_PANIC_REASON_SCAN_BLOCKS {
+            return Err(anyhow!(
+                "At most {MAX_PANIC_REASON_SCAN_BLOCKS} blocks can be scanned, got {}",
+                blocks.0
+            )
+            .into())
+        }
 
-        let mut tx = FuelTx::from_bytes(&tx.0)?;
-        tx.precompute(&config.transaction_parameters.chain_id)?;
+        let reason = parse_panic_reason(&reason)?;
 
-        let receipts = block_producer.dry_run_tx(tx, None, utxo_validation).await?;
-        Ok(receipts.iter().map(Into::into).collect())
+        let query: &Database = ctx.data_unchecked();
+        let mut tx_ids = vec![];
+        for block in query
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(blocks.0 as usize)
+        {
+            let (_, block_tx_ids) = block?.into_inner();
+            for tx_id in block_tx_ids.into_iter().rev() {
+                let receipts = query
+                    .receipts(&tx_id)
+                    .into_api_result::<Vec<_>, async_graphql::Error>()?
+                    .unwrap_or_default();
+                // The last panic receipt is the one that terminated the transaction
+                let panic_reason =
+                    receipts.iter().rev().find_map(|receipt| match receipt {
+                        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+                        _ => None,
+                    });
+                if panic_reason == Some(reason) {
+                    tx_ids.push(tx_id.into());
+                }
+            }
+        }
+
+        Ok(tx_ids)
     }
 
-    /// Submits transaction to the `TxPool`.
-    ///
-    /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
-    async fn submit(
+    /// Returns the ids of the pending transactions in the `TxPool` with an input that
+    /// references the `utxo_id`, sorted from the highest to the lowest gas price.
+    async fn pending_transactions_spending_utxo(
         &self,
         ctx: &Context<'_>,
-        tx: HexString,
-    ) -> async_graphql::Result<Transaction> {
+        #[graphql(desc = "The id of the UTXO")] utxo_id: UtxoId,
+    ) -> Vec<TransactionId> {
         let txpool = ctx.data_unchecked::<TxPool>();
-        let config = ctx.data_unchecked::<Config>();
-        let tx = FuelTx::from_bytes(&tx.0)?;
+        txpool
+            .transactions_spending_utxo(utxo_id.into())
+            .iter()
+            .map(|tx| tx.id().into())
+            .collect()
+    }
 
-        let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx.clone())])
-            .await
+    /// Returns the pending transactions in the `TxPool` with an input that references
+    /// the `utxo_id`, sorted from the highest to the lowest gas price. Unlike
+    /// `pending_transactions_spending_utxo`, returns the whole transactions, so
+    /// the client can compare the gas price of the holder with its own to decide
+    /// whether to wait, replace it, or rebuild its transaction with other coins.
+    async fn transactions_spending_utxo(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The id of the UTXO")] utxo_id: UtxoId,
+    ) -> Vec<Transaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .transactions_spending_utxo(utxo_id.into())
+            .iter()
+            .map(|tx| Transaction::from_tx(tx.id(), FuelTx::from(tx.as_ref())))
+            .collect()
+    }
+
+    /// Returns the state of the UTXO consumed by each coin and message input of
+    /// the transaction, to detect the inputs that would make the submission fail.
+    /// The contract inputs are skipped.
+    ///
+    /// The coins created by the pending transactions aren't in the database yet,
+    /// so they are reported as `SPENT`.
+    async fn transaction_input_status(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Vec<TransactionInputStatus>> {
+        let tx = decode_transaction(ctx, &tx)?;
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+
+        let inputs = match &tx {
+            FuelTx::Script(script) => script.inputs(),
+            FuelTx::Create(create) => create.inputs(),
+            FuelTx::Mint(_) => {
+                return Err(anyhow!("Mint transactions can't be submitted").into())
+            }
+        };
+
+        let mut statuses = vec![];
+        for (index, input) in inputs.iter().enumerate() {
+            let (utxo_id, nonce, unspent) = match input {
+                Input::Contract(_) => continue,
+                Input::CoinSigned(_) | Input::CoinPredicate(_) => {
+                    let utxo_id = *input.utxo_id().expect("Coins have the UTXO id");
+                    let unspent = match query.coin(utxo_id) {
+                        Ok(_) => true,
+                        Err(StorageError::NotFound(_, _)) => false,
+                        Err(err) => return Err(err.into()),
+                    };
+                    (Some(utxo_id), None, unspent)
+                }
+                _ => {
+                    let nonce = *input.nonce().expect("Messages have the nonce");
+                    let unspent = match query.message(&nonce) {
+                        Ok(_) => !query.message_is_spent(&nonce)?,
+                        Err(StorageError::NotFound(_, _)) => false,
+                        Err(err) => return Err(err.into()),
+                    };
+                    (None, Some(nonce), unspent)
+                }
+            };
+
+            let reserved_by: Vec<TransactionId> = if unspent {
+                txpool
+                    .conflicting_transactions(std::slice::from_ref(input))
+                    .iter()
+                    .map(|tx| tx.id().into())
+                    .collect()
+            } else {
+                vec![]
+            };
+            let state = match (unspent, reserved_by.is_empty()) {
+                (false, _) => InputUtxoState::Spent,
+                (true, true) => InputUtxoState::Unspent,
+                (true, false) => InputUtxoState::Reserved,
+            };
+
+            statuses.push(TransactionInputStatus {
+                index: (index as u32).into(),
+                utxo_id: utxo_id.map(Into::into),
+                nonce: nonce.map(Into::into),
+                state,
+                reserved_by,
+            });
+        }
+
+        Ok(statuses)
+    }
+
+    /// Returns the histogram of the gas prices of the transactions in the `TxPool`.
+    /// The range between the lowest and the highest price is split into equal buckets,
+    /// ordered from the lowest to the highest price. Empty if the `TxPool` is empty.
+    async fn mempool_gas_price_distribution(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Vec<GasPriceBucket> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        GasPriceBucket::histogram(&txpool.gas_prices(), MEMPOOL_GAS_PRICE_BUCKETS)
+    }
+
+    /// Estimates the impact of raising the minimum gas price to
+    /// the `proposed_min_gas_price` on the `TxPool`: the number and the total
+    /// maximum fee of the pending transactions priced below it.
+    ///
+    /// It is only the analysis, the minimum gas price of the node isn't changed.
+    async fn gas_price_impact(
+        &self,
+        ctx: &Context<'_>,
+        proposed_min_gas_price: U64,
+    ) -> GasPriceImpact {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        GasPriceImpact::below(&txpool.pending_transactions(), proposed_min_gas_price.0)
+    }
+
+    /// Returns the average number of milliseconds between the submission of
+    /// the transactions committed in the last `blocks` blocks and the time of their
+    /// inclusion block. Returns `0` if no such transaction is known.
+    ///
+    /// The result is best-effort. Only the transactions whose submission time the node
+    /// recorded are covered: the ones that passed through the `TxPool` of this node and
+    /// were among the latest `COMMITTED_SUBMISSION_TIMES_CAPACITY` committed ones.
+    /// The submission times are kept in memory, so they are lost on the restart of
+    /// the node. The time of the block has the precision of seconds.
+    /// At most `MAX_CONFIRMATION_TIME_SCAN_BLOCKS` blocks can be scanned.
+    async fn average_confirmation_time(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest blocks to scan")] blocks: U32,
+    ) -> async_graphql::Result<U64> {
+        if blocks.0 > MAX_CONFIRMATION_TIME_SCAN_BLOCKS {
+            return Err(anyhow!(
+                "At most {MAX_CONFIRMATION_TIME_SCAN_BLOCKS} blocks can be scanned, got {}",
+                blocks.0
+            )
+            .into())
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let mut total_millis: u128 = 0;
+        let mut count: u128 = 0;
+        for block in query
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(blocks.0 as usize)
+        {
+            let block = block?;
+            let block_millis = u128::try_from(block.header().time().to_unix())
+                .unwrap_or_default()
+                * 1000;
+            for tx_id in block.transactions() {
+                if let Some(submitted) = txpool.committed_submission_time(*tx_id) {
+                    total_millis += block_millis.saturating_sub(submitted.as_millis());
+                    count += 1;
+                }
+            }
+        }
+
+        let average = total_millis.checked_div(count).unwrap_or_default();
+        Ok(u64::try_from(average).unwrap_or(u64::MAX).into())
+    }
+
+    /// Returns the statistics of the gas prices and the sizes of the transactions
+    /// included into the last `block_window` blocks, to help picking a competitive
+    /// gas price. The `block_window` can't exceed the `max_fee_statistics_blocks`
+    /// of the node.
+    async fn fee_statistics(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest blocks to scan")] block_window: U32,
+    ) -> async_graphql::Result<FeeStatistics> {
+        let config = ctx.data_unchecked::<Config>();
+        if block_window.0 > config.max_fee_statistics_blocks {
+            return Err(anyhow!(
+                "At most {} blocks can be scanned, got {}",
+                config.max_fee_statistics_blocks,
+                block_window.0
+            )
+            .into())
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let mut block_count = 0;
+        let mut transactions = vec![];
+        for block in query
+            .compressed_blocks(None, IterDirection::Reverse)
+            .take(block_window.0 as usize)
+        {
+            let block = block?;
+            block_count += 1;
+            for tx_id in block.transactions() {
+                match query.transaction(tx_id)? {
+                    FuelTx::Script(script) => transactions
+                        .push((*script.gas_price(), script.metered_bytes_size() as u64)),
+                    FuelTx::Create(create) => transactions
+                        .push((*create.gas_price(), create.metered_bytes_size() as u64)),
+                    FuelTx::Mint(_) => {}
+                }
+            }
+        }
+
+        Ok(FeeStatistics::new(block_count, &transactions))
+    }
+
+    /// Estimate the predicate gas for the provided transaction.
+    ///
+    /// If the node limits the gas of the estimation, it fails with
+    /// the `PREDICATE_GAS_LIMIT_EXCEEDED` error at the first predicate that
+    /// exceeds the limit. All predicates are executed even with `only_missing`.
+    ///
+    /// The request is traced in the `estimate_predicates` span with the `tx_id`,
+    /// and the `decode_time_us` and `vm_time_us` in microseconds.
+    async fn estimate_predicates(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        #[graphql(
+            desc = "If `true`, only the inputs with the zero `predicate_gas_used` \
+                    are estimated, and the rest keep the provided values"
+        )]
+        only_missing: Option<bool>,
+    ) -> async_graphql::Result<Transaction> {
+        let span = tracing::info_span!(
+            "estimate_predicates",
+            tx_id = field::Empty,
+            decode_time_us = field::Empty,
+            vm_time_us = field::Empty,
+        );
+        let decode_start = Instant::now();
+        let mut tx = decode_transaction(ctx, &tx)?;
+        let config = ctx.data_unchecked::<Config>();
+        span.record("decode_time_us", decode_start.elapsed().as_micros() as u64);
+
+        let vm_start = Instant::now();
+        span.in_scope(|| {
+            predicate_estimation::estimate_predicates(
+                &mut tx,
+                &config.transaction_parameters,
+                &config.gas_costs,
+                config.estimate_predicates_gas_limit,
+                only_missing.unwrap_or(false),
+            )
+        })?;
+        span.record("vm_time_us", vm_start.elapsed().as_micros() as u64);
+
+        let id = tx.id(&config.transaction_parameters.chain_id);
+        span.record("tx_id", field::display(id));
+        Ok(Transaction::from_tx(id, tx))
+    }
+
+    /// Estimate the predicate gas for the provided transaction, and report the gas
+    /// of the verification of all its inputs, including the signature checks,
+    /// so the fee of the transaction can be computed in one request.
+    /// The predicates are limited the same as for `estimate_predicates`.
+    async fn estimate_predicates_and_fees(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<PredicatesAndFeesEstimate> {
+        let mut tx = decode_transaction(ctx, &tx)?;
+        let config = ctx.data_unchecked::<Config>();
+
+        predicate_estimation::estimate_predicates(
+            &mut tx,
+            &config.transaction_parameters,
+            &config.gas_costs,
+            config.estimate_predicates_gas_limit,
+            false,
+        )?;
+
+        Ok(PredicatesAndFeesEstimate::new(
+            tx,
+            &config.transaction_parameters.chain_id,
+            &config.gas_costs,
+        ))
+    }
+
+    /// Evaluate the predicates of the provided transaction and report whether
+    /// they allow spending of the inputs.
+    ///
+    /// The `test_witnesses` replace the witnesses of the transaction before
+    /// the evaluation. They are allowed only if the `test_witnesses_enabled` is set
+    /// in the configuration of the node.
+    async fn predicates_valid(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        test_witnesses: Option<Vec<TestWitness>>,
+    ) -> async_graphql::Result<PredicateValidation> {
+        let mut tx = decode_transaction(ctx, &tx)?;
+        let config = ctx.data_unchecked::<Config>();
+        test_witnesses::inject(&mut tx, test_witnesses, config.test_witnesses_enabled)?;
+
+        Ok(PredicateValidation::new(
+            tx,
+            &config.transaction_parameters,
+            &config.gas_costs,
+        ))
+    }
+
+    /// Validate the provided transaction without inserting it into the `TxPool`.
+    ///
+    /// Checks the structure, the witnesses, the gas price and the sufficiency of
+    /// the fee, and, if the UTXO validation is enabled, the signatures and
+    /// the predicates. The `gasLimit` failure is also reported if the maximum gas of
+    /// the transaction exceeds the block gas limit, because such a transaction is
+    /// never included into a block. Returns all the failures found, or an empty
+    /// list if the transaction is valid.
+    async fn validate_transaction(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Vec<ValidationFailure>> {
+        let tx = decode_transaction(ctx, &tx)?;
+        let query: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let height = query.latest_block_height()?;
+
+        Ok(validate_transaction(&tx, height, config))
+    }
+
+    /// Decode the provided transaction and return it with the computed id.
+    /// Neither the `TxPool` nor the database is accessed.
+    async fn decode_transaction(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Transaction> {
+        let tx = decode_transaction(ctx, &tx)?;
+        let config = ctx.data_unchecked::<Config>();
+
+        Ok(Transaction::from_tx(
+            tx.id(&config.transaction_parameters.chain_id),
+            tx,
+        ))
+    }
+
+    #[cfg(feature = "test-helpers")]
+    /// Returns all possible receipts for test purposes.
+    async fn all_receipts(&self) -> Vec<receipt::Receipt> {
+        receipt::all_receipts()
             .into_iter()
-            .try_collect()?;
+            .map(Into::into)
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct TxMutation;
+
+#[Object]
+impl TxMutation {
+    /// Execute a dry-run of the transaction using a fork of current state, no changes are committed.
+    ///
+    /// The receipts cover the whole execution of the transaction: the receipts of
+    /// the script, followed by the `ScriptResult` receipt emitted once the script
+    /// ends, with its result and the gas used. The finalization of the transaction,
+    /// which computes the change outputs and refunds the unused gas, emits no
+    /// receipts, so nothing is missing after the `ScriptResult`.
+    ///
+    /// The `gas_limit` overrides the gas limit of the transaction for the dry-run only.
+    /// The overridden limit is also used to compute the maximum fee, so the fee inputs
+    /// must cover it unless the gas price is zero or `utxo_validation` is disabled.
+    /// It can't exceed the `max_gas_per_tx` of the chain.
+    ///
+    /// The `block_time` is the time of the simulated block seen by the VM, in the TAI64
+    /// format. The current time is used if it is not specified. It can't be earlier
+    /// than the time of the genesis block.
+    ///
+    /// Fails with the `DRY_RUN_TIMEOUT` error code if the node limits the time of
+    /// the dry-run and the execution exceeds it.
+    ///
+    /// Fails with the `NODE_BUSY` error code if the node already executes
+    /// the maximum number of the dry-runs at once.
+    ///
+    /// The request is traced in the `dry_run` span with the `tx_id`, and
+    /// the `decode_time_us` and `vm_time_us` in microseconds.
+    ///
+    /// The `test_witnesses` replace the witnesses of the transaction before
+    /// the dry-run, so the predicates verifying the signed messages can be tested
+    /// without real signatures. They are allowed only if the `test_witnesses_enabled`
+    /// is set in the configuration of the node.
+    ///
+    /// Fails with the `ALREADY_COMMITTED` error code if the transaction is already
+    /// included in a block, and the `block_height` extension holds the height of
+    /// the block. Such transactions should be queried instead.
+    async fn dry_run(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        // This allows for non-existent inputs to be used without signature validation
+        // for read-only calls.
+        utxo_validation: Option<bool>,
+        gas_limit: Option<U64>,
+        block_time: Option<Tai64Timestamp>,
+        test_witnesses: Option<Vec<TestWitness>>,
+    ) -> async_graphql::Result<Vec<receipt::Receipt>> {
+        let config = ctx.data_unchecked::<Config>();
+        let span = tracing::info_span!(
+            "dry_run",
+            tx_id = field::Empty,
+            decode_time_us = field::Empty,
+            vm_time_us = field::Empty,
+        );
+
+        let block_time = block_time.map(|time| time.0);
+        if let Some(block_time) = block_time {
+            let query: &Database = ctx.data_unchecked();
+            if let Some(genesis) = query
+                .compressed_blocks(None, IterDirection::Forward)
+                .next()
+                .transpose()?
+            {
+                let genesis_time = genesis.header().time();
+                if block_time < genesis_time {
+                    return Err(anyhow!(
+                        "The block time {} is before the genesis block time {}",
+                        block_time.0,
+                        genesis_time.0
+                    )
+                    .into())
+                }
+            }
+        }
+
+        let decode_start = Instant::now();
+        let mut tx = decode_transaction(ctx, &tx)?;
+        if let Some(gas_limit) = gas_limit {
+            match &mut tx {
+                FuelTx::Script(script) => *script.gas_limit_mut() = gas_limit.into(),
+                FuelTx::Create(create) => *create.gas_limit_mut() = gas_limit.into(),
+                FuelTx::Mint(_) => {}
+            }
+        }
+        test_witnesses::inject(&mut tx, test_witnesses, config.test_witnesses_enabled)?;
+        tx.precompute(&config.transaction_parameters.chain_id)?;
+        let tx_id = tx.id(&config.transaction_parameters.chain_id);
+        span.record("tx_id", field::display(tx_id));
+        ensure_not_committed(ctx.data_unchecked(), &tx_id)?;
+        span.record("decode_time_us", decode_start.elapsed().as_micros() as u64);
+
+        let vm_start = Instant::now();
+        let receipts = limited_dry_run(ctx, |block_producer| async move {
+            block_producer
+                .dry_run_tx(tx, None, block_time, utxo_validation)
+                .await
+        })
+        .instrument(span.clone())
+        .await?;
+        span.record("vm_time_us", vm_start.elapsed().as_micros() as u64);
+        Ok(receipts.iter().map(Into::into).collect())
+    }
+
+    /// Execute a dry-run of the transaction like `dry_run`, and decode the reason
+    /// if the transaction reverted or panicked. Also reports whether the UTXO
+    /// validation was applied, since it falls back to the node configuration if unset,
+    /// and the gas used by the transaction together with the gas of each contract call.
+    ///
+    /// If the `owner` is set, also reports the net change of each of its assets,
+    /// computed the same as the `dry_run_summary`.
+    ///
+    /// If `find_min_gas` is set, also searches the minimum gas limit of the script
+    /// with which the dry-run succeeds, by repeating the dry-run with other gas
+    /// limits. The search makes at most `MAX_GAS_SEARCH_ITERATIONS` dry-runs.
+    async fn dry_run_detailed(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+        owner: Option<Address>,
+        find_min_gas: Option<bool>,
+    ) -> async_graphql::Result<DryRunDetails> {
+        let config = ctx.data_unchecked::<Config>();
+
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let gas_limit = match &tx {
+            FuelTx::Script(script) => *script.gas_limit(),
+            _ => 0,
+        };
+
+        // The search of the minimum gas limit runs under the same limits,
+        // since it is a part of the same dry-run.
+        let params = config.transaction_parameters;
+        let executed_tx = tx.clone();
+        let (receipts, min_gas_limit) = limited_dry_run(ctx, |block_producer| async move {
+            let receipts = block_producer
+                .dry_run_tx(executed_tx.clone(), None, None, utxo_validation)
+                .await?;
+            let min_gas_limit = match &executed_tx {
+                FuelTx::Script(script) if find_min_gas == Some(true) => {
+                    find_min_gas_limit(
+                        &block_producer,
+                        script,
+                        &receipts,
+                        utxo_validation,
+                        &params,
+                    )
+                    .await?
+                }
+                _ => None,
+            };
+            anyhow::Ok((receipts, min_gas_limit))
+        })
+        .await?;
+        let balance_changes = owner
+            .map(|owner| {
+                TransactionSummary::new(
+                    &tx,
+                    &receipts,
+                    &owner.into(),
+                    &config.transaction_parameters,
+                )
+            })
+            .transpose()?
+            .map(|summary| summary.balance_changes());
+
+        let mut details = DryRunDetails::new(
+            receipts,
+            utxo_validation.unwrap_or(config.utxo_validation),
+            gas_limit,
+        );
+        details.balance_changes = balance_changes;
+        details.min_gas_limit = min_gas_limit.map(Into::into);
+        Ok(details)
+    }
+
+    /// Execute a dry-run of the transaction like `dry_run`, and return the logs
+    /// in the emission order together with the contract and the call depth
+    /// that emitted them.
+    async fn dry_run_events(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<DecodedEvent>> {
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let receipts = dry_run_receipts(ctx, tx, utxo_validation).await?;
+        Ok(DecodedEvent::from_receipts(&receipts))
+    }
+
+    /// Execute a dry-run of the transaction and return the contract storage slots it
+    /// touched, both modified and newly created ones, with their values before and after.
+    async fn dry_run_storage_delta(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<StorageSlotDelta>> {
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let deltas = limited_dry_run(ctx, |block_producer| async move {
+            block_producer
+                .dry_run_storage_delta(tx, None, utxo_validation)
+                .await
+        })
+        .await?;
+        Ok(deltas.into_iter().map(Into::into).collect())
+    }
+
+    /// Execute a dry-run of the transaction where any call to the `fail_contract`
+    /// reverts, to exercise the handling of failed external calls.
+    ///
+    /// The code of the contract is replaced only in the state of this dry-run,
+    /// so it doesn't affect the execution of other dry-runs or the real blocks.
+    async fn dry_run_with_forced_failure(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        #[graphql(desc = "The contract whose calls revert")] fail_contract: ContractId,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<receipt::Receipt>> {
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let receipts = limited_dry_run(ctx, |block_producer| async move {
+            block_producer
+                .dry_run_with_forced_failure(
+                    tx,
+                    fail_contract.into(),
+                    None,
+                    utxo_validation,
+                )
+                .await
+        })
+        .await?;
+        Ok(receipts.iter().map(Into::into).collect())
+    }
+
+    /// Execute a dry-run of the batch of transactions as if they were included
+    /// into the same block, one after another in exactly the submitted order.
+    ///
+    /// The contract storage and the UTXO state carry forward between the transactions:
+    /// each transaction sees the changes made by the previous ones, so a transaction
+    /// may spend the outputs of an earlier one.
+    ///
+    /// If `sort_by_dependencies` is set, the batch is reordered before the execution
+    /// so that a transaction spending the outputs of another one from the batch runs
+    /// after it. The independent transactions keep the submitted order, and the cyclic
+    /// dependencies are rejected.
+    ///
+    /// The receipts are always returned in the submitted order.
+    ///
+    /// Fails with the `NODE_BUSY` error code if the node already executes
+    /// the maximum number of the dry-runs at once.
+    async fn dry_run_many(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+        #[graphql(desc = "Execute the transactions in the order of their dependencies")]
+        sort_by_dependencies: Option<bool>,
+    ) -> async_graphql::Result<Vec<Vec<receipt::Receipt>>> {
+        let config = ctx.data_unchecked::<Config>();
+        let chain_id = &config.transaction_parameters.chain_id;
+
+        let txs = txs
+            .iter()
+            .map(|tx| decode_dry_run_transaction(ctx, tx))
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+
+        let order = if sort_by_dependencies.unwrap_or(false) {
+            dependency_order::sort_by_dependencies(&txs, chain_id)?
+        } else {
+            (0..txs.len()).collect()
+        };
+        let mut txs: Vec<_> = txs.into_iter().map(Some).collect();
+        let ordered = order
+            .iter()
+            .map(|index| txs[*index].take().expect("Each index is used once"))
+            .collect();
+
+        let receipts = limited_dry_run(ctx, |block_producer| async move {
+            block_producer
+                .dry_run_many(ordered, None, utxo_validation)
+                .await
+        })
+        .await?;
+
+        let mut results: Vec<_> = order.iter().map(|_| vec![]).collect();
+        for (index, receipts) in order.into_iter().zip(receipts) {
+            results[index] = receipts.iter().map(Into::into).collect();
+        }
+        Ok(results)
+    }
+
+    /// Execute a dry-run of each transaction independently, against the same state
+    /// and in the same block context. Unlike `dry_run_many`, the transactions don't
+    /// see the changes made by each other, so the result of each one is the same
+    /// as of a separate `dry_run`. Each transaction runs on its own fork of the state.
+    ///
+    /// The receipts are returned in the submitted order.
+    ///
+    /// Fails with the `NODE_BUSY` error code if the node already executes
+    /// the maximum number of the dry-runs at once.
+    async fn dry_run_parallel(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<Vec<receipt::Receipt>>> {
+        let txs = txs
+            .iter()
+            .map(|tx| decode_dry_run_transaction(ctx, tx))
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+
+        let receipts = limited_dry_run(ctx, |block_producer| async move {
+            block_producer
+                .dry_run_parallel(txs, None, utxo_validation)
+                .await
+        })
+        .await?;
+        Ok(receipts
+            .iter()
+            .map(|receipts| receipts.iter().map(Into::into).collect())
+            .collect())
+    }
+
+    /// Simulate the block made of the transactions without committing it. The block
+    /// is executed the same way as by the block producer, on top of the current state.
+    ///
+    /// The transactions are taken in the submitted order while they fit into the gas
+    /// limit of the block. The transactions that don't fit are reported as excluded,
+    /// and the invalid or repeated ones as skipped.
+    ///
+    /// The block is always simulated at the next block height. The `height` is only
+    /// checked, and the simulation fails if it isn't the next block height.
+    async fn simulate_block(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        #[graphql(desc = "The height of the block, must be the next block height")]
+        height: Option<U32>,
+    ) -> async_graphql::Result<BlockSimulation> {
+        let config = ctx.data_unchecked::<Config>();
+
+        let txs = txs
+            .iter()
+            .map(|tx| decode_dry_run_transaction(ctx, tx))
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+
+        let block_gas_limit = config.block_gas_limit;
+        let simulation = limited_dry_run(ctx, |block_producer| async move {
+            block_producer
+                .simulate_block(txs, height.map(Into::into), block_gas_limit)
+                .await
+        })
+        .await?;
+        Ok(simulation.into())
+    }
+
+    /// Execute a dry-run of the transaction and, if it ended with an arithmetic overflow
+    /// panic, return the overflowing instruction. Returns `null` for any other outcome.
+    async fn dry_run_arithmetic_check(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Option<ArithmeticPanic>> {
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let receipts = dry_run_receipts(ctx, tx, None).await?;
+        Ok(ArithmeticPanic::from_receipts(&receipts))
+    }
+
+    /// Execute a dry-run of the transaction and summarize its effect from the point
+    /// of view of the `owner`: the net amounts of assets sent and received, the fee,
+    /// and the called contracts.
+    async fn dry_run_summary(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        owner: Address,
+    ) -> async_graphql::Result<TransactionSummary> {
+        let config = ctx.data_unchecked::<Config>();
+
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let receipts = dry_run_receipts(ctx, tx.clone(), None).await?;
+        let summary = TransactionSummary::new(
+            &tx,
+            &receipts,
+            &owner.into(),
+            &config.transaction_parameters,
+        )?;
+        Ok(summary)
+    }
+
+    /// Execute a dry-run of the transaction and return the tree of its calls,
+    /// starting from the script. Each call frame holds the transfers and the logs
+    /// it made itself, and the nested calls in the execution order.
+    async fn dry_run_execution_graph(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // The same as the `utxo_validation` of the `dry_run`.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<ExecutionGraph> {
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
+        let receipts = dry_run_receipts(ctx, tx, utxo_validation).await?;
+        Ok(ExecutionGraph::from_receipts(&receipts))
+    }
+
+    #[cfg(not(feature = "debug"))]
+    /// Execute a dry-run of the script transaction and record each instruction
+    /// executed by the VM. Requires the `debug` feature.
+    async fn dry_run_trace(
+        &self,
+        _ctx: &Context<'_>,
+        _tx: HexString,
+        _snapshot_at: Option<Vec<U32>>,
+    ) -> async_graphql::Result<ExecutionTrace> {
+        Err(async_graphql::Error::new(
+            "Feature 'debug' is not compiled in",
+        ))
+    }
+
+    #[cfg(feature = "debug")]
+    /// Execute a dry-run of the script transaction and record each instruction
+    /// executed by the VM. Requires the `debug` feature.
+    ///
+    /// The registers are recorded before the instructions with the `snapshot_at`
+    /// indexes, counted from the start of the running code. At most
+    /// `MAX_TRACE_STEPS` instructions are recorded. Inputs and signatures are not
+    /// validated, the same as for `dry_run` with the disabled `utxo_validation`.
+    /// Available only if the `dry_run_trace_enabled` is set in the configuration
+    /// of the node.
+    async fn dry_run_trace(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        snapshot_at: Option<Vec<U32>>,
+    ) -> async_graphql::Result<ExecutionTrace> {
+        let config = ctx.data_unchecked::<Config>();
+        if !config.dry_run_trace_enabled {
+            return Err(anyhow!("Tracing must be enabled to use this endpoint").into())
+        }
+
+        let database = ctx.data_unchecked::<crate::database::Database>();
+        let tx = decode_transaction(ctx, &tx)?;
+        let storage = database.transaction();
+        let params = config.transaction_parameters;
+        let gas_costs = config.gas_costs.clone();
+        let snapshot_at: Vec<u32> = snapshot_at
+            .unwrap_or_default()
+            .into_iter()
+            .map(|index| index.0)
+            .collect();
+
+        let trace = limited_dry_run(ctx, |_| async move {
+            tokio::task::spawn_blocking(move || {
+                dry_run_trace::trace(tx, storage, params, gas_costs, &snapshot_at)
+            })
+            .await?
+        })
+        .await?;
+        Ok(trace)
+    }
+
+    #[cfg(not(feature = "debug"))]
+    /// Execute a dry-run of the script transaction and report the code of
+    /// the contracts executed by the VM. Requires the `debug` feature.
+    async fn dry_run_coverage(
+        &self,
+        _ctx: &Context<'_>,
+        _tx: HexString,
+    ) -> async_graphql::Result<ExecutionCoverage> {
+        Err(async_graphql::Error::new(
+            "Feature 'debug' is not compiled in",
+        ))
+    }
+
+    #[cfg(feature = "debug")]
+    /// Execute a dry-run of the script transaction and report the code of
+    /// the contracts executed by the VM. Requires the `debug` feature.
+    ///
+    /// For each called contract, returns the ranges of the executed instructions
+    /// in bytes from the start of its code, for the test coverage tools. It uses
+    /// the tracing of `dry_run_trace`, so at most `MAX_TRACE_STEPS` instructions
+    /// are executed, and it is available only if the `dry_run_trace_enabled` is set
+    /// in the configuration of the node.
+    async fn dry_run_coverage(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<ExecutionCoverage> {
+        let config = ctx.data_unchecked::<Config>();
+        if !config.dry_run_trace_enabled {
+            return Err(anyhow!("Tracing must be enabled to use this endpoint").into())
+        }
+
+        let database = ctx.data_unchecked::<crate::database::Database>();
+        let tx = decode_transaction(ctx, &tx)?;
+        let storage = database.transaction();
+        let params = config.transaction_parameters;
+        let gas_costs = config.gas_costs.clone();
+
+        let coverage = limited_dry_run(ctx, |_| async move {
+            tokio::task::spawn_blocking(move || {
+                dry_run_trace::coverage(tx, storage, params, gas_costs)
+            })
+            .await?
+        })
+        .await?;
+        Ok(coverage)
+    }
+
+    /// Submits transaction to the `TxPool`.
+    ///
+    /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
+    /// The `insertion` tells if the transaction was newly inserted or already known,
+    /// and which transactions it evicted from the `TxPool`.
+    /// Fails with the `NODE_BUSY` error code if the node's submission queue is full,
+    /// and with the `POOL_FULL` error code if the `TxPool` is full. The `retry_after`
+    /// extension of the latter holds the number of seconds until the next block.
+    /// A repeated submission with the same `idempotency_key` returns the original result,
+    /// or fails with the `IDEMPOTENCY_CONFLICT` error code if the key was used for
+    /// another transaction, and with the `IDEMPOTENCY_IN_PROGRESS` error code if
+    /// the original submission hasn't finished yet. The keys are scoped to the address
+    /// of the client.
+    ///
+    /// If `preview` is `true`, the transaction is dry-run before the insertion, and
+    /// the receipts of the dry-run are returned in `preview_receipts`. It saves
+    /// the round trip of the separate `dry_run`. The state may change before
+    /// the inclusion, so the actual receipts may differ from the preview. The repeated
+    /// submission with the same `idempotency_key` returns no preview.
+    ///
+    /// The request is traced in the `submit` span with the `tx_id`, and
+    /// the `decode_time_us`, `vm_time_us` of the preview and `insertion_time_us`
+    /// in microseconds.
+    async fn submit(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // If set, the node remembers the result of the submission for a short time,
+        // and returns it for repeated submissions with the same key.
+        idempotency_key: Option<String>,
+        preview: Option<bool>,
+    ) -> async_graphql::Result<SubmissionResult> {
+        let config = ctx.data_unchecked::<Config>();
+        let idempotency_cache = ctx.data_unchecked::<IdempotencyCache>();
+        let span = tracing::info_span!(
+            "submit",
+            tx_id = field::Empty,
+            decode_time_us = field::Empty,
+            vm_time_us = field::Empty,
+            insertion_time_us = field::Empty,
+        );
+        let decode_start = Instant::now();
+        let tx = decode_transaction(ctx, &tx)?;
+        let id = tx.id(&config.transaction_parameters.chain_id);
+        span.record("tx_id", field::display(id));
+        span.record("decode_time_us", decode_start.elapsed().as_micros() as u64);
+
+        let reservation = match &idempotency_key {
+            Some(key) => {
+                let client = ctx.data_opt::<ClientAddr>().map(|ClientAddr(ip)| *ip);
+                match idempotency_cache.reserve(client, key, &id)? {
+                    Reservation::Known(result) => {
+                        return Ok(SubmissionResult::new(Transaction(tx, id), result?))
+                    }
+                    Reservation::Reserved(guard) => Some(guard),
+                }
+            }
+            None => None,
+        };
+
+        let preview_receipts = if preview.unwrap_or(false) {
+            let vm_start = Instant::now();
+            let mut preview_tx = tx.clone();
+            preview_tx.precompute(&config.transaction_parameters.chain_id)?;
+            let receipts = dry_run_receipts(ctx, preview_tx, None)
+                .instrument(span.clone())
+                .await?;
+            span.record("vm_time_us", vm_start.elapsed().as_micros() as u64);
+            Some(receipts.iter().map(Into::into).collect())
+        } else {
+            None
+        };
+
+        let _slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
+        let insertion_start = Instant::now();
+        let result = insert_into_txpool(ctx, &tx).instrument(span.clone()).await;
+        span.record(
+            "insertion_time_us",
+            insertion_start.elapsed().as_micros() as u64,
+        );
+        // The full `TxPool` is temporary, so the key is released and the retry
+        // with the same key is processed again
+        if let Some(guard) = reservation.filter(|_| !is_pool_full(&result)) {
+            guard.complete(result.clone());
+        }
+
+        Ok(SubmissionResult::new(Transaction(tx, id), result?)
+            .with_preview_receipts(preview_receipts))
+    }
+
+    /// Submits the transaction that the caller has already validated to the `TxPool`.
+    /// It is the fast path for the trusted infrastructure of the operator, for example
+    /// the sequencer frontend, that verified the transaction before forwarding it.
+    ///
+    /// Only the checks that the caller has already done are skipped:
+    /// - the recovery of the signers of the coins and messages from the witnesses;
+    /// - the execution of the predicates.
+    ///
+    /// The checks required for the consensus are always performed: the structure,
+    /// the limits of the consensus parameters, the maturity, the fee and
+    /// the `min_gas_price`, and the inputs against the state and the `TxPool`.
+    /// The block producer verifies the signatures and the predicates again during
+    /// the execution, so an invalid transaction is never included into a block.
+    ///
+    /// The transaction isn't gossiped to the peers. Only the `max_prevalidated_tx`
+    /// of the prevalidated transactions can be inside the `TxPool`, above it the call
+    /// fails with the `POOL_FULL` error code.
+    ///
+    /// Fails with the `UNAUTHORIZED` error code if the address of the caller isn't
+    /// in the `trusted_submitters` of the node, and with the same errors as `submit`
+    /// otherwise. The address is the peer address of the connection, so behind
+    /// a reverse proxy it is the address of the proxy.
+    async fn submit_prevalidated(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<SubmissionResult> {
+        let config = ctx.data_unchecked::<Config>();
+        let trusted = ctx
+            .data_opt::<ClientAddr>()
+            .map(|ClientAddr(client)| config.trusted_submitters.contains(client))
+            .unwrap_or(false);
+        if !trusted {
+            return Err(async_graphql::Error::new(
+                "The caller isn't allowed to submit the prevalidated transactions",
+            )
+            .extend_with(|_, e| e.set("code", UNAUTHORIZED)))
+        }
+
+        let tx = decode_transaction(ctx, &tx)?;
+        let id = tx.id(&config.transaction_parameters.chain_id);
+        let _slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
+        let result = ctx
+            .data_unchecked::<TxPool>()
+            .insert_prevalidated(vec![Arc::new(tx.clone())])
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("The `TxPool` didn't return the insertion result"))
+            .and_then(|result| result);
+        let insertion = match result {
+            Ok(insertion) => InsertionStatus::from_insertion(&insertion),
+            Err(err) if is_already_known(&err) => InsertionStatus::from_known_tx(&tx),
+            Err(err) => return Err(insertion_error(ctx, err)),
+        };
+
+        Ok(SubmissionResult::new(Transaction(tx, id), insertion))
+    }
+
+    /// Submits the batch of transactions to the `TxPool` in the given order,
+    /// so a transaction can spend the outputs of the previous ones.
+    ///
+    /// Returns the outcome of each transaction in the given order, categorized as
+    /// newly inserted, duplicate of an earlier transaction of the batch, already known
+    /// to the `TxPool`, or rejected with the reason. The duplicates and the transactions
+    /// that can't be decoded are not passed to the `TxPool`.
+    /// Fails with the `NODE_BUSY` error code if the node's submission queue is full.
+    async fn submit_many(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+    ) -> async_graphql::Result<Vec<BatchSubmissionOutcome>> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+
+        let mut seen = HashSet::new();
+        let decoded = txs
+            .iter()
+            .map(|tx| match decode_transaction(ctx, tx) {
+                Ok(tx) => {
+                    let id = tx.id(&config.transaction_parameters.chain_id);
+                    let duplicate = !seen.insert(id);
+                    Ok((tx, id, duplicate))
+                }
+                Err(err) => Err(err.message),
+            })
+            .collect_vec();
+
+        let unique = decoded
+            .iter()
+            .filter_map(|decoded| match decoded {
+                Ok((tx, _, false)) => Some(Arc::new(tx.clone())),
+                _ => None,
+            })
+            .collect_vec();
+        let _slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
+        let mut results = txpool.insert(unique).await.into_iter();
+
+        let outcomes = decoded
+            .into_iter()
+            .map(|decoded| {
+                let (tx, id, duplicate) = match decoded {
+                    Ok(decoded) => decoded,
+                    Err(reason) => return BatchSubmissionOutcome::rejected(None, reason),
+                };
+                if duplicate {
+                    return BatchSubmissionOutcome {
+                        id: Some(id.into()),
+                        category: BatchSubmissionCategory::DuplicateInBatch,
+                        reason: None,
+                        insertion: None,
+                    }
+                }
+                let (category, insertion) = match results.next() {
+                    Some(Ok(insertion)) => (
+                        BatchSubmissionCategory::NewlyInserted,
+                        InsertionStatus::from_insertion(&insertion),
+                    ),
+                    Some(Err(err)) if is_already_known(&err) => (
+                        BatchSubmissionCategory::AlreadyKnown,
+                        InsertionStatus::from_known_tx(&tx),
+                    ),
+                    Some(Err(err)) => {
+                        return BatchSubmissionOutcome::rejected(
+                            Some(id.into()),
+                            err.to_string(),
+                        )
+                    }
+                    None => {
+                        return BatchSubmissionOutcome::rejected(
+                            Some(id.into()),
+                            "The `TxPool` didn't return the insertion result".to_string(),
+                        )
+                    }
+                };
+                BatchSubmissionOutcome {
+                    id: Some(id.into()),
+                    category,
+                    reason: None,
+                    insertion: Some(insertion),
+                }
+            })
+            .collect();
+        Ok(outcomes)
+    }
+
+    /// Execute a dry-run of the transaction like `dry_run`, and submit it to
+    /// the `TxPool` like `submit`.
+    ///
+    /// If `require_success` is `true` and the dry-run reverted or panicked,
+    /// the transaction is not submitted, so the fee is not paid for the doomed
+    /// transaction. The receipts of the dry-run are returned in both cases.
+    /// The state may change between the dry-run and the inclusion of
+    /// the transaction, so the success of the dry-run doesn't guarantee
+    /// the success of the transaction.
+    async fn dry_run_then_submit(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        require_success: Option<bool>,
+    ) -> async_graphql::Result<DryRunThenSubmitResult> {
+        let config = ctx.data_unchecked::<Config>();
+        let tx = decode_dry_run_transaction(ctx, &tx)?;
         let id = tx.id(&config.transaction_parameters.chain_id);
 
-        let tx = Transaction(tx, id);
-        Ok(tx)
+        let receipts = dry_run_receipts(ctx, tx.clone(), None).await?;
+        let revert_reason = RevertReason::from_receipts(&receipts);
+        let receipts = receipts.iter().map(Into::into).collect();
+        if require_success.unwrap_or(false) && revert_reason.is_some() {
+            return Ok(DryRunThenSubmitResult {
+                receipts,
+                revert_reason,
+                submission: None,
+            })
+        }
+
+        let _slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
+        let insertion = insert_into_txpool(ctx, &tx).await?;
+
+        Ok(DryRunThenSubmitResult {
+            receipts,
+            revert_reason,
+            submission: Some(SubmissionResult::new(Transaction(tx, id), insertion)),
+        })
+    }
+
+    /// Re-inserts the `txs` into the `TxPool`, to restore the pending transactions
+    /// lost by the restart of the node. The transactions already included into
+    /// a block are skipped. The rest are inserted in the given order, so a transaction
+    /// can spend the outputs of the previous ones.
+    ///
+    /// Returns the outcome of each transaction in the given order. The transactions
+    /// are inserted the same way as by `submit`, so the reason of the rejection
+    /// because the `TxPool` is full tells when to retry. Available only if
+    /// the `resubmit_enabled` is set in the configuration of the node.
+    /// Fails with the `NODE_BUSY` error code if the node's submission queue is full.
+    async fn resubmit(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+    ) -> async_graphql::Result<Vec<ResubmissionOutcome>> {
+        let query: &Database = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+
+        if !config.resubmit_enabled {
+            return Err(anyhow!("Resubmission must be enabled to use this endpoint").into())
+        }
+
+        let txs = txs
+            .into_iter()
+            .map(|tx| {
+                let tx = decode_transaction(ctx, &tx)?;
+                let id = tx.id(&config.transaction_parameters.chain_id);
+                let status = query
+                    .status(&id)
+                    .into_api_result::<txpool::TransactionStatus, StorageError>()?;
+                let committed = matches!(
+                    status,
+                    Some(txpool::TransactionStatus::Success { .. })
+                        | Some(txpool::TransactionStatus::Failed { .. })
+                );
+                Ok((tx, id, committed))
+            })
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+
+        let _slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
+        let mut outcomes = Vec::with_capacity(txs.len());
+        for (tx, id, committed) in txs {
+            let (status, reason) = if committed {
+                (ResubmissionStatus::AlreadyCommitted, None)
+            } else {
+                // One at a time, so each insertion sees the outputs of the previous ones
+                match insert_into_txpool(ctx, &tx).await {
+                    Ok(insertion) if insertion.already_known => {
+                        (ResubmissionStatus::AlreadyPending, None)
+                    }
+                    Ok(_) => (ResubmissionStatus::Inserted, None),
+                    Err(err) => (ResubmissionStatus::Rejected, Some(err.message)),
+                }
+            };
+            outcomes.push(ResubmissionOutcome {
+                id: id.into(),
+                status,
+                reason,
+            });
+        }
+        Ok(outcomes)
+    }
+
+    /// Replaces the pending transactions that spend any of the coins or messages
+    /// of the `tx` with the `tx`. It allows bumping the gas price of a stuck transaction.
+    ///
+    /// Fails if no pending transaction conflicts with the `tx`, or if the gas price
+    /// of the `tx` isn't higher than the gas price of every conflicting transaction.
+    /// Returns the ids of the evicted transactions.
+    async fn replace_transaction(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let tx = decode_transaction(ctx, &tx)?;
+
+        let (inputs, gas_price) = match &tx {
+            FuelTx::Script(script) => (script.inputs(), *script.gas_price()),
+            FuelTx::Create(create) => (create.inputs(), *create.gas_price()),
+            FuelTx::Mint(_) => {
+                return Err(anyhow!("Mint transactions can't be submitted").into())
+            }
+        };
+        let conflicting = txpool.conflicting_transactions(inputs);
+        if conflicting.is_empty() {
+            return Err(anyhow!(
+                "The transaction doesn't spend inputs of any pending transaction"
+            )
+            .into())
+        }
+        if let Some(pending) = conflicting
+            .iter()
+            .find(|pending| pending.price() >= gas_price)
+        {
+            return Err(anyhow!(
+                "The gas price {gas_price} isn't higher than the gas price {} \
+                of the pending transaction {:#x}",
+                pending.price(),
+                pending.id()
+            )
+            .into())
+        }
+
+        let _slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
+        let insertion = txpool
+            .insert(vec![Arc::new(tx)])
+            .await
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                anyhow!("The `TxPool` didn't return the insertion result")
+            })??;
+
+        Ok(insertion
+            .removed
+            .iter()
+            .map(|removed| removed.id().into())
+            .collect())
     }
 }
 
@@ -291,16 +2385,63 @@ impl TxStatusSubscription {
     /// then the updates arrive. In such a case the stream will close without
     /// a status. If this occurs the stream can simply be restarted to return
     /// the latest status.
+    ///
+    /// To not miss the intermediate statuses, the stream can be restarted with
+    /// the `from_cursor` of the last status already received. The cursor is
+    /// `KIND#TIME`, where `KIND` is `SUBMITTED`, `SUCCESS`, `FAILURE` or
+    /// `SQUEEZED_OUT`, and `TIME` is the time of the status, like the `cursor`
+    /// of the `transaction_status_history`. The statuses recorded after it are
+    /// replayed first, and then the stream continues with the live updates.
+    /// If the history no longer has the status of the cursor, nothing is replayed
+    /// and the stream starts with the current status, like without the cursor.
     async fn status_change<'a>(
         &self,
         ctx: &Context<'a>,
         #[graphql(desc = "The ID of the transaction")] id: TransactionId,
-    ) -> impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a {
+        #[graphql(desc = "The cursor of the last status received before the restart")]
+        from_cursor: Option<String>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
+    > {
         let txpool = ctx.data_unchecked::<TxPool>();
         let db = ctx.data_unchecked::<Database>();
-        let rx = txpool.tx_update_subscribe(id.into()).await;
+        let rx = txpool.tx_update_subscribe(id.into()).await?;
 
-        transaction_status_change(
+        // The history is read after the subscription, so the transitions between
+        // them are both replayed and received live. The live `Submitted` status is
+        // skipped if it was recorded, and a recorded final status ends the stream.
+        let (replayed, latest) = match from_cursor {
+            Some(cursor) => {
+                let cursor = TxStatusCursor::decode_cursor(&cursor)
+                    .map_err(async_graphql::Error::new)?;
+                let history = get_tx_status_history(id.0, db, txpool)?;
+                match history
+                    .iter()
+                    .rposition(|record| TxStatusCursor::of(record) == cursor)
+                {
+                    Some(position) => {
+                        let latest = history.last().map(|record| record.status.clone());
+                        let replayed: Vec<async_graphql::Result<TransactionStatus>> =
+                            history
+                                .into_iter()
+                                .skip(position + 1)
+                                .map(|record| Ok(record.status.into()))
+                                .collect();
+                        (replayed, latest)
+                    }
+                    None => (vec![], None),
+                }
+            }
+            None => (vec![], None),
+        };
+        let replayed = futures::stream::iter(replayed);
+        let skip_submitted = match latest {
+            None => false,
+            Some(txpool::TransactionStatus::Submitted { .. }) => true,
+            Some(_) => return Ok(futures::future::Either::Left(replayed)),
+        };
+
+        let live = transaction_status_change(
             move |id| match db.tx_status(&id) {
                 Ok(status) => Ok(Some(status)),
                 Err(StorageError::NotFound(_, _)) => {
@@ -317,9 +2458,77 @@ impl TxStatusSubscription {
         )
         .await
         .map_err(async_graphql::Error::from)
+        .filter(move |status| {
+            !(skip_submitted && matches!(status, Ok(TransactionStatus::Submitted(_))))
+        });
+
+        Ok(futures::future::Either::Right(replayed.chain(live)))
+    }
+
+    /// Returns a stream of the transactions committed after the subscription
+    /// that reference the `owner` in their inputs or outputs, together with
+    /// their statuses.
+    ///
+    /// The node buffers a bounded number of committed blocks for each subscriber.
+    /// If the subscriber is too slow, the blocks committed while the buffer is full
+    /// are skipped, and the stream emits the `LAGGED` error with the number of
+    /// the skipped blocks before it continues. The same error is emitted if the node
+    /// itself lags behind the committed blocks and skips them.
+    async fn owner_transactions<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The address of the owner")] owner: Address,
+    ) -> impl Stream<Item = async_graphql::Result<Transaction>> + 'a {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let db = ctx.data_unchecked::<Database>();
+        owner_transactions::stream(db, block_importer.block_events(), owner.into())
+    }
+
+    /// Returns a stream of the transactions of the blocks from `start_height` to
+    /// `end_height` inclusive, block by block in the order of the heights, and in
+    /// the order of the execution within the block. The stream ends at `end_height`
+    /// or at the latest block at the moment of the subscription, whichever is lower.
+    ///
+    /// The blocks are read from the database only when the subscriber polls for
+    /// their transactions, so the node doesn't buffer the range for slow subscribers.
+    async fn transactions_in_range<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The height of the first block")] start_height: U32,
+        #[graphql(desc = "The height of the last block")] end_height: U32,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Transaction>> + 'a>
+    {
+        let db = ctx.data_unchecked::<Database>();
+        if start_height.0 > end_height.0 {
+            return Err(anyhow!(
+                "The start height {} is above the end height {}",
+                start_height.0,
+                end_height.0
+            )
+            .into())
+        }
+
+        let latest_height: u32 = db.latest_block_height()?.into();
+        let transactions =
+            (start_height.0..=end_height.0.min(latest_height)).flat_map(move |height| {
+                match block_transactions(db, height.into()) {
+                    Ok(transactions) => transactions,
+                    Err(err) => vec![Err(err.into())],
+                }
+            });
+        Ok(futures::stream::iter(transactions))
     }
 
     /// Submits transaction to the `TxPool` and await either confirmation or failure.
+    /// The terminal status is delivered even if the transaction is committed before
+    /// the client starts listening.
+    /// Fails with the `NODE_BUSY` error code if the node's submission queue is full,
+    /// and with the `POOL_FULL` error code if the `TxPool` is full.
+    ///
+    /// The terminal status is final: the block importer only appends the next block
+    /// and never reverts the committed ones, so the status isn't reversed later.
+    /// To follow the transaction after the submission, including the intermediate
+    /// statuses, subscribe to `statusChange` with its id.
     async fn submit_and_await<'a>(
         &self,
         ctx: &Context<'a>,
@@ -329,17 +2538,30 @@ impl TxStatusSubscription {
     > {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
-        let tx = FuelTx::from_bytes(&tx.0)?;
+        let tx = decode_transaction(ctx, &tx)?;
         let tx_id = tx.id(&config.transaction_parameters.chain_id);
-        let subscription = txpool.tx_update_subscribe(tx_id).await;
+        let subscription = txpool.tx_update_subscribe(tx_id).await?;
 
+        let slot = ctx.data_unchecked::<SubmissionQueue>().try_reserve()?;
         let _: Vec<_> = txpool
             .insert(vec![Arc::new(tx)])
             .await
             .into_iter()
-            .try_collect()?;
+            .try_collect()
+            .map_err(|err| insertion_error(ctx, err))?;
+        drop(slot);
 
-        Ok(subscription
+        // The transaction may be committed before the client polls the stream,
+        // so the status in the database is checked first, like in `status_change`.
+        let db = ctx.data_unchecked::<Database>();
+        let committed = match db.tx_status(&tx_id) {
+            Ok(status) => Some(TxStatusMessage::Status(status)),
+            Err(StorageError::NotFound(_, _)) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(futures::stream::iter(committed)
+            .chain(subscription)
             .skip_while(|event| {
                 matches!(
                     event,
@@ -351,7 +2573,199 @@ impl TxStatusSubscription {
                 TxStatusMessage::FailedStatus => {
                     Err(anyhow::anyhow!("Failed to get transaction status").into())
                 }
+                TxStatusMessage::Evicted => {
+                    Err(anyhow::anyhow!(SUBSCRIPTION_EVICTED).into())
+                }
             })
             .take(1))
     }
+
+    #[cfg(not(feature = "debug"))]
+    /// Execute a dry-run of the script transaction and stream the receipts as the VM
+    /// produces them. Requires the `debug` feature.
+    async fn dry_run_streaming(
+        &self,
+        _ctx: &Context<'_>,
+        _tx: HexString,
+    ) -> async_graphql::Result<
+        futures::stream::Empty<async_graphql::Result<receipt::Receipt>>,
+    > {
+        Err(async_graphql::Error::new(
+            "Feature 'debug' is not compiled in",
+        ))
+    }
+
+    #[cfg(feature = "debug")]
+    /// Execute a dry-run of the script transaction and stream the receipts as the VM
+    /// produces them. Requires the `debug` feature.
+    ///
+    /// Inputs and signatures are not validated, the same as for `dry_run` with
+    /// the disabled `utxo_validation`. The stream ends when the execution finishes
+    /// or fails, and the execution stops if the subscription is dropped.
+    async fn dry_run_streaming<'a>(
+        &self,
+        ctx: &Context<'a>,
+        tx: HexString,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<receipt::Receipt>> + 'a,
+    > {
+        let config = ctx.data_unchecked::<Config>();
+        let database = ctx.data_unchecked::<crate::database::Database>();
+        let tx = decode_transaction(ctx, &tx)?;
+        // The permit is held by the VM until the execution stops.
+        let permit = ctx.data_unchecked::<DryRunLimiter>().try_acquire()?;
+
+        let receipts = dry_run_stream::spawn(
+            tx,
+            database.transaction(),
+            config.transaction_parameters,
+            config.gas_costs.clone(),
+            permit,
+        )?;
+        Ok(tokio_stream::wrappers::ReceiverStream::new(receipts))
+    }
+}
+
+/// Returns the transactions of the block at the `height` in the order of the execution.
+fn block_transactions(
+    db: &Database,
+    height: fuel_types::BlockHeight,
+) -> StorageResult<Vec<async_graphql::Result<Transaction>>> {
+    let block = db.block(&db.block_id(&height)?)?;
+    let transactions = block
+        .transactions()
+        .iter()
+        .map(|id| {
+            db.transaction(id)
+                .map(|tx| Transaction::from_tx(*id, tx))
+                .map_err(Into::into)
+        })
+        .collect();
+    Ok(transactions)
+}
+
+/// Decodes the transaction passed to the endpoint. The size is checked before
+/// the decoding, so the oversized payloads are rejected without parsing them.
+fn decode_transaction(
+    ctx: &Context<'_>,
+    tx: &HexString,
+) -> async_graphql::Result<FuelTx> {
+    let max_tx_size = ctx.data_unchecked::<Config>().max_tx_size;
+    let size = tx.0.len();
+    if size > max_tx_size {
+        return Err(async_graphql::Error::new(format!(
+            "Transaction too large: {size} bytes exceeds the limit of {max_tx_size} bytes"
+        ))
+        .extend_with(|_, e| e.set("code", TRANSACTION_TOO_LARGE)))
+    }
+    Ok(FuelTx::from_bytes(&tx.0)?)
+}
+
+/// Decodes the transaction passed to the dry-run endpoint and precomputes it
+/// for the execution.
+fn decode_dry_run_transaction(
+    ctx: &Context<'_>,
+    tx: &HexString,
+) -> async_graphql::Result<FuelTx> {
+    let chain_id = &ctx
+        .data_unchecked::<Config>()
+        .transaction_parameters
+        .chain_id;
+    let mut tx = decode_transaction(ctx, tx)?;
+    tx.precompute(chain_id)?;
+    Ok(tx)
+}
+
+/// Parses the name of the panic `reason`, e.g. `ArithmeticOverflow`.
+fn parse_panic_reason(reason: &str) -> async_graphql::Result<PanicReason> {
+    use strum::IntoEnumIterator;
+    PanicReason::iter()
+        .find(|panic| format!("{panic:?}") == reason)
+        .ok_or_else(|| anyhow!("Unknown panic reason `{reason}`").into())
+}
+
+/// Returns `true` if the script of the dry-run finished without a revert or a panic.
+fn script_succeeded(receipts: &[Receipt]) -> bool {
+    receipts.iter().any(|receipt| {
+        matches!(
+            receipt,
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Success,
+                ..
+            }
+        )
+    })
+}
+
+/// Searches the minimum gas limit of the `script` with which its dry-run succeeds.
+/// The `receipts` are the result of the dry-run with the gas limit of the `script`.
+///
+/// If the script succeeded, the search starts from the gas it used. Otherwise, the
+/// gas limit is doubled until the script succeeds, up to the `max_gas_per_tx`.
+/// Then the range between the failing and the succeeding limits is halved. At most
+/// `MAX_GAS_SEARCH_ITERATIONS` dry-runs are made, and the lowest succeeding limit
+/// found so far is returned if the search didn't converge.
+async fn find_min_gas_limit(
+    block_producer: &BlockProducer,
+    script: &Script,
+    receipts: &[Receipt],
+    utxo_validation: Option<bool>,
+    params: &ConsensusParameters,
+) -> anyhow::Result<Option<u64>> {
+    let succeeds_with = |gas_limit: u64| async move {
+        let mut tx = FuelTx::Script(script.clone());
+        if let FuelTx::Script(script) = &mut tx {
+            *script.gas_limit_mut() = gas_limit;
+        }
+        tx.precompute(&params.chain_id)?;
+        let receipts = block_producer
+            .dry_run_tx(tx, None, None, utxo_validation)
+            .await?;
+        anyhow::Ok(script_succeeded(&receipts))
+    };
+
+    // The largest limit known to fail and the smallest limit known to succeed.
+    let mut failing = 0;
+    let mut succeeding = None;
+    // The script usually needs exactly the gas it used, so the first probes
+    // check the gas used and one less, popped from the end.
+    let mut hints = vec![];
+    if script_succeeded(receipts) {
+        succeeding = Some(*script.gas_limit());
+        if let Some(gas_used) = receipts.iter().find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        }) {
+            hints = vec![gas_used.saturating_sub(1), gas_used];
+        }
+    } else {
+        failing = *script.gas_limit();
+    }
+
+    for _ in 0..MAX_GAS_SEARCH_ITERATIONS {
+        let candidate = match succeeding {
+            Some(succeeding) if succeeding <= failing.saturating_add(1) => break,
+            Some(succeeding) => match hints.pop() {
+                Some(hint) if hint > failing && hint < succeeding => hint,
+                _ => failing + (succeeding - failing) / 2,
+            },
+            None if failing >= params.max_gas_per_tx => break,
+            None => failing.saturating_mul(2).clamp(1, params.max_gas_per_tx),
+        };
+
+        if succeeds_with(candidate).await? {
+            succeeding = Some(candidate);
+        } else {
+            failing = candidate;
+        }
+    }
+
+    Ok(succeeding)
+}
+
+fn is_already_known(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<txpool::Error>(),
+        Some(txpool::Error::NotInsertedTxKnown)
+    )
 }
@@ -0,0 +1,69 @@
+use anyhow::anyhow;
+use fuel_core_types::{
+    fuel_tx::{
+        field::Inputs,
+        Transaction as FuelTx,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+};
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+/// Returns the indexes of the `txs` in the order in which they can be executed,
+/// so that a transaction spending the outputs of another one from the batch
+/// is executed after it. The independent transactions keep their relative order.
+///
+/// Returns an error if the transactions depend on each other in a cycle.
+pub fn sort_by_dependencies(
+    txs: &[FuelTx],
+    chain_id: &ChainId,
+) -> anyhow::Result<Vec<usize>> {
+    let indexes: HashMap<TxId, usize> = txs
+        .iter()
+        .enumerate()
+        .map(|(index, tx)| (tx.id(chain_id), index))
+        .collect();
+
+    let mut dependents = vec![vec![]; txs.len()];
+    let mut dependencies = vec![0usize; txs.len()];
+    for (index, tx) in txs.iter().enumerate() {
+        let inputs = match tx {
+            FuelTx::Script(script) => script.inputs(),
+            FuelTx::Create(create) => create.inputs(),
+            FuelTx::Mint(_) => continue,
+        };
+        for utxo_id in inputs.iter().filter_map(|input| input.utxo_id()) {
+            if let Some(parent) = indexes.get(utxo_id.tx_id()) {
+                dependents[*parent].push(index);
+                dependencies[index] += 1;
+            }
+        }
+    }
+
+    // The ready transactions are processed in the submitted order to keep
+    // the sorting stable.
+    let mut ready: VecDeque<_> = (0..txs.len())
+        .filter(|index| dependencies[*index] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(txs.len());
+    while let Some(index) = ready.pop_front() {
+        order.push(index);
+        for dependent in &dependents[index] {
+            dependencies[*dependent] -= 1;
+            if dependencies[*dependent] == 0 {
+                ready.push_back(*dependent);
+            }
+        }
+    }
+
+    if order.len() != txs.len() {
+        return Err(anyhow!(
+            "The transactions have cyclic dependencies and can't be ordered"
+        ))
+    }
+    Ok(order)
+}
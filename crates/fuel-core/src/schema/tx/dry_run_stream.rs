@@ -0,0 +1,121 @@
+//! The dry-run that streams receipts while the VM produces them.
+//!
+//! The VM runs in the single-stepping mode, and after each step the new receipts
+//! are sent to the subscriber. It requires the `debug` feature.
+
+use super::receipt::Receipt;
+use crate::database::{
+    transaction::DatabaseTransaction,
+    vm_database::VmDatabase,
+};
+use anyhow::anyhow;
+use fuel_core_storage::not_found;
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        Script,
+        Transaction as FuelTx,
+    },
+    fuel_types::Address,
+    fuel_vm::{
+        checked_transaction::{
+            Checked,
+            IntoChecked,
+        },
+        GasCosts,
+        Interpreter,
+        ProgramState,
+    },
+};
+use tokio::sync::{
+    mpsc,
+    OwnedSemaphorePermit,
+};
+
+/// The number of receipts buffered for the subscriber before the VM is paused.
+const RECEIPTS_BUFFER: usize = 64;
+
+/// Starts the dry-run of the script `tx` on top of the `storage` and returns
+/// the receiver of its receipts. The changes to the `storage` are never committed.
+///
+/// Inputs and signatures are not validated, the same as for `dry_run` with
+/// the disabled `utxo_validation`. The VM stops when the receiver is dropped.
+/// The `permit` of the `DryRunLimiter` is held until the VM stops.
+pub fn spawn(
+    tx: FuelTx,
+    storage: DatabaseTransaction,
+    params: ConsensusParameters,
+    gas_costs: GasCosts,
+    permit: OwnedSemaphorePermit,
+) -> anyhow::Result<mpsc::Receiver<async_graphql::Result<Receipt>>> {
+    let script = match tx {
+        FuelTx::Script(script) => script,
+        _ => {
+            return Err(anyhow!(
+                "Only `Script` transactions can be dry-run with streaming"
+            ))
+        }
+    };
+
+    let (checked_tx, vm_database) = prepare(script, &storage, &params)?;
+
+    let (sender, receiver) = mpsc::channel(RECEIPTS_BUFFER);
+    tokio::task::spawn_blocking(move || {
+        // The transaction is dropped without commit when the VM stops,
+        // and the permit of the dry-run is released.
+        let _storage = storage;
+        let _permit = permit;
+        let mut vm = Interpreter::with_storage(vm_database, params, gas_costs);
+        vm.set_single_stepping(true);
+
+        let mut state = vm.transact(checked_tx).map(|state| *state.state());
+        let mut sent = 0;
+        loop {
+            let receipts = vm.receipts();
+            for receipt in &receipts[sent..] {
+                if sender.blocking_send(Ok(Receipt(receipt.clone()))).is_err() {
+                    // The subscriber is gone, so there is no reason to continue.
+                    return
+                }
+            }
+            sent = receipts.len();
+
+            match state {
+                Ok(ProgramState::RunProgram(_))
+                | Ok(ProgramState::VerifyPredicate(_)) => {
+                    state = vm.resume();
+                }
+                Ok(_) => return,
+                Err(err) => {
+                    let _ = sender.blocking_send(Err(async_graphql::Error::new(
+                        format!("VM error: {err:?}"),
+                    )));
+                    return
+                }
+            }
+        }
+    });
+
+    Ok(receiver)
+}
+
+/// Checks the `script` and creates the view of the `storage` for the VM.
+pub fn prepare(
+    script: Script,
+    storage: &DatabaseTransaction,
+    params: &ConsensusParameters,
+) -> anyhow::Result<(Checked<Script>, VmDatabase)> {
+    let block = storage
+        .get_current_block()?
+        .ok_or(not_found!("Block for VMDatabase"))?
+        .into_owned();
+    let checked_tx = script.into_checked_basic(*block.header().height(), params)?;
+    let vm_database = VmDatabase::new(
+        storage.as_ref().clone(),
+        &block.header().consensus,
+        // TODO: Use a real coinbase address
+        Address::zeroed(),
+    );
+
+    Ok((checked_tx, vm_database))
+}
@@ -0,0 +1,285 @@
+//! The dry-run that records each instruction executed by the VM, and the coverage
+//! of the code of the contracts built on top of it.
+//!
+//! The VM runs in the single-stepping mode, and it pauses before each instruction.
+//! The tracing requires the `debug` feature.
+
+use super::{
+    receipt::Receipt,
+    types::ProgramState,
+};
+#[cfg(feature = "debug")]
+use crate::database::vm_database::VmDatabase;
+use crate::schema::scalars::{
+    ContractId,
+    U32,
+    U64,
+};
+use async_graphql::SimpleObject;
+#[cfg(feature = "debug")]
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        Script,
+        Transaction as FuelTx,
+    },
+    fuel_vm::{
+        GasCosts,
+        Interpreter,
+    },
+};
+
+/// The maximum number of the instructions recorded by the trace.
+/// The execution is stopped when the limit is reached.
+pub const MAX_TRACE_STEPS: usize = 10_000;
+
+/// One instruction executed by the VM.
+#[derive(SimpleObject)]
+pub struct TraceStep {
+    /// The program counter of the instruction.
+    pub pc: U64,
+    /// The index of the instruction in the running code, counted from `$is`.
+    pub index: U32,
+    /// The mnemonic of the instruction, e.g. `ADDI`.
+    pub opcode: String,
+    /// The registers before the execution of the instruction, set only
+    /// for the requested indexes.
+    pub registers: Option<Vec<U64>>,
+}
+
+/// The instructions executed by the dry-run, in the order of the execution.
+#[derive(SimpleObject)]
+pub struct ExecutionTrace {
+    pub steps: Vec<TraceStep>,
+    /// `true` if the execution reached `MAX_TRACE_STEPS` and was stopped.
+    pub truncated: bool,
+    /// The final state of the script, or `null` if the execution was stopped.
+    pub state: Option<ProgramState>,
+    /// The receipts produced by the execution.
+    pub receipts: Vec<Receipt>,
+}
+
+/// The range of the code executed without a jump, in bytes from the start
+/// of the code of the contract.
+#[derive(SimpleObject)]
+pub struct PcRange {
+    /// The offset of the first executed instruction.
+    pub start: U64,
+    /// The offset past the last executed instruction.
+    pub end: U64,
+}
+
+/// The code of the contract executed by the dry-run.
+#[derive(SimpleObject)]
+pub struct ContractCoverage {
+    pub contract_id: ContractId,
+    /// The executed ranges of the code, sorted by the start, with no overlaps.
+    pub ranges: Vec<PcRange>,
+}
+
+/// The code of the contracts executed by the dry-run.
+#[derive(SimpleObject)]
+pub struct ExecutionCoverage {
+    /// The called contracts, sorted by the id.
+    pub contracts: Vec<ContractCoverage>,
+    /// `true` if the execution reached `MAX_TRACE_STEPS` and was stopped,
+    /// so the coverage is incomplete.
+    pub truncated: bool,
+}
+
+#[cfg(feature = "debug")]
+/// Runs the script `tx` on top of the `storage` and records each executed
+/// instruction. The registers are recorded before the instructions with
+/// the `snapshot_at` indexes. The changes to the `storage` are never committed.
+///
+/// Inputs and signatures are not validated, the same as for `dry_run` with
+/// the disabled `utxo_validation`.
+pub fn trace(
+    tx: FuelTx,
+    storage: crate::database::transaction::DatabaseTransaction,
+    params: ConsensusParameters,
+    gas_costs: GasCosts,
+    snapshot_at: &[u32],
+) -> anyhow::Result<ExecutionTrace> {
+    let mut steps = vec![];
+    let (final_state, receipts) =
+        run_single_stepping(tx, storage, params, gas_costs, |vm| {
+            steps.push(step(vm, snapshot_at))
+        })?;
+
+    Ok(ExecutionTrace {
+        steps,
+        truncated: final_state.is_none(),
+        state: final_state.map(Into::into),
+        receipts: receipts.into_iter().map(Receipt).collect(),
+    })
+}
+
+#[cfg(feature = "debug")]
+/// Runs the script `tx` like [`trace`], and collects the offsets of the instructions
+/// executed by each called contract. The consecutive offsets are merged into
+/// the ranges, so a jump over the code starts a new range.
+pub fn coverage(
+    tx: FuelTx,
+    storage: crate::database::transaction::DatabaseTransaction,
+    params: ConsensusParameters,
+    gas_costs: GasCosts,
+) -> anyhow::Result<ExecutionCoverage> {
+    use fuel_core_types::{
+        fuel_asm::{
+            Instruction,
+            RegId,
+        },
+        fuel_tx,
+    };
+    use std::collections::{
+        BTreeMap,
+        BTreeSet,
+    };
+
+    let mut executed: BTreeMap<fuel_tx::ContractId, BTreeSet<u64>> = BTreeMap::new();
+    let (final_state, _) = run_single_stepping(tx, storage, params, gas_costs, |vm| {
+        let registers = vm.registers();
+        // The frame pointer is zero while the script runs, and points to the call
+        // frame, which starts with the id of the contract, while a contract runs.
+        let fp = registers[RegId::FP.to_u8() as usize] as usize;
+        if fp == 0 {
+            return
+        }
+        let contract_id = vm
+            .memory()
+            .get(fp..fp + fuel_tx::ContractId::LEN)
+            .and_then(|bytes| fuel_tx::ContractId::try_from(bytes).ok());
+        if let Some(contract_id) = contract_id {
+            let pc = registers[RegId::PC.to_u8() as usize];
+            let is = registers[RegId::IS.to_u8() as usize];
+            executed
+                .entry(contract_id)
+                .or_default()
+                .insert(pc.saturating_sub(is));
+        }
+    })?;
+
+    let instruction_size = Instruction::SIZE as u64;
+    let contracts = executed
+        .into_iter()
+        .map(|(contract_id, offsets)| {
+            let mut ranges: Vec<PcRange> = vec![];
+            for offset in offsets {
+                match ranges.last_mut() {
+                    Some(range) if range.end.0 == offset => {
+                        range.end = (offset + instruction_size).into()
+                    }
+                    _ => ranges.push(PcRange {
+                        start: offset.into(),
+                        end: (offset + instruction_size).into(),
+                    }),
+                }
+            }
+            ContractCoverage {
+                contract_id: contract_id.into(),
+                ranges,
+            }
+        })
+        .collect();
+
+    Ok(ExecutionCoverage {
+        contracts,
+        truncated: final_state.is_none(),
+    })
+}
+
+#[cfg(feature = "debug")]
+/// Runs the script `tx` on top of the `storage` in the single-stepping mode, and
+/// calls the `on_step` with the VM paused before each instruction. At most
+/// `MAX_TRACE_STEPS` instructions are executed.
+///
+/// Returns the final state, or `None` if the execution was stopped, and
+/// the receipts produced by the execution.
+fn run_single_stepping(
+    tx: FuelTx,
+    storage: crate::database::transaction::DatabaseTransaction,
+    params: ConsensusParameters,
+    gas_costs: GasCosts,
+    mut on_step: impl FnMut(&Interpreter<VmDatabase, Script>),
+) -> anyhow::Result<(
+    Option<fuel_core_types::fuel_vm::ProgramState>,
+    Vec<fuel_core_types::fuel_tx::Receipt>,
+)> {
+    use super::dry_run_stream;
+    use anyhow::anyhow;
+    use fuel_core_types::{
+        fuel_tx::Receipt as FuelReceipt,
+        fuel_vm::ProgramState as VmProgramState,
+    };
+
+    let script = match tx {
+        FuelTx::Script(script) => script,
+        _ => return Err(anyhow!("Only `Script` transactions can be traced")),
+    };
+    let (checked_tx, vm_database) = dry_run_stream::prepare(script, &storage, &params)?;
+
+    let mut vm = Interpreter::with_storage(vm_database, params, gas_costs);
+    vm.set_single_stepping(true);
+    let mut state = *vm.transact(checked_tx)?.state();
+    let mut steps = 0;
+    let final_state = loop {
+        match state {
+            VmProgramState::RunProgram(_) | VmProgramState::VerifyPredicate(_) => {
+                if steps >= MAX_TRACE_STEPS {
+                    break None
+                }
+                on_step(&vm);
+                steps += 1;
+                state = vm.resume()?;
+            }
+            state => break Some(state),
+        }
+    };
+
+    // The VM appends the result of the script when it pauses before the first
+    // instruction, so it doesn't reflect the execution.
+    let receipts = vm
+        .receipts()
+        .iter()
+        .filter(|receipt| !matches!(receipt, FuelReceipt::ScriptResult { .. }))
+        .cloned()
+        .collect();
+
+    Ok((final_state, receipts))
+}
+
+#[cfg(feature = "debug")]
+/// Records the instruction at the `$pc` of the paused `vm`.
+fn step(vm: &Interpreter<VmDatabase, Script>, snapshot_at: &[u32]) -> TraceStep {
+    use fuel_core_types::fuel_asm::{
+        Instruction,
+        RegId,
+    };
+
+    let registers = vm.registers();
+    let pc = registers[RegId::PC.to_u8() as usize];
+    let is = registers[RegId::IS.to_u8() as usize];
+    let index = (pc.saturating_sub(is) / Instruction::SIZE as u64) as u32;
+
+    let opcode = vm
+        .memory()
+        .get(pc as usize..pc as usize + Instruction::SIZE)
+        .and_then(|bytes| {
+            let raw = u32::from_be_bytes(bytes.try_into().ok()?);
+            Instruction::try_from(raw).ok()
+        })
+        .map(|instruction| format!("{:?}", instruction.opcode()))
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+
+    let registers = snapshot_at
+        .contains(&index)
+        .then(|| registers.iter().copied().map(Into::into).collect());
+
+    TraceStep {
+        pc: pc.into(),
+        index: index.into(),
+        opcode,
+        registers,
+    }
+}
@@ -0,0 +1,169 @@
+use super::types::DecodedEvent;
+use crate::schema::scalars::{
+    Address,
+    AssetId,
+    ContractId,
+    U64,
+};
+use async_graphql::{
+    Enum,
+    SimpleObject,
+};
+use fuel_core_types::fuel_tx;
+
+/// The calls made by the transaction with the transfers and the logs of each
+/// call, reconstructed from the receipts of its execution.
+#[derive(SimpleObject)]
+pub struct ExecutionGraph {
+    /// The gas used by the script, `0` for other transactions.
+    pub gas_used: U64,
+    /// The frame of the script, the root of all the calls.
+    pub root: CallFrame,
+}
+
+/// How the call frame ended.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum CallFrameOutcome {
+    /// The frame returned, with or without data.
+    Return,
+    /// The frame executed the `RVRT` instruction.
+    Revert,
+    /// The execution of the frame panicked.
+    Panic,
+    /// The frame didn't end by itself, because a nested call failed
+    /// or the transaction doesn't have a script.
+    Incomplete,
+}
+
+/// The transfer of coins from the call frame.
+#[derive(SimpleObject)]
+pub struct ExecutionTransfer {
+    pub asset_id: AssetId,
+    pub amount: U64,
+    /// The receiving contract, set for the transfers to contracts.
+    pub to_contract: Option<ContractId>,
+    /// The receiving address, set for the transfers to outputs.
+    pub to_address: Option<Address>,
+}
+
+/// The call frame, either the script or a contract call.
+#[derive(SimpleObject)]
+pub struct CallFrame {
+    /// The called contract, zeroed for the script.
+    pub contract_id: ContractId,
+    /// The asset forwarded with the call, zeroed for the script.
+    pub asset_id: AssetId,
+    /// The amount forwarded with the call, `0` for the script.
+    pub amount: U64,
+    /// The gas forwarded to the call, `0` for the script.
+    pub gas: U64,
+    pub outcome: CallFrameOutcome,
+    /// The transfers made by the frame itself, in the execution order.
+    pub transfers: Vec<ExecutionTransfer>,
+    /// The logs emitted by the frame itself, in the emission order.
+    pub logs: Vec<DecodedEvent>,
+    /// The calls made by the frame, in the execution order.
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    fn new(contract_id: fuel_tx::ContractId, asset_id: fuel_tx::AssetId) -> Self {
+        Self {
+            contract_id: ContractId(contract_id),
+            asset_id: AssetId(asset_id),
+            amount: 0.into(),
+            gas: 0.into(),
+            outcome: CallFrameOutcome::Incomplete,
+            transfers: vec![],
+            logs: vec![],
+            calls: vec![],
+        }
+    }
+}
+
+impl ExecutionGraph {
+    /// Builds the graph from the `receipts` of the transaction.
+    ///
+    /// Each `CALL` receipt opens a nested frame of the current one, and the
+    /// `RETURN` or `RETURN_DATA` receipt of the contract closes it. The frames
+    /// still open when the receipts end, because the execution failed,
+    /// are closed as `INCOMPLETE`.
+    pub fn from_receipts(receipts: &[fuel_tx::Receipt]) -> Self {
+        let mut gas_used = 0;
+        let mut stack = vec![CallFrame::new(
+            fuel_tx::ContractId::zeroed(),
+            fuel_tx::AssetId::zeroed(),
+        )];
+        for receipt in receipts {
+            let call_depth = stack.len() as u32 - 1;
+            let frame = stack.last_mut().expect("The root frame is never removed");
+            match receipt {
+                fuel_tx::Receipt::Call {
+                    to,
+                    amount,
+                    asset_id,
+                    gas,
+                    ..
+                } => {
+                    let mut call = CallFrame::new(*to, *asset_id);
+                    call.amount = (*amount).into();
+                    call.gas = (*gas).into();
+                    stack.push(call);
+                }
+                fuel_tx::Receipt::Return { .. } | fuel_tx::Receipt::ReturnData { .. } => {
+                    frame.outcome = CallFrameOutcome::Return;
+                    close_frame(&mut stack);
+                }
+                fuel_tx::Receipt::Revert { .. } => {
+                    frame.outcome = CallFrameOutcome::Revert;
+                }
+                fuel_tx::Receipt::Panic { .. } => {
+                    frame.outcome = CallFrameOutcome::Panic;
+                }
+                fuel_tx::Receipt::Transfer {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => frame.transfers.push(ExecutionTransfer {
+                    asset_id: AssetId(*asset_id),
+                    amount: (*amount).into(),
+                    to_contract: Some(ContractId(*to)),
+                    to_address: None,
+                }),
+                fuel_tx::Receipt::TransferOut {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => frame.transfers.push(ExecutionTransfer {
+                    asset_id: AssetId(*asset_id),
+                    amount: (*amount).into(),
+                    to_contract: None,
+                    to_address: Some(Address(*to)),
+                }),
+                fuel_tx::Receipt::ScriptResult { gas_used: used, .. } => gas_used = *used,
+                _ => frame
+                    .logs
+                    .extend(DecodedEvent::from_receipt(receipt, call_depth)),
+            }
+        }
+
+        while stack.len() > 1 {
+            close_frame(&mut stack);
+        }
+        Self {
+            gas_used: gas_used.into(),
+            root: stack.pop().expect("The root frame is never removed"),
+        }
+    }
+}
+
+/// Moves the current frame into the calls of its parent.
+/// The root frame stays on the `stack`.
+fn close_frame(stack: &mut Vec<CallFrame>) {
+    if stack.len() > 1 {
+        let frame = stack.pop().expect("Checked above");
+        stack.last_mut().expect("Checked above").calls.push(frame);
+    }
+}
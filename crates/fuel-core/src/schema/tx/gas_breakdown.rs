@@ -0,0 +1,80 @@
+use crate::schema::scalars::{
+    ContractId,
+    U32,
+    U64,
+};
+use async_graphql::SimpleObject;
+use fuel_core_types::fuel_tx;
+
+/// The gas consumption of the dry-run, aggregated from its receipts.
+#[derive(SimpleObject)]
+pub struct GasBreakdown {
+    /// The gas limit of the script, `0` for other transactions.
+    pub gas_limit: U64,
+    /// The gas used by the script, taken from the `SCRIPT_RESULT` receipt.
+    pub gas_used: U64,
+    /// The unused part of the gas limit, which isn't charged to the sender.
+    pub gas_refunded: U64,
+    /// The contract calls, in the execution order.
+    pub calls: Vec<CallGas>,
+}
+
+/// The gas available to one contract call.
+///
+/// The receipts don't record the gas left when the call ends, so the gas used
+/// by the call itself is unknown. The gas not used by the call returns to
+/// the caller, and only the gas used by the whole script is charged.
+#[derive(SimpleObject)]
+pub struct CallGas {
+    pub contract_id: ContractId,
+    /// The number of the calls the call is nested in, `0` for the calls
+    /// made by the script.
+    pub depth: U32,
+    /// The gas forwarded to the call, the most it and its nested calls can use.
+    pub gas_forwarded: U64,
+    /// `true` if the call returned, `false` if it reverted or panicked.
+    pub returned: bool,
+}
+
+impl GasBreakdown {
+    /// Aggregates the `receipts` of the script with the `gas_limit`.
+    ///
+    /// Each `CALL` receipt opens a nested call, and the `RETURN` or `RETURN_DATA`
+    /// receipt of the contract closes it.
+    pub fn from_receipts(gas_limit: u64, receipts: &[fuel_tx::Receipt]) -> Self {
+        let mut gas_used = 0;
+        let mut calls: Vec<CallGas> = vec![];
+        // The indexes of the calls that didn't return yet.
+        let mut stack: Vec<usize> = vec![];
+        for receipt in receipts {
+            match receipt {
+                fuel_tx::Receipt::Call { to, gas, .. } => {
+                    stack.push(calls.len());
+                    calls.push(CallGas {
+                        contract_id: ContractId(*to),
+                        depth: (stack.len() as u32 - 1).into(),
+                        gas_forwarded: (*gas).into(),
+                        returned: false,
+                    });
+                }
+                fuel_tx::Receipt::Return { id, .. }
+                | fuel_tx::Receipt::ReturnData { id, .. }
+                    if *id != fuel_tx::ContractId::zeroed() =>
+                {
+                    if let Some(index) = stack.pop() {
+                        calls[index].returned = true;
+                    }
+                }
+                fuel_tx::Receipt::ScriptResult { gas_used: used, .. } => gas_used = *used,
+                _ => {}
+            }
+        }
+
+        Self {
+            gas_limit: gas_limit.into(),
+            gas_used: gas_used.into(),
+            gas_refunded: gas_limit.saturating_sub(gas_used).into(),
+            calls,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! The feed of the committed transactions that reference the owner.
+//!
+//! The heights of the committed blocks are buffered per subscriber, and
+//! the transactions of each block are read from the owned-transactions index
+//! when the subscriber polls for them.
+
+use super::types::Transaction;
+use crate::{
+    fuel_core_graphql_api::{
+        ports::BlockEvent,
+        service::Database,
+    },
+    query::SimpleTransactionData,
+};
+use async_graphql::ErrorExtensions;
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    fuel_tx::TxPointer,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+use tokio::sync::mpsc::{
+    self,
+    error::TrySendError,
+};
+use tokio_stream::wrappers::ReceiverStream;
+
+/// The `code` extension of the error emitted when the subscriber lagged behind
+/// and the blocks were skipped.
+pub const LAGGED: &str = "LAGGED";
+
+/// The number of committed blocks buffered for the subscriber. The blocks committed
+/// while the buffer is full are skipped.
+pub const OWNER_TRANSACTIONS_BUFFER: usize = 64;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Event {
+    Block(BlockHeight),
+    Lagged(u64),
+}
+
+/// Returns the stream of the transactions from the `blocks` committed after
+/// the subscription that reference the `owner` in their inputs or outputs.
+///
+/// If the subscriber doesn't keep up and the buffer overflows, or the `blocks`
+/// themselves lag behind the block importer, the stream emits the [`LAGGED`] error
+/// with the number of skipped blocks and continues with the next buffered block.
+pub fn stream(
+    database: &Database,
+    blocks: BoxStream<BlockEvent>,
+    owner: Address,
+) -> impl Stream<Item = async_graphql::Result<Transaction>> + '_ {
+    ReceiverStream::new(spawn_buffer(blocks))
+        .map(move |event| {
+            let txs = match event {
+                Event::Block(height) => block_transactions(database, owner, height),
+                Event::Lagged(skipped) => Err(async_graphql::Error::new(format!(
+                    "The subscriber lagged behind, {skipped} blocks were skipped"
+                ))
+                .extend_with(|_, e| e.set("code", LAGGED))),
+            };
+            let txs: Vec<_> = match txs {
+                Ok(txs) => txs.into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            futures::stream::iter(txs)
+        })
+        .flatten()
+}
+
+/// Forwards the heights of the committed `blocks` into the bounded buffer without
+/// waiting for the subscriber. The blocks skipped by the `blocks` are counted
+/// together with the ones skipped because the buffer is full. The task stops when
+/// the subscriber is dropped.
+fn spawn_buffer(mut blocks: BoxStream<BlockEvent>) -> mpsc::Receiver<Event> {
+    let (sender, receiver) = mpsc::channel(OWNER_TRANSACTIONS_BUFFER);
+    tokio::spawn(async move {
+        let mut skipped = 0u64;
+        loop {
+            let height = tokio::select! {
+                event = blocks.next() => match event {
+                    Some(BlockEvent::Committed(height)) => height,
+                    Some(BlockEvent::Lagged(lagged)) => {
+                        skipped = skipped.saturating_add(lagged);
+                        continue
+                    }
+                    None => return,
+                },
+                _ = sender.closed() => return,
+            };
+
+            // The subscriber is notified about the skipped blocks before the next one.
+            if skipped > 0 {
+                match sender.try_send(Event::Lagged(skipped)) {
+                    Ok(()) => skipped = 0,
+                    Err(TrySendError::Full(_)) => {
+                        skipped += 1;
+                        continue
+                    }
+                    Err(TrySendError::Closed(_)) => return,
+                }
+            }
+            match sender.try_send(Event::Block(height)) {
+                Ok(()) => {}
+                Err(TrySendError::Full(_)) => skipped += 1,
+                Err(TrySendError::Closed(_)) => return,
+            }
+        }
+    });
+    receiver
+}
+
+fn block_transactions(
+    database: &Database,
+    owner: Address,
+    height: BlockHeight,
+) -> async_graphql::Result<Vec<Transaction>> {
+    let start = TxPointer::new(height, 0);
+    database
+        .owned_transactions_ids(owner, Some(start), IterDirection::Forward)
+        .take_while(|result| {
+            !matches!(result, Ok((pointer, _)) if pointer.block_height() != height)
+        })
+        .map(|result| {
+            let (_, id) = result?;
+            let tx = database.transaction(&id)?;
+            Ok(Transaction::from_tx(id, tx))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lag_of_blocks_is_reported_before_next_block() {
+        let blocks = futures::stream::iter(vec![
+            BlockEvent::Committed(1u32.into()),
+            BlockEvent::Lagged(3),
+            BlockEvent::Committed(5u32.into()),
+        ]);
+        let mut receiver = spawn_buffer(Box::pin(blocks));
+
+        assert_eq!(receiver.recv().await, Some(Event::Block(1u32.into())));
+        assert_eq!(receiver.recv().await, Some(Event::Lagged(3)));
+        assert_eq!(receiver.recv().await, Some(Event::Block(5u32.into())));
+        assert_eq!(receiver.recv().await, None);
+    }
+}
@@ -0,0 +1,198 @@
+use super::types::Transaction;
+use crate::schema::scalars::U64;
+use async_graphql::{
+    ErrorExtensions,
+    SimpleObject,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        field::Inputs,
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        CheckError,
+        ConsensusParameters,
+        Input,
+        Transaction as FuelTx,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+    fuel_vm::{
+        checked_transaction::EstimatePredicates,
+        GasCosts,
+    },
+};
+use std::collections::BTreeSet;
+
+/// The error code of the estimation stopped at the predicate that exceeded
+/// the gas limit. The `input_index` extension holds the index of its input.
+pub const PREDICATE_GAS_LIMIT_EXCEEDED: &str = "PREDICATE_GAS_LIMIT_EXCEEDED";
+
+/// Estimates the predicates of the `tx` with the `max_gas_per_predicate` of
+/// the `params` lowered to the `gas_limit`, so the expensive predicates are
+/// stopped early.
+///
+/// If `only_missing` is set, the non-zero `predicate_gas_used` of the inputs
+/// are kept as provided, and only the zero ones are replaced by the estimation.
+pub fn estimate_predicates(
+    tx: &mut FuelTx,
+    params: &ConsensusParameters,
+    gas_costs: &GasCosts,
+    gas_limit: Option<Word>,
+    only_missing: bool,
+) -> async_graphql::Result<()> {
+    let mut params = *params;
+    if let Some(gas_limit) = gas_limit {
+        params.max_gas_per_predicate = params.max_gas_per_predicate.min(gas_limit);
+    }
+
+    let provided: Vec<Option<Word>> = if only_missing {
+        inputs_mut(tx)
+            .iter_mut()
+            .map(|input| predicate_gas_used_mut(input).map(|gas| *gas))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let result = tx.estimate_predicates(&params, gas_costs);
+
+    // The VM doesn't fail the estimation of the predicate that ran out of gas,
+    // it reports all available gas as used instead.
+    let available_gas = params.max_gas_per_predicate.min(params.max_gas_per_tx);
+    let exhausted = gas_limit.and_then(|_| {
+        inputs_mut(tx).iter_mut().position(|input| {
+            matches!(predicate_gas_used_mut(input), Some(gas) if *gas >= available_gas)
+        })
+    });
+
+    for (input, provided) in inputs_mut(tx).iter_mut().zip(provided) {
+        if let (Some(gas), Some(provided)) = (predicate_gas_used_mut(input), provided) {
+            if provided != 0 {
+                *gas = provided;
+            }
+        }
+    }
+
+    match (result, exhausted) {
+        (Ok(()), None) => Ok(()),
+        (Ok(()), Some(index))
+        | (Err(CheckError::PredicateExhaustedGas), Some(index)) => {
+            Err(async_graphql::Error::new(format!(
+                "The predicate of the input {index} exceeded the gas limit of {}",
+                params.max_gas_per_predicate
+            ))
+            .extend_with(|_, e| {
+                e.set("code", PREDICATE_GAS_LIMIT_EXCEEDED);
+                e.set("input_index", index as u64);
+            }))
+        }
+        (Err(err), _) => Err(err.into()),
+    }
+}
+
+fn inputs_mut(tx: &mut FuelTx) -> &mut [Input] {
+    match tx {
+        FuelTx::Script(script) => script.inputs_mut(),
+        FuelTx::Create(create) => create.inputs_mut(),
+        FuelTx::Mint(_) => &mut [],
+    }
+}
+
+fn predicate_gas_used_mut(input: &mut Input) -> Option<&mut Word> {
+    match input {
+        Input::CoinPredicate(CoinPredicate {
+            predicate_gas_used, ..
+        })
+        | Input::MessageCoinPredicate(MessageCoinPredicate {
+            predicate_gas_used, ..
+        })
+        | Input::MessageDataPredicate(MessageDataPredicate {
+            predicate_gas_used, ..
+        }) => Some(predicate_gas_used),
+        _ => None,
+    }
+}
+
+/// The transaction with the estimated predicates, together with the gas
+/// required to verify its inputs.
+#[derive(SimpleObject)]
+pub struct PredicatesAndFeesEstimate {
+    /// The transaction with the `predicate_gas_used` of each predicate input.
+    pub transaction: Transaction,
+    /// The gas used by all predicates of the transaction.
+    pub predicate_gas: U64,
+    /// The gas of the recovery of the signatures of the signed inputs.
+    pub signature_gas: U64,
+    /// The sum of the predicate gas and the signature gas.
+    pub verification_gas: U64,
+}
+
+impl PredicatesAndFeesEstimate {
+    /// Sums up the verification gas of the `tx` with already estimated predicates.
+    pub fn new(tx: FuelTx, chain_id: &ChainId, gas_costs: &GasCosts) -> Self {
+        let (predicate_gas, signature_gas) = match &tx {
+            FuelTx::Script(script) => verification_gas(script.inputs(), gas_costs),
+            FuelTx::Create(create) => verification_gas(create.inputs(), gas_costs),
+            FuelTx::Mint(_) => (0, 0),
+        };
+
+        Self {
+            transaction: Transaction::from_tx(tx.id(chain_id), tx),
+            predicate_gas: predicate_gas.into(),
+            signature_gas: signature_gas.into(),
+            verification_gas: predicate_gas.saturating_add(signature_gas).into(),
+        }
+    }
+}
+
+/// Returns the gas of the predicates and of the signatures of the `inputs`.
+///
+/// The inputs signed by the same witness share one signature, so each witness
+/// is recovered only once and costs `eck1`.
+fn verification_gas(inputs: &[Input], gas_costs: &GasCosts) -> (Word, Word) {
+    let predicate_gas = inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinPredicate(CoinPredicate {
+                predicate_gas_used, ..
+            })
+            | Input::MessageCoinPredicate(MessageCoinPredicate {
+                predicate_gas_used,
+                ..
+            })
+            | Input::MessageDataPredicate(MessageDataPredicate {
+                predicate_gas_used,
+                ..
+            }) => Some(*predicate_gas_used),
+            _ => None,
+        })
+        .fold(0 as Word, Word::saturating_add);
+
+    let signed_witnesses = inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned { witness_index, .. })
+            | Input::MessageCoinSigned(MessageCoinSigned { witness_index, .. })
+            | Input::MessageDataSigned(MessageDataSigned { witness_index, .. }) => {
+                Some(*witness_index)
+            }
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let signature_gas = gas_costs
+        .eck1
+        .saturating_mul(signed_witnesses.len() as Word);
+
+    (predicate_gas, signature_gas)
+}
@@ -0,0 +1,141 @@
+use async_graphql::SimpleObject;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        field::Inputs,
+        input::{
+            coin::CoinPredicate,
+            message::{
+                MessageCoinPredicate,
+                MessageDataPredicate,
+            },
+        },
+        ConsensusParameters,
+        Input,
+        Transaction as FuelTx,
+    },
+    fuel_vm::{
+        checked_transaction::EstimatePredicates,
+        GasCosts,
+    },
+};
+
+/// The result of the evaluation of one predicate input.
+#[derive(SimpleObject)]
+pub struct PredicateInputValidation {
+    /// The index of the input in the transaction.
+    pub index: u8,
+    /// `true` if the predicate of the input evaluates to `true`.
+    pub valid: bool,
+}
+
+/// The result of the evaluation of all predicates of the transaction.
+#[derive(SimpleObject)]
+pub struct PredicateValidation {
+    /// `true` if all predicates of the transaction evaluate to `true`.
+    pub all_valid: bool,
+    /// The result of each predicate input, in the order of inputs.
+    pub per_input: Vec<PredicateInputValidation>,
+}
+
+impl PredicateValidation {
+    pub fn new(tx: FuelTx, params: &ConsensusParameters, gas_costs: &GasCosts) -> Self {
+        let per_input = match tx {
+            FuelTx::Script(script) => validate_inputs(script, params, gas_costs),
+            FuelTx::Create(create) => validate_inputs(create, params, gas_costs),
+            FuelTx::Mint(_) => vec![],
+        };
+        let all_valid = per_input.iter().all(|input| input.valid);
+
+        Self {
+            all_valid,
+            per_input,
+        }
+    }
+}
+
+/// Evaluates each predicate in isolation. Predicates of other inputs are replaced
+/// with the one that always returns `true`, so the failure of one predicate
+/// doesn't hide the results of the rest.
+fn validate_inputs<Tx>(
+    tx: Tx,
+    params: &ConsensusParameters,
+    gas_costs: &GasCosts,
+) -> Vec<PredicateInputValidation>
+where
+    Tx: Inputs + EstimatePredicates + Clone,
+{
+    let predicate_indexes = tx
+        .inputs()
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| is_predicate(input))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    predicate_indexes
+        .into_iter()
+        .map(|index| {
+            let mut isolated = tx.clone();
+            isolated
+                .inputs_mut()
+                .iter_mut()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .for_each(|(_, input)| replace_with_true_predicate(input, params));
+            let valid = isolated.estimate_predicates(params, gas_costs).is_ok();
+
+            PredicateInputValidation {
+                index: index as u8,
+                valid,
+            }
+        })
+        .collect()
+}
+
+fn is_predicate(input: &Input) -> bool {
+    matches!(
+        input,
+        Input::CoinPredicate(_)
+            | Input::MessageCoinPredicate(_)
+            | Input::MessageDataPredicate(_)
+    )
+}
+
+fn replace_with_true_predicate(input: &mut Input, params: &ConsensusParameters) {
+    let true_predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let true_owner = Input::predicate_owner(&true_predicate, &params.chain_id);
+
+    match input {
+        Input::CoinPredicate(CoinPredicate {
+            owner,
+            predicate,
+            predicate_data,
+            ..
+        }) => {
+            *owner = true_owner;
+            *predicate = true_predicate;
+            predicate_data.clear();
+        }
+        Input::MessageCoinPredicate(MessageCoinPredicate {
+            recipient,
+            predicate,
+            predicate_data,
+            ..
+        })
+        | Input::MessageDataPredicate(MessageDataPredicate {
+            recipient,
+            predicate,
+            predicate_data,
+            ..
+        }) => {
+            *recipient = true_owner;
+            *predicate = true_predicate;
+            predicate_data.clear();
+        }
+        _ => {}
+    }
+}
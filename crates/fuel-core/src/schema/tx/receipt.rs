@@ -18,6 +18,7 @@ use derive_more::Display;
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
+    fuel_types::bytes::SerializableVec,
 };
 
 #[derive(Copy, Clone, Debug, Display, Enum, Eq, PartialEq, strum_macros::EnumIter)]
@@ -145,6 +146,11 @@ impl Receipt {
     async fn sub_id(&self) -> Option<Bytes32> {
         self.0.sub_id().copied().map(Into::into)
     }
+    /// The canonical serialization of the receipt, the same bytes that
+    /// the receipts root of the script is computed from.
+    async fn raw(&self) -> HexString {
+        HexString(self.0.clone().to_bytes())
+    }
 }
 
 impl From<&fuel_tx::Receipt> for Receipt {
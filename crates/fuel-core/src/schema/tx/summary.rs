@@ -0,0 +1,287 @@
+use crate::schema::scalars::{
+    AssetId,
+    ContractId,
+    U64,
+};
+use anyhow::anyhow;
+use async_graphql::SimpleObject;
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        self,
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+            },
+        },
+        Chargeable,
+        ConsensusParameters,
+        Input,
+        Output,
+        Receipt,
+        ScriptExecutionResult,
+        TransactionFee,
+    },
+    fuel_types,
+};
+use itertools::Itertools;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+#[derive(SimpleObject)]
+pub struct AssetAmount {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+/// The net change of the balance of one asset of the owner.
+#[derive(SimpleObject)]
+pub struct BalanceChange {
+    pub asset_id: AssetId,
+    /// The net amount the owner gives away, `0` if the owner gets the asset.
+    pub sent: U64,
+    /// The net amount the owner gets, `0` if the owner gives the asset away.
+    pub received: U64,
+}
+
+/// The effect of the transaction from the point of view of one owner.
+#[derive(SimpleObject)]
+pub struct TransactionSummary {
+    /// The net amounts of assets the owner gives away, the fee included.
+    pub sends: Vec<AssetAmount>,
+    /// The net amounts of assets the owner gets.
+    pub receives: Vec<AssetAmount>,
+    /// The fee paid for the transaction.
+    pub fee: U64,
+    /// The contracts called by the transaction, in the order of the first call.
+    pub contracts_called: Vec<ContractId>,
+}
+
+impl TransactionSummary {
+    /// Summarizes the transaction for the `owner` based on the receipts of its dry-run.
+    ///
+    /// The amount of the `Change` outputs is estimated as the balance left after
+    /// the coin outputs, the fee and the transfers made by the script.
+    pub fn new(
+        tx: &fuel_tx::Transaction,
+        receipts: &[Receipt],
+        owner: &fuel_types::Address,
+        params: &ConsensusParameters,
+    ) -> anyhow::Result<Self> {
+        match tx {
+            fuel_tx::Transaction::Script(script) => {
+                summarize(script, receipts, owner, params)
+            }
+            fuel_tx::Transaction::Create(create) => {
+                summarize(create, receipts, owner, params)
+            }
+            fuel_tx::Transaction::Mint(_) => {
+                Err(anyhow!("Mint transactions can't be summarized"))
+            }
+        }
+    }
+}
+
+impl TransactionSummary {
+    /// Returns the net change of each asset of the owner, ordered by the asset id.
+    /// The assets whose balance doesn't change are omitted.
+    pub fn balance_changes(&self) -> Vec<BalanceChange> {
+        let sends = self.sends.iter().map(|send| BalanceChange {
+            asset_id: send.asset_id,
+            sent: send.amount,
+            received: 0.into(),
+        });
+        let receives = self.receives.iter().map(|receive| BalanceChange {
+            asset_id: receive.asset_id,
+            sent: 0.into(),
+            received: receive.amount,
+        });
+        sends
+            .chain(receives)
+            .sorted_by_key(|change| change.asset_id)
+            .collect()
+    }
+}
+
+fn summarize<Tx>(
+    tx: &Tx,
+    receipts: &[Receipt],
+    owner: &fuel_types::Address,
+    params: &ConsensusParameters,
+) -> anyhow::Result<TransactionSummary>
+where
+    Tx: Chargeable + Inputs + Outputs,
+{
+    fn add(
+        amounts: &mut BTreeMap<fuel_types::AssetId, Word>,
+        asset_id: &fuel_types::AssetId,
+        amount: Word,
+    ) {
+        let total = amounts.entry(*asset_id).or_default();
+        *total = total.saturating_add(amount);
+    }
+
+    let base_asset_id = fuel_types::AssetId::BASE;
+    let succeeded = !receipts.iter().any(|receipt| {
+        matches!(
+            receipt,
+            Receipt::ScriptResult { result, .. }
+                if *result != ScriptExecutionResult::Success
+        )
+    });
+    let gas_used = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap_or_default();
+    let fee = TransactionFee::checked_from_values(
+        params,
+        tx.metered_bytes_size() as Word,
+        tx.gas_used_by_predicates(),
+        gas_used,
+        tx.price(),
+    )
+    .ok_or_else(|| anyhow!("The fee of the transaction overflows"))?
+    .max_fee();
+
+    let mut inputs = BTreeMap::new();
+    let mut spent = BTreeMap::new();
+    for input in tx.inputs() {
+        let (input_owner, asset_id, amount) = match input {
+            Input::CoinSigned(CoinSigned {
+                owner,
+                asset_id,
+                amount,
+                ..
+            })
+            | Input::CoinPredicate(CoinPredicate {
+                owner,
+                asset_id,
+                amount,
+                ..
+            }) => (owner, asset_id, *amount),
+            Input::MessageCoinSigned(MessageCoinSigned {
+                recipient, amount, ..
+            })
+            | Input::MessageCoinPredicate(MessageCoinPredicate {
+                recipient,
+                amount,
+                ..
+            }) => (recipient, &base_asset_id, *amount),
+            _ => continue,
+        };
+        add(&mut inputs, asset_id, amount);
+        if input_owner == owner {
+            add(&mut spent, asset_id, amount);
+        }
+    }
+
+    // The part of the inputs that doesn't return as change
+    let mut used = BTreeMap::new();
+    let mut received = BTreeMap::new();
+    let mut contracts_called = vec![];
+    add(&mut used, &base_asset_id, fee);
+    for receipt in receipts {
+        match receipt {
+            Receipt::Call { to, .. } if !contracts_called.contains(to) => {
+                contracts_called.push(*to);
+            }
+            Receipt::TransferOut {
+                to,
+                amount,
+                asset_id,
+                ..
+            } if succeeded && to == owner => {
+                add(&mut received, asset_id, *amount);
+            }
+            _ => {}
+        }
+
+        match receipt {
+            Receipt::Call {
+                id,
+                amount,
+                asset_id,
+                ..
+            }
+            | Receipt::Transfer {
+                id,
+                amount,
+                asset_id,
+                ..
+            }
+            | Receipt::TransferOut {
+                id,
+                amount,
+                asset_id,
+                ..
+            } if succeeded && *id == fuel_types::ContractId::zeroed() => {
+                add(&mut used, asset_id, *amount);
+            }
+            _ => {}
+        }
+    }
+
+    for output in tx.outputs() {
+        if let Output::Coin {
+            to,
+            amount,
+            asset_id,
+        } = output
+        {
+            add(&mut used, asset_id, *amount);
+            if to == owner {
+                add(&mut received, asset_id, *amount);
+            }
+        }
+    }
+
+    for output in tx.outputs() {
+        if let Output::Change { to, asset_id, .. } = output {
+            if to == owner {
+                let input = inputs.get(asset_id).copied().unwrap_or_default();
+                let used = used.get(asset_id).copied().unwrap_or_default();
+                add(&mut received, asset_id, input.saturating_sub(used));
+            }
+        }
+    }
+
+    let mut sends = vec![];
+    let mut receives = vec![];
+    let assets: BTreeSet<_> = spent.keys().chain(received.keys()).copied().collect();
+    for asset_id in assets {
+        let spent = spent.get(&asset_id).copied().unwrap_or_default();
+        let received = received.get(&asset_id).copied().unwrap_or_default();
+        if spent > received {
+            sends.push(AssetAmount {
+                asset_id: AssetId(asset_id),
+                amount: (spent - received).into(),
+            });
+        } else if received > spent {
+            receives.push(AssetAmount {
+                asset_id: AssetId(asset_id),
+                amount: (received - spent).into(),
+            });
+        }
+    }
+
+    Ok(TransactionSummary {
+        sends,
+        receives,
+        fee: fee.into(),
+        contracts_called: contracts_called.into_iter().map(ContractId).collect(),
+    })
+}
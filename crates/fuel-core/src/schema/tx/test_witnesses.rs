@@ -0,0 +1,59 @@
+//! The witnesses injected into the transaction before its dry-run, so the predicates
+//! verifying the off-chain signed messages can be tested without real signatures.
+//!
+//! The injection is honored only if the `test_witnesses_enabled` is set
+//! in the configuration of the node, and must never be enabled in production.
+
+use crate::schema::scalars::{
+    HexString,
+    U32,
+};
+use anyhow::anyhow;
+use fuel_core_types::fuel_tx::{
+    field::Witnesses,
+    Transaction as FuelTx,
+    Witness,
+};
+
+/// The witness that replaces the witness of the transaction at the `index`.
+#[derive(async_graphql::InputObject)]
+pub struct TestWitness {
+    /// The index of the witness. The witnesses of the transaction are extended
+    /// with the empty ones if the index is past the end.
+    pub index: U32,
+    /// The data of the witness, for example the test signature of the message.
+    pub data: HexString,
+}
+
+/// Replaces the witnesses of the `tx` with the `test_witnesses`.
+///
+/// Fails if any test witness is provided, but the `enabled` is `false`.
+pub fn inject(
+    tx: &mut FuelTx,
+    test_witnesses: Option<Vec<TestWitness>>,
+    enabled: bool,
+) -> anyhow::Result<()> {
+    let test_witnesses = test_witnesses.unwrap_or_default();
+    if test_witnesses.is_empty() {
+        return Ok(())
+    }
+    if !enabled {
+        return Err(anyhow!(
+            "The test witnesses must be enabled in the configuration of the node"
+        ))
+    }
+
+    let witnesses = match tx {
+        FuelTx::Script(script) => script.witnesses_mut(),
+        FuelTx::Create(create) => create.witnesses_mut(),
+        FuelTx::Mint(_) => return Err(anyhow!("The mint transaction has no witnesses")),
+    };
+    for TestWitness { index, data } in test_witnesses {
+        let index = index.0 as usize;
+        if witnesses.len() <= index {
+            witnesses.resize(index + 1, Witness::default());
+        }
+        witnesses[index] = Witness::from(data.0);
+    }
+    Ok(())
+}
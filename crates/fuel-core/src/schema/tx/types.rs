@@ -1,7 +1,14 @@
 use super::{
+    expected_block_interval,
+    expected_transactions_per_block,
+    gas_breakdown::GasBreakdown,
     input::Input,
     output::Output,
-    receipt::Receipt,
+    receipt::{
+        Receipt,
+        ReceiptType,
+    },
+    summary::BalanceChange,
 };
 use crate::{
     fuel_core_graphql_api::{
@@ -9,6 +16,7 @@ use crate::{
             Database,
             TxPool,
         },
+        Config,
         IntoApiResult,
     },
     query::{
@@ -22,30 +30,51 @@ use crate::{
         scalars::{
             AssetId,
             Bytes32,
+            ContractId,
             HexString,
+            Nonce,
             Salt,
             Tai64Timestamp,
             TransactionId,
             TxPointer,
+            UtxoId,
             U32,
             U64,
         },
     },
 };
 use async_graphql::{
+    connection::{
+        Connection,
+        ConnectionNameType,
+        CursorType,
+        EdgeNameType,
+        EmptyFields,
+    },
     Context,
     Enum,
     Object,
+    OutputType,
+    SelectionField,
+    SimpleObject,
     Union,
 };
-use fuel_core_storage::Error as StorageError;
+use fuel_core_storage::{
+    iter::IterDirection,
+    Error as StorageError,
+};
 use fuel_core_types::{
     blockchain::primitives,
+    fuel_asm::{
+        Instruction,
+        PanicReason,
+    },
     fuel_tx::{
         self,
         field::{
             BytecodeLength,
             BytecodeWitnessIndex,
+            GasPrice,
             Inputs,
             Maturity,
             Outputs,
@@ -57,17 +86,31 @@ use fuel_core_types::{
             TxPointer as TxPointerField,
             Witnesses,
         },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
         Chargeable,
         Executable,
     },
-    fuel_types::bytes::SerializableVec,
+    fuel_types::bytes::{
+        SerializableVec,
+        SizedBytes,
+    },
     fuel_vm::ProgramState as VmProgramState,
     services::{
+        executor,
         txpool,
-        txpool::TransactionStatus as TxStatus,
+        txpool::{
+            ArcPoolTx,
+            TransactionStatus as TxStatus,
+            TransactionStatusRecord,
+        },
     },
     tai64::Tai64,
 };
+use itertools::Itertools;
+use std::str::FromStr;
 
 pub struct ProgramState {
     return_type: ReturnType,
@@ -115,6 +158,746 @@ impl From<VmProgramState> for ProgramState {
     }
 }
 
+/// The overflowing instruction of a dry-run that ended with an arithmetic overflow panic.
+#[derive(SimpleObject)]
+pub struct ArithmeticPanic {
+    /// The program counter of the overflowing instruction.
+    pub pc: U64,
+    /// The mnemonic of the overflowing instruction, e.g. `ADD`.
+    pub operation: String,
+    /// The contract in whose context the overflow happened, zeroed for scripts.
+    pub contract_id: ContractId,
+}
+
+impl ArithmeticPanic {
+    /// Returns the details of the first arithmetic overflow `Panic` receipt, if any.
+    pub fn from_receipts(receipts: &[fuel_tx::Receipt]) -> Option<Self> {
+        receipts.iter().find_map(|receipt| match receipt {
+            fuel_tx::Receipt::Panic { id, reason, pc, .. }
+                if *reason.reason() == PanicReason::ArithmeticOverflow =>
+            {
+                let operation = Instruction::try_from(*reason.instruction())
+                    .map(|instruction| format!("{:?}", instruction.opcode()))
+                    .unwrap_or_else(|_| "UNKNOWN".to_string());
+                Some(Self {
+                    pc: (*pc).into(),
+                    operation,
+                    contract_id: ContractId(*id),
+                })
+            }
+            _ => None,
+        })
+    }
+}
+
+/// The result of a dry-run together with the decoded reason if it was reverted.
+#[derive(SimpleObject)]
+pub struct DryRunDetails {
+    /// The receipts produced by the dry-run.
+    pub receipts: Vec<Receipt>,
+    /// The reason of the revert, `null` if the dry-run didn't revert or panic.
+    pub revert_reason: Option<RevertReason>,
+    /// `true` if the existence of the UTXOs was enforced during the dry-run,
+    /// either requested explicitly or taken from the configuration of the node.
+    pub utxo_validation: bool,
+    /// The gas used by the dry-run and the gas forwarded to each contract call.
+    pub gas_breakdown: GasBreakdown,
+    /// The net change of each asset of the requested owner, `null` if no owner
+    /// was requested.
+    pub balance_changes: Option<Vec<BalanceChange>>,
+    /// The minimum gas limit of the script with which the dry-run succeeds,
+    /// `null` if the search wasn't requested or no such limit was found.
+    pub min_gas_limit: Option<U64>,
+}
+
+impl DryRunDetails {
+    pub fn new(
+        receipts: Vec<fuel_tx::Receipt>,
+        utxo_validation: bool,
+        gas_limit: u64,
+    ) -> Self {
+        let revert_reason = RevertReason::from_receipts(&receipts);
+        let gas_breakdown = GasBreakdown::from_receipts(gas_limit, &receipts);
+        Self {
+            receipts: receipts.into_iter().map(Receipt).collect(),
+            revert_reason,
+            utxo_validation,
+            gas_breakdown,
+            balance_changes: None,
+            min_gas_limit: None,
+        }
+    }
+}
+
+/// The decoded reason of a revert or a panic.
+#[derive(SimpleObject)]
+pub struct RevertReason {
+    /// The type of the receipt the reason was decoded from, `PANIC` or `REVERT`.
+    pub receipt_type: ReceiptType,
+    /// The `PanicReason` code for panics, or the value passed to `RVRT` for reverts.
+    pub reason_code: U64,
+    /// The human-readable description of the reason.
+    pub reason: String,
+    /// The contract in whose context the revert happened, zeroed for scripts.
+    pub contract_id: ContractId,
+    /// The program counter at the moment of the revert.
+    pub pc: U64,
+}
+
+impl RevertReason {
+    /// Returns the reason decoded from the first `Panic` or `Revert` receipt, if any.
+    pub fn from_receipts(receipts: &[fuel_tx::Receipt]) -> Option<Self> {
+        receipts.iter().find_map(|receipt| match receipt {
+            fuel_tx::Receipt::Panic { id, reason, pc, .. } => Some(Self {
+                receipt_type: ReceiptType::Panic,
+                reason_code: (*reason.reason() as u8 as u64).into(),
+                reason: describe_panic_reason(reason.reason()),
+                contract_id: ContractId(*id),
+                pc: (*pc).into(),
+            }),
+            fuel_tx::Receipt::Revert { id, ra, pc, .. } => Some(Self {
+                receipt_type: ReceiptType::Revert,
+                reason_code: (*ra).into(),
+                reason: format!("Reverted with the code {ra}"),
+                contract_id: ContractId(*id),
+                pc: (*pc).into(),
+            }),
+            _ => None,
+        })
+    }
+}
+
+/// The log emitted during the dry-run, annotated with its call context.
+#[derive(SimpleObject)]
+pub struct DecodedEvent {
+    /// The type of the receipt of the log, `LOG` or `LOG_DATA`.
+    pub receipt_type: ReceiptType,
+    /// The contract that emitted the log, zeroed for scripts.
+    pub contract_id: ContractId,
+    /// The depth of the call frame that emitted the log, `0` for scripts.
+    pub call_depth: U32,
+    /// The logged data. For `LOG` it is the values of `ra`, `rb`, `rc` and `rd`
+    /// registers, each encoded as 8 big-endian bytes.
+    pub data: HexString,
+}
+
+impl DecodedEvent {
+    /// Returns the logs of the `receipts` in the emission order.
+    pub fn from_receipts(receipts: &[fuel_tx::Receipt]) -> Vec<Self> {
+        let mut call_depth: u32 = 0;
+        let mut events = vec![];
+        for receipt in receipts {
+            match receipt {
+                fuel_tx::Receipt::Call { .. } => call_depth += 1,
+                fuel_tx::Receipt::Return { id, .. }
+                | fuel_tx::Receipt::ReturnData { id, .. }
+                    if *id != fuel_tx::ContractId::zeroed() =>
+                {
+                    call_depth = call_depth.saturating_sub(1)
+                }
+                _ => events.extend(Self::from_receipt(receipt, call_depth)),
+            }
+        }
+        events
+    }
+
+    /// Returns the log of the `receipt` emitted at the `call_depth`,
+    /// or `None` if the `receipt` is not a log.
+    pub fn from_receipt(receipt: &fuel_tx::Receipt, call_depth: u32) -> Option<Self> {
+        match receipt {
+            fuel_tx::Receipt::Log {
+                id, ra, rb, rc, rd, ..
+            } => Some(Self {
+                receipt_type: ReceiptType::Log,
+                contract_id: ContractId(*id),
+                call_depth: call_depth.into(),
+                data: [ra, rb, rc, rd]
+                    .into_iter()
+                    .flat_map(|register| register.to_be_bytes())
+                    .collect::<Vec<_>>()
+                    .into(),
+            }),
+            fuel_tx::Receipt::LogData { id, .. } => Some(Self {
+                receipt_type: ReceiptType::LogData,
+                contract_id: ContractId(*id),
+                call_depth: call_depth.into(),
+                data: receipt.data().unwrap_or_default().to_vec().into(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of the insertion of the transaction into the `TxPool`.
+#[derive(SimpleObject, Clone)]
+pub struct InsertionStatus {
+    /// `true` if the transaction was inserted by this submission.
+    pub inserted: bool,
+    /// `true` if the transaction was already in the `TxPool` before this submission.
+    pub already_known: bool,
+    /// The gas price used to order the transaction in the `TxPool`.
+    pub gas_price: U64,
+    /// The transactions removed from the `TxPool` because the submitted one
+    /// spends the same inputs with a higher or equal gas price, or pushed them
+    /// out of the full `TxPool`. Includes the transactions that depended on the
+    /// outputs of the removed ones.
+    pub removed: Vec<TransactionId>,
+}
+
+impl InsertionStatus {
+    /// The status of the transaction inserted by this submission.
+    pub fn from_insertion(result: &txpool::InsertionResult) -> Self {
+        Self {
+            inserted: true,
+            already_known: false,
+            gas_price: result.inserted.price().into(),
+            removed: result.removed.iter().map(|tx| tx.id().into()).collect(),
+        }
+    }
+
+    /// The status of the transaction that was already in the `TxPool`.
+    pub fn from_known_tx(tx: &fuel_tx::Transaction) -> Self {
+        let gas_price = match tx {
+            fuel_tx::Transaction::Script(script) => *script.gas_price(),
+            fuel_tx::Transaction::Create(create) => *create.gas_price(),
+            fuel_tx::Transaction::Mint(_) => 0,
+        };
+        Self {
+            inserted: false,
+            already_known: true,
+            gas_price: gas_price.into(),
+            removed: vec![],
+        }
+    }
+}
+
+/// The result of the submission of the transaction.
+#[derive(SimpleObject)]
+pub struct SubmissionResult {
+    /// The id of the submitted transaction.
+    pub id: TransactionId,
+    /// The submitted transaction.
+    pub transaction: Transaction,
+    /// The outcome of the insertion of the transaction into the `TxPool`.
+    pub insertion: InsertionStatus,
+    /// The receipts of the dry-run made before the insertion, `null` if
+    /// the preview wasn't requested.
+    pub preview_receipts: Option<Vec<Receipt>>,
+}
+
+impl SubmissionResult {
+    pub fn new(transaction: Transaction, insertion: InsertionStatus) -> Self {
+        Self {
+            id: transaction.1.into(),
+            transaction,
+            insertion,
+            preview_receipts: None,
+        }
+    }
+
+    pub fn with_preview_receipts(mut self, receipts: Option<Vec<Receipt>>) -> Self {
+        self.preview_receipts = receipts;
+        self
+    }
+}
+
+/// The result of the dry-run of the transaction, and of its submission if the
+/// dry-run allowed it.
+#[derive(SimpleObject)]
+pub struct DryRunThenSubmitResult {
+    /// The receipts produced by the dry-run.
+    pub receipts: Vec<Receipt>,
+    /// The reason of the revert, `null` if the dry-run didn't revert or panic.
+    pub revert_reason: Option<RevertReason>,
+    /// The result of the submission, `null` if the transaction wasn't submitted
+    /// because the success was required and the dry-run reverted.
+    pub submission: Option<SubmissionResult>,
+}
+
+/// What happened to the transaction passed to `submit_many`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum BatchSubmissionCategory {
+    /// The transaction was inserted into the `TxPool` by this submission.
+    NewlyInserted,
+    /// The same transaction appears earlier in the batch, so it was skipped.
+    DuplicateInBatch,
+    /// The transaction was already in the `TxPool` before this submission.
+    AlreadyKnown,
+    /// The transaction couldn't be decoded, or the `TxPool` rejected it.
+    Rejected,
+}
+
+/// The outcome of the submission of one transaction of the batch.
+#[derive(SimpleObject)]
+pub struct BatchSubmissionOutcome {
+    /// The id of the transaction, `null` if it couldn't be decoded.
+    pub id: Option<TransactionId>,
+    pub category: BatchSubmissionCategory,
+    /// The reason of the rejection, `null` unless the category is `REJECTED`.
+    pub reason: Option<String>,
+    /// The outcome of the insertion, `null` for the `DUPLICATE_IN_BATCH`
+    /// and the `REJECTED` categories.
+    pub insertion: Option<InsertionStatus>,
+}
+
+impl BatchSubmissionOutcome {
+    pub fn rejected(id: Option<TransactionId>, reason: String) -> Self {
+        Self {
+            id,
+            category: BatchSubmissionCategory::Rejected,
+            reason: Some(reason),
+            insertion: None,
+        }
+    }
+}
+
+/// What happened to the transaction passed to `resubmit`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ResubmissionStatus {
+    /// The transaction was inserted into the `TxPool`.
+    Inserted,
+    /// The transaction was already in the `TxPool`.
+    AlreadyPending,
+    /// The transaction is already included into a block, so it was skipped.
+    AlreadyCommitted,
+    /// The `TxPool` rejected the transaction.
+    Rejected,
+}
+
+/// The outcome of the resubmission of one transaction.
+#[derive(SimpleObject)]
+pub struct ResubmissionOutcome {
+    /// The id of the transaction.
+    pub id: TransactionId,
+    pub status: ResubmissionStatus,
+    /// The reason of the rejection, `null` unless the status is `REJECTED`.
+    pub reason: Option<String>,
+}
+
+/// The current status of one of the transactions of `transactionStatuses`.
+#[derive(SimpleObject)]
+pub struct TransactionStatusEntry {
+    /// The id of the transaction.
+    pub id: TransactionId,
+    /// The current status, `null` if the transaction is unknown.
+    pub status: Option<TransactionStatus>,
+}
+
+/// The result of `transactionLookup`.
+#[allow(clippy::large_enum_variant)]
+#[derive(Union)]
+pub enum TransactionLookup {
+    /// The transaction is in the `TxPool` or committed.
+    Found(Transaction),
+    /// The transaction was removed from the `TxPool` without being committed.
+    SqueezedOut(SqueezedOutStatus),
+    Unknown(UnknownTransaction),
+}
+
+/// The transaction isn't in the `TxPool`, isn't committed, and the node
+/// has no record of its removal from the `TxPool`.
+#[derive(SimpleObject)]
+pub struct UnknownTransaction {
+    pub id: TransactionId,
+}
+
+/// The ordering of the transactions returned by the `transactions` query.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransactionOrder {
+    /// By the height of the block and the index within the block.
+    BlockOrder,
+    /// By the gas price, the highest first.
+    GasPriceDesc,
+    /// By the fee paid for the gas used, the highest first.
+    FeeDesc,
+}
+
+/// The state of the coin or the message referenced by the input of the transaction.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum InputUtxoState {
+    /// The UTXO exists and isn't spent.
+    Unspent,
+    /// The UTXO is already spent or doesn't exist.
+    Spent,
+    /// The UTXO isn't spent yet, but a pending transaction in the `TxPool` spends it.
+    Reserved,
+}
+
+/// The state of the UTXO consumed by one input of the transaction passed to
+/// `transactionInputStatus`.
+#[derive(SimpleObject)]
+pub struct TransactionInputStatus {
+    /// The index of the input in the transaction.
+    pub index: U32,
+    /// The id of the coin, `null` for the message inputs.
+    pub utxo_id: Option<UtxoId>,
+    /// The nonce of the message, `null` for the coin inputs.
+    pub nonce: Option<Nonce>,
+    pub state: InputUtxoState,
+    /// The ids of the pending transactions spending the UTXO,
+    /// empty unless the state is `RESERVED`.
+    pub reserved_by: Vec<TransactionId>,
+}
+
+/// The role of the owner in the transaction returned by `transactionsByOwner`.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum OwnerRole {
+    /// The owner spends the coins of the inputs.
+    Sender,
+    /// The owner receives the coins of the outputs.
+    Recipient,
+    /// The owner spends the coins of the inputs and receives the coins of the outputs,
+    /// e.g. the change.
+    Both,
+}
+
+impl OwnerRole {
+    /// Derives the role of the `owner` from the coin inputs and the coin, the change
+    /// and the variable outputs of the `tx`, the same ones that index the owned
+    /// transactions. The transaction that doesn't reference the `owner` in its inputs
+    /// isn't indexed unless the `owner` is a recipient.
+    pub fn of(tx: &fuel_tx::Transaction, owner: &fuel_tx::Address) -> Self {
+        let (inputs, outputs) = match tx {
+            fuel_tx::Transaction::Script(script) => {
+                (script.inputs().as_slice(), script.outputs().as_slice())
+            }
+            fuel_tx::Transaction::Create(create) => {
+                (create.inputs().as_slice(), create.outputs().as_slice())
+            }
+            fuel_tx::Transaction::Mint(mint) => (&[][..], mint.outputs().as_slice()),
+        };
+        let sends = inputs.iter().any(|input| match input {
+            fuel_tx::Input::CoinSigned(CoinSigned { owner: from, .. })
+            | fuel_tx::Input::CoinPredicate(CoinPredicate { owner: from, .. }) => {
+                from == owner
+            }
+            _ => false,
+        });
+        let receives = outputs.iter().any(|output| match output {
+            fuel_tx::Output::Coin { to, .. }
+            | fuel_tx::Output::Change { to, .. }
+            | fuel_tx::Output::Variable { to, .. } => to == owner,
+            fuel_tx::Output::Contract { .. }
+            | fuel_tx::Output::ContractCreated { .. } => false,
+        });
+
+        match (sends, receives) {
+            (true, true) => OwnerRole::Both,
+            (true, false) => OwnerRole::Sender,
+            (false, _) => OwnerRole::Recipient,
+        }
+    }
+}
+
+/// The fields of the edge of the `OwnedTransactionConnection`.
+#[derive(SimpleObject)]
+pub struct OwnedTransactionFields {
+    /// Whether the queried owner sends, receives or both in the transaction.
+    pub owner_role: OwnerRole,
+}
+
+pub struct OwnedTransactionConnectionName;
+
+impl ConnectionNameType for OwnedTransactionConnectionName {
+    fn type_name<T: OutputType>() -> String {
+        "OwnedTransactionConnection".to_string()
+    }
+}
+
+pub struct OwnedTransactionEdgeName;
+
+impl EdgeNameType for OwnedTransactionEdgeName {
+    fn type_name<T: OutputType>() -> String {
+        "OwnedTransactionEdge".to_string()
+    }
+}
+
+/// The transactions of the owner together with the role of the owner in each of them.
+pub type OwnedTransactionConnection = Connection<
+    TxPointer,
+    Transaction,
+    EmptyFields,
+    OwnedTransactionFields,
+    OwnedTransactionConnectionName,
+    OwnedTransactionEdgeName,
+>;
+
+/// The change of the contract storage slot made by the dry-run.
+#[derive(SimpleObject)]
+pub struct StorageSlotDelta {
+    /// The contract that owns the slot.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The value of the slot before the dry-run, `null` if the slot didn't exist.
+    pub before: Option<Bytes32>,
+    /// The value of the slot after the dry-run. The cleared slot is zeroed.
+    pub after: Bytes32,
+}
+
+impl From<executor::StorageSlotDelta> for StorageSlotDelta {
+    fn from(delta: executor::StorageSlotDelta) -> Self {
+        Self {
+            contract_id: delta.contract_id.into(),
+            key: delta.key.into(),
+            before: delta.before.map(Into::into),
+            after: delta.after.into(),
+        }
+    }
+}
+
+/// The outcome of the transaction in the simulated block.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum SimulatedTransactionStatus {
+    /// The transaction was included and executed successfully.
+    Success,
+    /// The transaction was included, but its execution failed.
+    Failure,
+    /// The transaction was rejected by the execution as invalid.
+    Skipped,
+    /// The transaction didn't fit into the gas limit of the block.
+    Excluded,
+}
+
+/// The transaction of the simulated block.
+#[derive(SimpleObject)]
+pub struct SimulatedTransaction {
+    /// The id of the transaction.
+    pub id: TransactionId,
+    /// The outcome of the transaction.
+    pub status: SimulatedTransactionStatus,
+    /// The reason of the failure or of the rejection of the transaction.
+    pub reason: Option<String>,
+    /// The receipts of the included transaction, empty for other outcomes.
+    pub receipts: Vec<Receipt>,
+}
+
+impl From<executor::SimulatedTransaction> for SimulatedTransaction {
+    fn from(transaction: executor::SimulatedTransaction) -> Self {
+        match transaction {
+            executor::SimulatedTransaction::Included {
+                id,
+                result,
+                receipts,
+            } => {
+                let (status, reason) = match result {
+                    executor::TransactionExecutionResult::Success { .. } => {
+                        (SimulatedTransactionStatus::Success, None)
+                    }
+                    executor::TransactionExecutionResult::Failed { reason, .. } => {
+                        (SimulatedTransactionStatus::Failure, Some(reason))
+                    }
+                };
+                Self {
+                    id: id.into(),
+                    status,
+                    reason,
+                    receipts: receipts.iter().map(Into::into).collect(),
+                }
+            }
+            executor::SimulatedTransaction::Skipped { id, reason } => Self {
+                id: id.into(),
+                status: SimulatedTransactionStatus::Skipped,
+                reason: Some(reason),
+                receipts: vec![],
+            },
+            executor::SimulatedTransaction::Excluded { id } => Self {
+                id: id.into(),
+                status: SimulatedTransactionStatus::Excluded,
+                reason: None,
+                receipts: vec![],
+            },
+        }
+    }
+}
+
+/// The result of the simulation of the block made of the chosen transactions.
+#[derive(SimpleObject)]
+pub struct BlockSimulation {
+    /// The height of the simulated block.
+    pub height: U32,
+    /// The gas used by the included transactions.
+    pub gas_used: U64,
+    /// The fees paid to the block producer by the included transactions.
+    pub fees: U64,
+    /// The outcome of each transaction, in the submitted order.
+    pub transactions: Vec<SimulatedTransaction>,
+}
+
+impl From<executor::BlockSimulation> for BlockSimulation {
+    fn from(simulation: executor::BlockSimulation) -> Self {
+        Self {
+            height: simulation.height.into(),
+            gas_used: simulation.gas_used.into(),
+            fees: simulation.fees.into(),
+            transactions: simulation
+                .transactions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// The range of gas prices of the pending transactions with the number of transactions
+/// in it. The bounds are inclusive.
+#[derive(SimpleObject)]
+pub struct GasPriceBucket {
+    /// The lowest gas price of the bucket.
+    pub min_gas_price: U64,
+    /// The highest gas price of the bucket.
+    pub max_gas_price: U64,
+    /// The number of transactions with the gas price in the bucket.
+    pub count: U32,
+}
+
+impl GasPriceBucket {
+    /// Splits the range between the lowest and the highest of the `gas_prices` into
+    /// at most `buckets` buckets of equal width and counts the prices in each of them.
+    /// The empty buckets are included, so the histogram is continuous.
+    pub fn histogram(gas_prices: &[u64], buckets: u64) -> Vec<Self> {
+        let (min, max) = match gas_prices.iter().minmax().into_option() {
+            Some((min, max)) => (*min, *max),
+            None => return vec![],
+        };
+        let width = (max - min) / buckets.max(1) + 1;
+        let mut counts = vec![0u32; ((max - min) / width + 1) as usize];
+        for price in gas_prices {
+            counts[((price - min) / width) as usize] += 1;
+        }
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let lower = min + index as u64 * width;
+                Self {
+                    min_gas_price: lower.into(),
+                    max_gas_price: lower.saturating_add(width - 1).min(max).into(),
+                    count: count.into(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The pending transactions that a higher minimum gas price would evict
+/// from the `TxPool`.
+#[derive(SimpleObject)]
+pub struct GasPriceImpact {
+    /// The number of the pending transactions with a lower gas price.
+    pub evicted_count: U32,
+    /// The sum of the maximum fees of these transactions.
+    pub total_fee: U64,
+}
+
+impl GasPriceImpact {
+    /// Aggregates the `txs` with the gas price below the `min_gas_price`.
+    pub fn below(txs: &[ArcPoolTx], min_gas_price: u64) -> Self {
+        let (count, fee) = txs
+            .iter()
+            .filter(|tx| tx.price() < min_gas_price)
+            .fold((0u32, 0u64), |(count, fee), tx| {
+                (count + 1, fee.saturating_add(tx.max_fee()))
+            });
+        Self {
+            evicted_count: count.into(),
+            total_fee: fee.into(),
+        }
+    }
+}
+
+/// The statistics of the gas prices and the sizes of the transactions included
+/// into the recent blocks. The `Mint` transactions aren't covered.
+///
+/// The percentiles are computed with the nearest-rank method, so each of them is
+/// the gas price of some covered transaction. All values are `0` if no transaction
+/// is covered.
+#[derive(SimpleObject)]
+pub struct FeeStatistics {
+    /// The number of scanned blocks.
+    pub block_count: U32,
+    /// The number of covered transactions.
+    pub transaction_count: U32,
+    pub min_gas_price: U64,
+    /// The 25th percentile of the gas prices.
+    pub p25_gas_price: U64,
+    /// The 50th percentile of the gas prices.
+    pub median_gas_price: U64,
+    /// The 75th percentile of the gas prices.
+    pub p75_gas_price: U64,
+    /// The 90th percentile of the gas prices.
+    pub p90_gas_price: U64,
+    pub max_gas_price: U64,
+    /// The average metered size of the transactions in bytes.
+    pub average_transaction_size: U64,
+}
+
+impl FeeStatistics {
+    /// Computes the statistics of the `transactions`, given as pairs of
+    /// the gas price and the metered size, from `block_count` blocks.
+    pub fn new(block_count: u32, transactions: &[(u64, u64)]) -> Self {
+        let mut gas_prices = transactions
+            .iter()
+            .map(|(gas_price, _)| *gas_price)
+            .collect_vec();
+        gas_prices.sort_unstable();
+        let percentile = |percent: usize| {
+            let rank = (percent * gas_prices.len() + 99) / 100;
+            gas_prices
+                .get(rank.saturating_sub(1))
+                .copied()
+                .unwrap_or_default()
+        };
+        let total_size = transactions
+            .iter()
+            .map(|(_, size)| *size)
+            .fold(0u64, u64::saturating_add);
+        let average_size = total_size
+            .checked_div(transactions.len() as u64)
+            .unwrap_or_default();
+
+        Self {
+            block_count: block_count.into(),
+            transaction_count: (transactions.len() as u32).into(),
+            min_gas_price: gas_prices.first().copied().unwrap_or_default().into(),
+            p25_gas_price: percentile(25).into(),
+            median_gas_price: percentile(50).into(),
+            p75_gas_price: percentile(75).into(),
+            p90_gas_price: percentile(90).into(),
+            max_gas_price: gas_prices.last().copied().unwrap_or_default().into(),
+            average_transaction_size: average_size.into(),
+        }
+    }
+}
+
+fn describe_panic_reason(reason: &PanicReason) -> String {
+    let description = match reason {
+        PanicReason::OutOfGas => "The transaction ran out of gas",
+        PanicReason::MemoryOverflow => "A memory access was out of bounds",
+        PanicReason::ArithmeticOverflow => "An arithmetic operation overflowed",
+        PanicReason::ContractNotFound => "The called contract doesn't exist",
+        PanicReason::MemoryOwnership => "The memory isn't owned by the current context",
+        PanicReason::NotEnoughBalance => "The balance is too low for the operation",
+        PanicReason::ExpectedInternalContext => {
+            "The instruction can only be used inside of a contract"
+        }
+        PanicReason::InputNotFound => "The requested input doesn't exist",
+        PanicReason::OutputNotFound => "The requested output doesn't exist",
+        PanicReason::WitnessNotFound => "The requested witness doesn't exist",
+        PanicReason::TransactionMaturity => "The transaction maturity isn't reached",
+        PanicReason::ContractNotInInputs => {
+            "The contract is used but isn't in the transaction inputs"
+        }
+        PanicReason::TransferAmountCannotBeZero => "The transfer amount is zero",
+        PanicReason::ExpectedOutputVariable => "The output isn't a variable output",
+        PanicReason::IllegalJump => "The jump target is out of bounds",
+        PanicReason::ErrorFlag => "The error flag was set by the previous instruction",
+        _ => return format!("{reason:?}"),
+    };
+    description.to_string()
+}
+
 #[derive(Union, Debug)]
 pub enum TransactionStatus {
     Submitted(SubmittedStatus),
@@ -124,12 +907,31 @@ pub enum TransactionStatus {
 }
 
 #[derive(Debug)]
-pub struct SubmittedStatus(pub Tai64);
+pub struct SubmittedStatus {
+    pub time: Tai64,
+    /// The id of the transaction to look up its position in the `TxPool`.
+    pub tx_id: Option<fuel_tx::TxId>,
+}
 
 #[Object]
 impl SubmittedStatus {
     async fn time(&self) -> Tai64Timestamp {
-        Tai64Timestamp(self.0)
+        Tai64Timestamp(self.time)
+    }
+
+    /// The number of seconds since the submission. It is computed when the status
+    /// is emitted, so the repeated emissions report the growing age.
+    async fn age_seconds(&self) -> U64 {
+        Tai64::now().0.saturating_sub(self.time.0).into()
+    }
+
+    /// The estimated position of the transaction in the `TxPool` ordered by the gas
+    /// price, where `0` is the next transaction to be included. `null` if the
+    /// transaction isn't pending anymore.
+    async fn queue_position(&self, ctx: &Context<'_>) -> Option<U32> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let position = txpool.queue_position(self.tx_id?)?;
+        Some(u32::try_from(position).unwrap_or(u32::MAX).into())
     }
 }
 
@@ -198,11 +1000,75 @@ impl SqueezedOutStatus {
     }
 }
 
+/// The status of the transaction with the time of the transition to it.
+#[derive(SimpleObject)]
+pub struct TransactionStatusTransition {
+    /// The time of the submission for `SubmittedStatus`, the time of the block
+    /// for `SuccessStatus` and `FailureStatus`, and the time of the removal for
+    /// `SqueezedOutStatus`.
+    pub time: Tai64Timestamp,
+    pub status: TransactionStatus,
+    /// The cursor to resume `statusChange` after this transition.
+    pub cursor: String,
+}
+
+impl From<TransactionStatusRecord> for TransactionStatusTransition {
+    fn from(record: TransactionStatusRecord) -> Self {
+        Self {
+            time: Tai64Timestamp(record.time),
+            cursor: TxStatusCursor::of(&record).encode_cursor(),
+            status: record.status.into(),
+        }
+    }
+}
+
+/// Identifies the record in the status history of the transaction by the kind
+/// of the status and the time of the transition to it, in the `KIND#TIME` format.
+/// Unlike the position in the history, it stays valid when the older records
+/// are dropped from the history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxStatusCursor {
+    kind: String,
+    time: Tai64,
+}
+
+impl TxStatusCursor {
+    pub fn of(record: &TransactionStatusRecord) -> Self {
+        let kind = match record.status {
+            TxStatus::Submitted { .. } => "SUBMITTED",
+            TxStatus::Success { .. } => "SUCCESS",
+            TxStatus::Failed { .. } => "FAILURE",
+            TxStatus::SqueezedOut { .. } => "SQUEEZED_OUT",
+        };
+        Self {
+            kind: kind.to_string(),
+            time: record.time,
+        }
+    }
+}
+
+impl CursorType for TxStatusCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (kind, time) = s.split_once('#').ok_or("Incorrect format provided")?;
+        let time = u64::from_str(time).map_err(|_| "Failed to decode time")?;
+        Ok(Self {
+            kind: kind.to_string(),
+            time: Tai64(time),
+        })
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.kind, self.time.0)
+    }
+}
+
 impl From<TxStatus> for TransactionStatus {
     fn from(s: TxStatus) -> Self {
         match s {
             TxStatus::Submitted { time } => {
-                TransactionStatus::Submitted(SubmittedStatus(time))
+                TransactionStatus::Submitted(SubmittedStatus { time, tx_id: None })
             }
             TxStatus::Success {
                 block_id,
@@ -234,7 +1100,7 @@ impl From<TxStatus> for TransactionStatus {
 impl From<TransactionStatus> for TxStatus {
     fn from(s: TransactionStatus) -> Self {
         match s {
-            TransactionStatus::Submitted(SubmittedStatus(time)) => {
+            TransactionStatus::Submitted(SubmittedStatus { time, .. }) => {
                 TxStatus::Submitted { time }
             }
             TransactionStatus::Success(SuccessStatus {
@@ -269,6 +1135,56 @@ impl Transaction {
     pub fn from_tx(id: fuel_tx::TxId, tx: fuel_tx::Transaction) -> Self {
         Self(tx, id)
     }
+
+    /// The fields resolved only from the id of the transaction.
+    const ID_ONLY_FIELDS: [&'static str; 3] = ["id", "status", "__typename"];
+
+    /// Returns `true` if the `selection` of the transaction doesn't require its body.
+    pub fn requires_only_id(selection: SelectionField<'_>) -> bool {
+        selection
+            .selection_set()
+            .all(|field| Self::ID_ONLY_FIELDS.contains(&field.name()))
+    }
+
+    /// The transaction without the body, for the selections that require
+    /// only the id. The body is a placeholder and is never resolved.
+    pub fn id_only(id: fuel_tx::TxId) -> Self {
+        Self(fuel_tx::Transaction::default(), id)
+    }
+
+    /// Returns the size of the serialized transaction in bytes.
+    pub fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    /// Returns the number of the inputs matching the `filter`, `None` for
+    /// the `Mint` transactions.
+    fn count_inputs(&self, filter: fn(&fuel_tx::Input) -> bool) -> Option<u32> {
+        let inputs = match &self.0 {
+            fuel_tx::Transaction::Script(script) => script.inputs(),
+            fuel_tx::Transaction::Create(create) => create.inputs(),
+            fuel_tx::Transaction::Mint(_) => return None,
+        };
+        Some(inputs.iter().filter(|input| filter(input)).count() as u32)
+    }
+}
+
+fn is_signed_input(input: &fuel_tx::Input) -> bool {
+    matches!(
+        input,
+        fuel_tx::Input::CoinSigned(_)
+            | fuel_tx::Input::MessageCoinSigned(_)
+            | fuel_tx::Input::MessageDataSigned(_)
+    )
+}
+
+fn is_predicate_input(input: &fuel_tx::Input) -> bool {
+    matches!(
+        input,
+        fuel_tx::Input::CoinPredicate(_)
+            | fuel_tx::Input::MessageCoinPredicate(_)
+            | fuel_tx::Input::MessageDataPredicate(_)
+    )
 }
 
 #[Object]
@@ -358,6 +1274,23 @@ impl Transaction {
         }
     }
 
+    /// `true` if any coin or message input is authorized by a signature.
+    async fn requires_signatures(&self) -> Option<bool> {
+        self.count_inputs(is_signed_input).map(|count| count > 0)
+    }
+
+    /// The number of the coin and message inputs authorized by a predicate.
+    async fn predicate_inputs_count(&self) -> Option<u32> {
+        self.count_inputs(is_predicate_input)
+    }
+
+    /// `true` if the transaction has predicate inputs and doesn't require signatures.
+    async fn is_predicate_only(&self) -> Option<bool> {
+        let signed = self.count_inputs(is_signed_input)?;
+        let predicates = self.count_inputs(is_predicate_input)?;
+        Some(signed == 0 && predicates > 0)
+    }
+
     async fn outputs(&self) -> Vec<Output> {
         match &self.0 {
             fuel_tx::Transaction::Script(script) => {
@@ -412,15 +1345,145 @@ impl Transaction {
         get_tx_status(id, query, txpool).map_err(Into::into)
     }
 
+    /// The estimated number of seconds until the pending transaction is committed.
+    /// For the committed transaction, the number of seconds it took to commit it
+    /// since the submission, if this node recorded the submission. `null` otherwise.
+    ///
+    /// The estimate is a heuristic: the pending transactions are expected to be
+    /// included from the highest gas price, at the average number of transactions
+    /// per block, one block per the average block interval. Both averages are
+    /// measured over the last `POOL_TURNOVER_BLOCKS` blocks. It doesn't account for
+    /// the block gas limit or the transactions submitted later with a higher gas price.
+    async fn estimated_confirmation_time(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U64>> {
+        let id = self.1;
+        let query: &Database = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let status = query
+            .status(&id)
+            .into_api_result::<TxStatus, async_graphql::Error>()?;
+        match status {
+            Some(TxStatus::Success { time, .. })
+            | Some(TxStatus::Failed { time, .. }) => {
+                let committed = u64::try_from(time.to_unix()).unwrap_or_default();
+                Ok(txpool.committed_submission_time(id).map(|submitted| {
+                    committed.saturating_sub(submitted.as_secs()).into()
+                }))
+            }
+            Some(_) => Ok(None),
+            None => {
+                let position = match txpool.queue_position(id) {
+                    Some(position) => position as u64,
+                    None => return Ok(None),
+                };
+                let blocks = position / expected_transactions_per_block(query) + 1;
+                Ok(Some(
+                    blocks.saturating_mul(expected_block_interval(query)).into(),
+                ))
+            }
+        }
+    }
+
+    /// The block that includes the transaction, or `null` if the transaction
+    /// isn't committed.
+    async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Block>> {
+        let query: &Database = ctx.data_unchecked();
+        let status = query
+            .status(&self.1)
+            .into_api_result::<TxStatus, async_graphql::Error>()?;
+        let block_id = match status {
+            Some(TxStatus::Success { block_id, .. })
+            | Some(TxStatus::Failed { block_id, .. }) => block_id,
+            _ => return Ok(None),
+        };
+        let block = query.block(&block_id)?;
+        Ok(Some(block.into()))
+    }
+
+    /// The receipts of the transaction. If the node limits the number of
+    /// the receipts, only the first `max_receipts` are returned, and
+    /// `receipts_truncated` is set.
     async fn receipts(
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Option<Vec<Receipt>>> {
+        let query: &Database = ctx.data_unchecked();
+        let max_receipts = ctx.data_unchecked::<Config>().max_receipts;
+        let receipts = query
+            .receipts(&self.1)
+            .into_api_result::<Vec<_>, async_graphql::Error>()?;
+        Ok(receipts.map(|receipts| {
+            receipts
+                .into_iter()
+                .take(max_receipts.unwrap_or(usize::MAX))
+                .map(Receipt)
+                .collect()
+        }))
+    }
+
+    /// Whether `receipts` omits some of the receipts of the transaction
+    /// because of the limit of the node. The whole list is available from
+    /// `receipts_connection` then.
+    async fn receipts_truncated(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let max_receipts = match ctx.data_unchecked::<Config>().max_receipts {
+            Some(max_receipts) => max_receipts,
+            None => return Ok(false),
+        };
         let query: &Database = ctx.data_unchecked();
         let receipts = query
             .receipts(&self.1)
             .into_api_result::<Vec<_>, async_graphql::Error>()?;
-        Ok(receipts.map(|receipts| receipts.into_iter().map(Receipt).collect()))
+        Ok(receipts.map_or(false, |receipts| receipts.len() > max_receipts))
+    }
+
+    /// Returns the receipts of the transaction page by page. The cursor is the index
+    /// of the receipt, so it is preferable over `receipts` for transactions with many receipts.
+    async fn receipts_connection(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, Receipt, EmptyFields, EmptyFields>> {
+        let query: &Database = ctx.data_unchecked();
+        let receipts = query
+            .receipts(&self.1)
+            .into_api_result::<Vec<_>, async_graphql::Error>()?
+            .unwrap_or_default();
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<U32>, direction| {
+                let mut receipts: Vec<_> = receipts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, receipt)| Ok((U32(index as u32), Receipt(receipt))))
+                    .collect();
+                let start = start.map(|index| index.0 as usize);
+
+                match direction {
+                    IterDirection::Forward => {
+                        let start = start.unwrap_or_default().min(receipts.len());
+                        receipts.drain(..start);
+                    }
+                    IterDirection::Reverse => {
+                        if let Some(start) = start {
+                            receipts.truncate(start.saturating_add(1));
+                        }
+                        receipts.reverse();
+                    }
+                }
+
+                Ok(receipts.into_iter())
+            },
+        )
+        .await
     }
 
     async fn script(&self) -> Option<HexString> {
@@ -512,10 +1575,78 @@ pub(crate) fn get_tx_status(
     {
         Some(status) => Ok(Some(status.into())),
         None => match txpool.submission_time(id) {
-            Some(submitted_time) => Ok(Some(TransactionStatus::Submitted(
-                SubmittedStatus(submitted_time),
-            ))),
+            Some(submitted_time) => {
+                Ok(Some(TransactionStatus::Submitted(SubmittedStatus {
+                    time: submitted_time,
+                    tx_id: Some(id),
+                })))
+            }
             _ => Ok(None),
         },
     }
 }
+
+/// Returns the recorded transitions of the status of the transaction, from the oldest
+/// to the latest. The submissions and the removals are kept in memory by the `TxPool`
+/// in the order they were recorded. The commit is the last transition, so the record
+/// of the committed transaction is built from its status in the database and
+/// appended at the end. The records aren't sorted by their time, because the time
+/// of the block comes from the block producer, not from the clock of the `TxPool`.
+pub(crate) fn get_tx_status_history(
+    id: fuel_core_types::fuel_types::Bytes32,
+    query: &Database,
+    txpool: &TxPool,
+) -> Result<Vec<TransactionStatusRecord>, StorageError> {
+    let mut history = txpool.status_history(id);
+    let status = match query.status(&id) {
+        Ok(status) => status,
+        Err(StorageError::NotFound(_, _)) => return Ok(history),
+        Err(err) => return Err(err),
+    };
+    // Only the executor sets the status in the database, to the result of the commit
+    if let TxStatus::Success { time, .. } | TxStatus::Failed { time, .. } = &status {
+        let time = *time;
+        history.push(TransactionStatusRecord { time, status });
+    }
+    Ok(history)
+}
+
+/// Returns the statuses of the transactions with the `ids` in the same order,
+/// like [`get_tx_status`] for each id. The ids unknown to the database are
+/// looked up in the `TxPool` at once, under one lock.
+pub(crate) fn get_tx_statuses(
+    ids: &[fuel_core_types::fuel_types::Bytes32],
+    query: &Database,
+    txpool: &TxPool,
+) -> Result<Vec<Option<TransactionStatus>>, StorageError> {
+    let mut statuses = ids
+        .iter()
+        .map(|id| {
+            query
+                .status(id)
+                .into_api_result::<txpool::TransactionStatus, StorageError>()
+                .map(|status| status.map(Into::into))
+        })
+        .collect::<Result<Vec<Option<TransactionStatus>>, _>>()?;
+
+    let unknown = ids
+        .iter()
+        .zip(&statuses)
+        .filter(|(_, status)| status.is_none())
+        .map(|(id, _)| *id)
+        .collect_vec();
+    let mut submission_times = txpool.submission_times(unknown).into_iter();
+    for (id, status) in ids
+        .iter()
+        .zip(statuses.iter_mut())
+        .filter(|(_, status)| status.is_none())
+    {
+        if let Some(Some(submitted_time)) = submission_times.next() {
+            *status = Some(TransactionStatus::Submitted(SubmittedStatus {
+                time: submitted_time,
+                tx_id: Some(*id),
+            }));
+        }
+    }
+    Ok(statuses)
+}
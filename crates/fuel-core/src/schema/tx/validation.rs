@@ -0,0 +1,241 @@
+use super::predicate_validation::PredicateValidation;
+use crate::graphql_api::Config;
+use async_graphql::SimpleObject;
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        field::{
+            BytecodeWitnessIndex,
+            Inputs,
+            Witnesses,
+        },
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataSigned,
+            },
+        },
+        AssetId,
+        Chargeable,
+        CheckError,
+        FormatValidityChecks,
+        Input,
+        Transaction as FuelTx,
+        TransactionFee,
+    },
+    fuel_types::BlockHeight,
+};
+
+/// The problem found by the validation of the transaction.
+#[derive(SimpleObject)]
+pub struct ValidationFailure {
+    /// The path to the invalid field, like `inputs[0].witnessIndex`,
+    /// or `null` if the problem isn't tied to one field.
+    pub path: Option<String>,
+    /// The description of the problem.
+    pub reason: String,
+}
+
+impl ValidationFailure {
+    fn new(path: Option<String>, reason: String) -> Self {
+        Self { path, reason }
+    }
+}
+
+/// Runs the same checks as the insertion into the `TxPool` and returns all
+/// the problems found, instead of stopping at the first one. The checks of
+/// the structure, the fee, the witness indexes, the signatures and each
+/// predicate are independent, so one invalid field doesn't hide the others.
+///
+/// The transaction whose maximum gas exceeds the block gas limit is reported too,
+/// because it can't be included into a block even though the `TxPool` accepts it.
+///
+/// As in the `TxPool`, the signatures and the predicates are verified only if
+/// the UTXO validation is enabled.
+pub fn validate_transaction(
+    tx: &FuelTx,
+    height: BlockHeight,
+    config: &Config,
+) -> Vec<ValidationFailure> {
+    let mut failures = match tx {
+        FuelTx::Script(script) => witness_index_failures(script),
+        FuelTx::Create(create) => {
+            let mut failures = witness_index_failures(create);
+            let index = *create.bytecode_witness_index();
+            if index as usize >= create.witnesses().len() {
+                failures.push(ValidationFailure::new(
+                    Some("bytecodeWitnessIndex".to_string()),
+                    format!("The witness {index} doesn't exist"),
+                ));
+            }
+            failures
+        }
+        FuelTx::Mint(_) => {
+            return vec![ValidationFailure::new(
+                None,
+                "Mint transactions can't be submitted".to_string(),
+            )]
+        }
+    };
+    let witness_failures = !failures.is_empty();
+    match tx {
+        FuelTx::Script(script) => {
+            failures.extend(fee_failures(script, config));
+            failures.extend(block_gas_failure(script, config));
+        }
+        FuelTx::Create(create) => {
+            failures.extend(fee_failures(create, config));
+            failures.extend(block_gas_failure(create, config));
+        }
+        FuelTx::Mint(_) => {}
+    }
+
+    let params = &config.transaction_parameters;
+    // The invalid witness indexes are already reported with their paths.
+    let is_reported = |error: &CheckError| {
+        witness_failures
+            && matches!(
+                error,
+                CheckError::InputWitnessIndexBounds { .. }
+                    | CheckError::TransactionCreateBytecodeWitnessIndex
+            )
+    };
+    if let Err(error) = tx.check_without_signatures(height, params) {
+        if !is_reported(&error) {
+            failures.push(ValidationFailure::new(None, format!("{error:?}")));
+        }
+    }
+
+    if config.utxo_validation {
+        if let Err(error) = tx.check_signatures(&params.chain_id) {
+            if !is_reported(&error) {
+                failures.push(ValidationFailure::new(
+                    Some("witnesses".to_string()),
+                    format!("{error:?}"),
+                ));
+            }
+        }
+
+        let predicates = PredicateValidation::new(tx.clone(), params, &config.gas_costs);
+        failures.extend(
+            predicates
+                .per_input
+                .into_iter()
+                .filter(|input| !input.valid)
+                .map(|input| {
+                    ValidationFailure::new(
+                        Some(format!("inputs[{}].predicate", input.index)),
+                        "The predicate doesn't allow spending the input".to_string(),
+                    )
+                }),
+        );
+    }
+
+    failures
+}
+
+/// Checks that the signed inputs refer to the existing witnesses.
+fn witness_index_failures<Tx>(tx: &Tx) -> Vec<ValidationFailure>
+where
+    Tx: Inputs + Witnesses,
+{
+    tx.inputs()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, input)| match input {
+            Input::CoinSigned(CoinSigned { witness_index, .. })
+            | Input::MessageCoinSigned(MessageCoinSigned { witness_index, .. })
+            | Input::MessageDataSigned(MessageDataSigned { witness_index, .. })
+                if *witness_index as usize >= tx.witnesses().len() =>
+            {
+                Some(ValidationFailure::new(
+                    Some(format!("inputs[{index}].witnessIndex")),
+                    format!("The witness {witness_index} doesn't exist"),
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Checks that the maximum gas of the transaction fits into the block. The transaction
+/// may be valid on its own, but it is never included into a block if it doesn't fit.
+fn block_gas_failure<Tx>(tx: &Tx, config: &Config) -> Option<ValidationFailure>
+where
+    Tx: Chargeable,
+{
+    let max_gas =
+        TransactionFee::checked_from_tx(&config.transaction_parameters, tx)?.max_gas();
+    (max_gas > config.block_gas_limit).then(|| {
+        ValidationFailure::new(
+            Some("gasLimit".to_string()),
+            format!(
+                "The transaction may use up to {max_gas} gas, more than the block \
+                gas limit {}, so it can't be included into a block",
+                config.block_gas_limit
+            ),
+        )
+    })
+}
+
+/// Checks the gas price and that the base asset of the inputs covers the fee.
+fn fee_failures<Tx>(tx: &Tx, config: &Config) -> Vec<ValidationFailure>
+where
+    Tx: Chargeable + Inputs,
+{
+    let mut failures = vec![];
+
+    let gas_price = tx.price();
+    if gas_price < config.min_gas_price {
+        failures.push(ValidationFailure::new(
+            Some("gasPrice".to_string()),
+            format!(
+                "The gas price {gas_price} is lower than the minimal gas price {}",
+                config.min_gas_price
+            ),
+        ));
+    }
+
+    let base_amount = tx
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned {
+                asset_id, amount, ..
+            })
+            | Input::CoinPredicate(CoinPredicate {
+                asset_id, amount, ..
+            }) if *asset_id == AssetId::BASE => Some(*amount),
+            Input::MessageCoinSigned(MessageCoinSigned { amount, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { amount, .. }) => {
+                Some(*amount)
+            }
+            _ => None,
+        })
+        .fold(0 as Word, Word::saturating_add);
+
+    match TransactionFee::checked_from_tx(&config.transaction_parameters, tx) {
+        Some(fee) if fee.max_fee() > base_amount => {
+            failures.push(ValidationFailure::new(
+                Some("inputs".to_string()),
+                format!(
+                    "The inputs provide {base_amount} of the base asset, \
+                    but the fee may take up to {}",
+                    fee.max_fee()
+                ),
+            ));
+        }
+        Some(_) => {}
+        None => failures.push(ValidationFailure::new(
+            Some("gasLimit".to_string()),
+            "The fee of the transaction overflows".to_string(),
+        )),
+    }
+
+    failures
+}
@@ -18,10 +18,17 @@ pub use config::{
     VMConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
+pub use query::{
+    BlockRef,
+    QueryError,
+};
 
 pub use fuel_core_consensus_module::RelayerVerifierConfig;
 
-use self::adapters::BlockImporterAdapter;
+use self::adapters::{
+    BlockImporterAdapter,
+    BlockProducerAdapter,
+};
 
 pub mod adapters;
 pub mod config;
@@ -46,6 +53,8 @@ pub struct SharedState {
     pub database: Database,
     /// Subscribe to new block production.
     pub block_importer: BlockImporterAdapter,
+    /// The block producer, used for the dry-runs.
+    pub block_producer: BlockProducerAdapter,
     /// The config of the service.
     pub config: Config,
 }
@@ -114,6 +123,31 @@ impl FuelService {
         Ok(service)
     }
 
+    /// Returns `true` once the database is initialized and the txpool, block producer
+    /// and the rest of the sub-services are started, so the node can serve requests.
+    pub fn is_ready(&self) -> bool {
+        self.runner.state().started()
+    }
+
+    /// Waits until the node [`is_ready`](Self::is_ready).
+    ///
+    /// Returns an error if the node is stopped before it becomes ready.
+    pub async fn await_ready(&self) -> anyhow::Result<()> {
+        let mut watcher = self.runner.state_watcher();
+        loop {
+            let state = watcher.borrow_and_update().clone();
+            if state.started() {
+                return Ok(())
+            }
+            if !state.not_started() && !state.starting() {
+                return Err(anyhow::anyhow!(
+                    "The node was stopped before it became ready: {state:?}"
+                ))
+            }
+            watcher.changed().await?;
+        }
+    }
+
     #[cfg(feature = "relayer")]
     /// Wait for the [`Relayer`] to be in sync with
     /// the data availability layer.
@@ -21,11 +21,16 @@ use fuel_core_types::{
     entities::message::Message,
     fuel_tx,
     fuel_tx::Receipt,
-    fuel_types::Nonce,
+    fuel_types::{
+        ContractId,
+        Nonce,
+    },
     services::{
         block_producer::Components,
         executor::{
+            BlockSimulation,
             Result as ExecutorResult,
+            StorageSlotDelta,
             UncommittedResult,
         },
     },
@@ -66,6 +71,71 @@ impl ExecutorAdapter {
         };
         executor.dry_run(block, utxo_validation)
     }
+
+    pub(crate) fn _dry_run_many(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.dry_run_many(block, utxo_validation)
+    }
+
+    pub(crate) fn _dry_run_parallel(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.dry_run_parallel(block, utxo_validation)
+    }
+
+    pub(crate) fn _simulate_block(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<BlockSimulation> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.simulate_block(block)
+    }
+
+    pub(crate) fn _dry_run_storage_delta(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<StorageSlotDelta>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.dry_run_storage_delta(block, utxo_validation)
+    }
+
+    pub(crate) fn _dry_run_with_forced_failure(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        fail_contract: ContractId,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+        };
+        executor.dry_run_with_forced_failure(block, fail_contract, utxo_validation)
+    }
 }
 
 /// Implemented to satisfy: `GenesisCommitment for ContractRef<&'a mut Database>`
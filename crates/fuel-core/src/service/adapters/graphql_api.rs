@@ -4,6 +4,8 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::ports::{
+        BlockEvent,
+        BlockImporterPort,
         BlockProducerPort,
         DatabaseBlocks,
         DatabaseChain,
@@ -16,7 +18,10 @@ use crate::{
         DryRunExecution,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::adapters::{
+        BlockImporterAdapter,
+        TxPoolAdapter,
+    },
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::{
@@ -52,6 +57,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        Input,
         Receipt as TxReceipt,
         Transaction,
         TxPointer,
@@ -60,12 +66,19 @@ use fuel_core_types::{
     fuel_types::{
         BlockHeight,
         Nonce,
+        Word,
     },
     services::{
+        executor::{
+            BlockSimulation,
+            StorageSlotDelta,
+        },
         graphql_api::ContractBalance,
         txpool::{
+            ArcPoolTx,
             InsertionResult,
             TransactionStatus,
+            TransactionStatusRecord,
         },
     },
     tai64::Tai64,
@@ -73,6 +86,7 @@ use fuel_core_types::{
 use std::{
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 impl DatabaseBlocks for Database {
@@ -121,6 +135,21 @@ impl DatabaseTransactions for Database {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.contract_transactions(contract_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
 }
 
 impl DatabaseMessages for Database {
@@ -218,6 +247,44 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn submission_times(&self, ids: Vec<TxId>) -> Vec<Option<Tai64>> {
+        self.service
+            .find(ids)
+            .into_iter()
+            .map(|info| {
+                info.map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
+            })
+            .collect()
+    }
+
+    fn committed_submission_time(&self, id: TxId) -> Option<Duration> {
+        self.service.committed_submission_time(id)
+    }
+
+    fn status_history(&self, id: TxId) -> Vec<TransactionStatusRecord> {
+        self.service.status_history(id)
+    }
+
+    fn conflicting_transactions(&self, inputs: &[Input]) -> Vec<ArcPoolTx> {
+        self.service.find_conflicting(inputs)
+    }
+
+    fn transactions_spending_utxo(&self, utxo_id: UtxoId) -> Vec<ArcPoolTx> {
+        self.service.find_by_utxo(utxo_id)
+    }
+
+    fn pending_transactions(&self) -> Vec<ArcPoolTx> {
+        self.service.pending_transactions()
+    }
+
+    fn gas_prices(&self) -> Vec<Word> {
+        self.service.gas_prices()
+    }
+
+    fn queue_position(&self, id: TxId) -> Option<usize> {
+        self.service.queue_position(id)
+    }
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -225,7 +292,17 @@ impl TxPoolPort for TxPoolAdapter {
         self.service.insert(txs).await
     }
 
-    fn tx_update_subscribe(&self, id: TxId) -> BoxFuture<BoxStream<TxStatusMessage>> {
+    fn insert_prevalidated(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.service.insert_prevalidated(txs)
+    }
+
+    fn tx_update_subscribe(
+        &self,
+        id: TxId,
+    ) -> BoxFuture<anyhow::Result<BoxStream<TxStatusMessage>>> {
         Box::pin(self.service.tx_update_subscribe(id))
     }
 }
@@ -243,13 +320,75 @@ impl DatabaseMessageProof for Database {
 #[async_trait]
 impl DryRunExecution for BlockProducerAdapter {
     async fn dry_run_tx(
+        &self,
+        transaction: Transaction,
+        height: Option<BlockHeight>,
+        block_time: Option<Tai64>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<TxReceipt>> {
+        self.block_producer
+            .dry_run(transaction, height, block_time, utxo_validation)
+            .await
+    }
+
+    async fn dry_run_many(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Vec<TxReceipt>>> {
+        self.block_producer
+            .dry_run_many(transactions, height, utxo_validation)
+            .await
+    }
+
+    async fn dry_run_parallel(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Vec<TxReceipt>>> {
+        self.block_producer
+            .dry_run_parallel(transactions, height, utxo_validation)
+            .await
+    }
+
+    async fn simulate_block(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        gas_limit: Word,
+    ) -> anyhow::Result<BlockSimulation> {
+        self.block_producer
+            .simulate_block(transactions, height, gas_limit)
+            .await
+    }
+
+    async fn dry_run_storage_delta(
         &self,
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<StorageSlotDelta>> {
+        self.block_producer
+            .dry_run_storage_delta(transaction, height, utxo_validation)
+            .await
+    }
+
+    async fn dry_run_with_forced_failure(
+        &self,
+        transaction: Transaction,
+        fail_contract: ContractId,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<TxReceipt>> {
         self.block_producer
-            .dry_run(transaction, height, utxo_validation)
+            .dry_run_with_forced_failure(
+                transaction,
+                fail_contract,
+                height,
+                utxo_validation,
+            )
             .await
     }
 }
@@ -257,3 +396,27 @@ impl DryRunExecution for BlockProducerAdapter {
 impl BlockProducerPort for BlockProducerAdapter {}
 
 use super::BlockProducerAdapter;
+
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<BlockEvent> {
+        use tokio_stream::{
+            wrappers::{
+                errors::BroadcastStreamRecvError,
+                BroadcastStream,
+            },
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe()).map(
+                |result| match result {
+                    Ok(result) => BlockEvent::Committed(
+                        *result.sealed_block.entity.header().height(),
+                    ),
+                    Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                        BlockEvent::Lagged(skipped)
+                    }
+                },
+            ),
+        )
+    }
+}
@@ -22,19 +22,24 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
-        primitives::{self,},
+        primitives::{
+            self,
+        },
     },
     fuel_tx,
     fuel_tx::Receipt,
     fuel_types::{
         BlockHeight,
         Bytes32,
+        ContractId,
     },
     services::{
         block_producer::Components,
         executor::{
+            BlockSimulation,
             ExecutionTypes,
             Result as ExecutorResult,
+            StorageSlotDelta,
             UncommittedResult,
         },
     },
@@ -80,6 +85,46 @@ impl fuel_core_producer::ports::Executor for ExecutorAdapter {
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         self._dry_run(block, utxo_validation)
     }
+
+    fn dry_run_many(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        self._dry_run_many(block, utxo_validation)
+    }
+
+    fn dry_run_parallel(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        self._dry_run_parallel(block, utxo_validation)
+    }
+
+    fn simulate_block(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<BlockSimulation> {
+        self._simulate_block(block)
+    }
+
+    fn dry_run_storage_delta(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<StorageSlotDelta>> {
+        self._dry_run_storage_delta(block, utxo_validation)
+    }
+
+    fn dry_run_with_forced_failure(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        fail_contract: ContractId,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        self._dry_run_with_forced_failure(block, fail_contract, utxo_validation)
+    }
 }
 
 #[async_trait::async_trait]
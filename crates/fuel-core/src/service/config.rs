@@ -9,6 +9,7 @@ use fuel_core_types::{
 };
 use std::{
     net::{
+        IpAddr,
         Ipv4Addr,
         SocketAddr,
     },
@@ -60,6 +61,78 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The maximum number of submitted transactions that can wait for the `TxPool` at once.
+    /// When the queue is full, the `submit` endpoints reject new transactions with
+    /// the `NODE_BUSY` error instead of blocking.
+    pub submission_queue_depth: usize,
+    /// The maximum number of GraphQL queries one IP can make per minute.
+    /// `None` means no limit.
+    pub max_queries_per_minute: Option<u32>,
+    /// The maximum number of GraphQL mutations one IP can make per minute.
+    /// The requests above the limit are rejected with the `RATE_LIMITED` error.
+    /// `None` means no limit.
+    pub max_mutations_per_minute: Option<u32>,
+    /// The maximum number of the latest blocks the `feeStatistics` query can scan.
+    pub max_fee_statistics_blocks: u32,
+    /// Allows the `resubmit` mutation, which the operators use to restore
+    /// the pending transactions after a restart of the node.
+    pub resubmit_enabled: bool,
+    /// The maximum wall-clock time of the script execution in the `dry_run` endpoint.
+    /// When it elapses, the dry-run fails without waiting for the VM, which finishes
+    /// the execution in the background and holds the dry-run permit until then.
+    pub dry_run_timeout: Option<Duration>,
+    /// The maximum number of the `dry_run` and `dry_run_many` requests executing
+    /// at once. The requests above the limit are rejected with the `NODE_BUSY` error,
+    /// so the concurrent dry-runs don't exhaust the CPU of the node.
+    pub max_concurrent_dry_runs: usize,
+    /// The maximum total size in bytes of the serialized transactions of one page
+    /// of the transaction queries. The page is cut short once the next transaction
+    /// doesn't fit, so the large transactions don't produce huge responses.
+    /// Only the transactions are counted, not their receipts or statuses.
+    pub max_page_bytes: Option<usize>,
+    /// The maximum number of receipts returned by `receipts` of the transaction.
+    /// The transactions with more receipts are truncated and flagged by
+    /// `receipts_truncated`, so the client switches to `receipts_connection`.
+    pub max_receipts: Option<usize>,
+    /// The interval of the keep-alive comments sent over the idle subscription
+    /// streams, so the proxies that drop the idle connections keep them open.
+    pub subscription_keep_alive_interval: Duration,
+    /// Allows the `dry_run_trace` and `dry_run_coverage` mutations, which record
+    /// each instruction of the dry-run. The tracing is expensive, so it is disabled
+    /// by default.
+    pub dry_run_trace_enabled: bool,
+    /// Allows the `test_witnesses` of the `dry_run` and `predicates_valid`, that
+    /// replace the witnesses of the transaction, so the predicates verifying
+    /// the signed messages can be tested without real signatures. It is only for
+    /// the testing and debugging, and must never be enabled in production.
+    pub test_witnesses_enabled: bool,
+    /// The maximum gas one predicate can use in the `estimatePredicates` endpoints.
+    /// The estimation stops at the first predicate that exceeds it. It lowers the
+    /// `max_gas_per_predicate` of the consensus parameters to protect the public
+    /// endpoints, `None` means only the consensus limit is applied.
+    pub estimate_predicates_gas_limit: Option<u64>,
+    /// Indexes the transactions by the contracts they called during the execution of
+    /// the blocks, and allows the `transactions_by_contract` query. The index costs
+    /// the storage and the time of the block commit, so it is disabled by default.
+    /// The blocks executed before enabling it are not indexed.
+    pub contract_transactions_index_enabled: bool,
+    /// The maximum size in bytes of the transaction accepted by the GraphQL endpoints.
+    /// The larger transactions are rejected before the decoding, so a client can't
+    /// make the node parse a huge payload. `None` means the upper bound of the size
+    /// of a valid transaction derived from the consensus parameters.
+    pub max_tx_size: Option<usize>,
+    /// The time the status stream of `FuelService::transaction_status_change` waits
+    /// for the transaction to appear in the database or the `TxPool`. When it elapses,
+    /// the stream ends with an error. `None` means waiting forever.
+    pub tx_status_grace_period: Option<Duration>,
+    /// The addresses of the clients allowed to call the `submit_prevalidated` mutation,
+    /// which inserts the transactions into the `TxPool` without verifying their
+    /// signatures and predicates. Empty by default, so nobody can call it.
+    ///
+    /// The address of the client is the peer address of the connection. Behind
+    /// a reverse proxy it is the address of the proxy, so every client of the proxy
+    /// is trusted if the proxy is listed. Don't list the proxies.
+    pub trusted_submitters: Vec<IpAddr>,
 }
 
 impl Config {
@@ -103,6 +176,23 @@ impl Config {
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            submission_queue_depth: 1024,
+            max_queries_per_minute: None,
+            max_mutations_per_minute: None,
+            max_fee_statistics_blocks: 1_000,
+            resubmit_enabled: false,
+            dry_run_timeout: None,
+            max_concurrent_dry_runs: 64,
+            max_page_bytes: None,
+            max_receipts: None,
+            subscription_keep_alive_interval: Duration::from_secs(15),
+            dry_run_trace_enabled: false,
+            test_witnesses_enabled: false,
+            estimate_predicates_gas_limit: None,
+            contract_transactions_index_enabled: false,
+            max_tx_size: None,
+            tx_status_grace_period: None,
+            trusted_submitters: vec![],
         }
     }
 }
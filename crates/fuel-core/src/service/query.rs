@@ -1,48 +1,257 @@
 //! Queries we can run directly on `FuelService`.
 
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::FuelBlocks,
+    StorageAsRef,
+};
 use fuel_core_types::{
+    blockchain::{
+        header::BlockHeader,
+        primitives::BlockId,
+    },
     fuel_tx::{
+        Cacheable,
+        Receipt,
         Transaction,
+        TxPointer,
         UniqueIdentifier,
     },
-    fuel_types::Bytes32,
-    services::txpool::InsertionResult,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+    },
+    fuel_vm::checked_transaction::EstimatePredicates,
+    services::txpool::{
+        self,
+        InsertionResult,
+    },
 };
 use futures::{
     Stream,
     StreamExt,
 };
+use tokio::sync::broadcast::error::RecvError;
+use tokio_stream::wrappers::{
+    errors::BroadcastStreamRecvError,
+    BroadcastStream,
+};
 
 use crate::{
-    query::transaction_status_change,
+    graphql_api::IntoApiResult,
+    query::{
+        transaction_status_change,
+        SimpleTransactionData,
+        TransactionQueryData,
+    },
     schema::tx::types::TransactionStatus,
 };
 
 use super::*;
 
+/// The error of the submission of the transaction and of waiting for its status.
+///
+/// It converts into and from the `anyhow::Error`, so it can be propagated with `?`
+/// in both directions.
+#[derive(Debug, thiserror::Error)]
+pub enum QueryError {
+    /// The txpool returned no result for the submitted transaction.
+    #[error("Nothing was inserted")]
+    NothingInserted,
+    /// The status stream ended before the final status of the transaction.
+    #[error("Stream closed without transaction status")]
+    StreamClosed,
+    /// The txpool rejected the transaction.
+    #[error("The transaction was rejected by the txpool: {0}")]
+    SubmissionFailed(#[source] anyhow::Error),
+    /// Any other error, like the failure to read the database.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+/// Ends the status `stream` of the transaction with an error if it doesn't yield
+/// the first status within the `grace_period`.
+///
+/// The first status comes either from the database or the txpool, or from the
+/// txpool notification after the submission. Without it, the transaction is unknown
+/// to the node and will not be processed.
+fn end_if_unknown<S>(
+    stream: S,
+    id: Bytes32,
+    grace_period: Duration,
+) -> impl Stream<Item = anyhow::Result<TransactionStatus>>
+where
+    S: Stream<Item = anyhow::Result<TransactionStatus>>,
+{
+    futures::stream::once(async move {
+        let mut stream = Box::pin(stream);
+        match tokio::time::timeout(grace_period, stream.next()).await {
+            Ok(first) => futures::stream::iter(first).chain(stream).left_stream(),
+            Err(_) => futures::stream::iter(Some(Err(anyhow::anyhow!(
+                "The transaction {id} was not found within {grace_period:?} \
+                and will not be processed"
+            ))))
+            .right_stream(),
+        }
+    })
+    .flatten()
+}
+
+/// Returns the first status of the `stream` that is not `Submitted`.
+async fn first_non_submitted<S>(stream: S) -> Result<TransactionStatus, QueryError>
+where
+    S: Stream<Item = anyhow::Result<TransactionStatus>>,
+{
+    let stream = stream.filter(|status| {
+        futures::future::ready(!matches!(status, Ok(TransactionStatus::Submitted(_))))
+    });
+    futures::pin_mut!(stream);
+    let status = stream.next().await.ok_or(QueryError::StreamClosed)??;
+    Ok(status)
+}
+
+/// Returns the result of the insertion of the only transaction submitted to the txpool.
+fn single_insertion(
+    results: Vec<anyhow::Result<InsertionResult>>,
+) -> Result<InsertionResult, QueryError> {
+    results
+        .into_iter()
+        .next()
+        .ok_or(QueryError::NothingInserted)?
+        .map_err(QueryError::SubmissionFailed)
+}
+
+/// The reference to the committed block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockRef {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The id of the block.
+    pub id: BlockId,
+}
+
 impl FuelService {
     /// Submit a transaction to the txpool.
-    pub async fn submit(&self, tx: Transaction) -> anyhow::Result<InsertionResult> {
-        let results: Vec<_> = self
+    ///
+    /// Traced in the `submit` span with the `tx_id` and the `insertion_time_us`.
+    #[tracing::instrument(
+        name = "submit",
+        skip_all,
+        fields(
+            tx_id = tracing::field::Empty,
+            insertion_time_us = tracing::field::Empty,
+        )
+    )]
+    pub async fn submit(&self, tx: Transaction) -> Result<InsertionResult, QueryError> {
+        let span = tracing::Span::current();
+        let chain_id = &self
             .shared
+            .config
+            .chain_conf
+            .transaction_parameters
+            .chain_id;
+        span.record("tx_id", tracing::field::display(tx.id(chain_id)));
+        let start = Instant::now();
+        let result = self.shared.txpool.insert(vec![Arc::new(tx)]).await;
+        span.record("insertion_time_us", start.elapsed().as_micros() as u64);
+        single_insertion(result)
+    }
+
+    /// Submit a prevalidated transaction to the txpool, skipping the verification of
+    /// its signatures and predicates. It is the fast path for trusted clients that
+    /// build the transactions in-process.
+    ///
+    /// The caller must guarantee that the transaction is well-formed and valid, as
+    /// the txpool trusts it more than the transactions from the public `submit`.
+    /// An invalid transaction is only rejected later by the block production.
+    pub fn submit_prevalidated(
+        &self,
+        tx: Transaction,
+    ) -> Result<InsertionResult, QueryError> {
+        single_insertion(self.shared.txpool.insert_prevalidated(vec![Arc::new(tx)]))
+    }
+
+    /// Returns the snapshot of the transactions pending in the txpool, sorted by
+    /// the gas price, the highest first. The snapshot is taken at once, so it
+    /// doesn't mix the states of the txpool before and after an update.
+    pub fn tx_pool_contents(&self) -> Vec<Transaction> {
+        self.shared
             .txpool
-            .insert(vec![Arc::new(tx)])
-            .await
-            .into_iter()
-            .collect::<Result<_, _>>()?;
-        results
-            .into_iter()
-            .next()
-            .ok_or_else(|| anyhow::anyhow!("Nothing was inserted"))
+            .pending_transactions()
+            .iter()
+            .map(|tx| tx.as_ref().into())
+            .collect()
+    }
+
+    /// Execute a dry-run of the transaction on top of the latest block, without
+    /// committing it, and return the receipts. Unlike the `dryRun` mutation, it takes
+    /// the transaction as is, so there is no round trip through the bytes.
+    ///
+    /// The execution requires the transaction to be precomputed, so it is done here,
+    /// and the caller doesn't need to call `precompute` beforehand.
+    /// If set, `utxo_validation` overrides the configuration of the node.
+    ///
+    /// Traced in the `dry_run` span with the `tx_id` and the `vm_time_us`.
+    #[tracing::instrument(
+        name = "dry_run",
+        skip_all,
+        fields(
+            tx_id = tracing::field::Empty,
+            vm_time_us = tracing::field::Empty,
+        )
+    )]
+    pub async fn dry_run(
+        &self,
+        mut tx: Transaction,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Receipt>> {
+        let span = tracing::Span::current();
+        let chain_id = &self
+            .shared
+            .config
+            .chain_conf
+            .transaction_parameters
+            .chain_id;
+        tx.precompute(chain_id)?;
+        span.record("tx_id", tracing::field::display(tx.id(chain_id)));
+        let start = Instant::now();
+        let receipts = self
+            .shared
+            .block_producer
+            .block_producer
+            .dry_run(tx, None, None, utxo_validation)
+            .await;
+        span.record("vm_time_us", start.elapsed().as_micros() as u64);
+        receipts
+    }
+
+    /// Estimate the `predicate_gas_used` of the predicate inputs of the transaction
+    /// and set them in place, like the `estimatePredicates` mutation. It uses
+    /// the consensus parameters and the gas costs of the chain of the node, but not
+    /// the lower `estimate_predicates_gas_limit` meant for the public endpoints.
+    pub fn estimate_predicates(&self, tx: &mut Transaction) -> anyhow::Result<()> {
+        let chain_conf = &self.shared.config.chain_conf;
+        tx.estimate_predicates(
+            &chain_conf.transaction_parameters,
+            &chain_conf.gas_costs,
+        )?;
+        Ok(())
     }
 
     /// Submit a transaction to the txpool and return a stream of status changes.
     pub async fn submit_and_status_change(
         &self,
         tx: Transaction,
-    ) -> anyhow::Result<impl Stream<Item = anyhow::Result<TransactionStatus>>> {
+    ) -> Result<impl Stream<Item = anyhow::Result<TransactionStatus>>, QueryError> {
         let id = tx.id(&self
             .shared
             .config
@@ -58,33 +267,133 @@ impl FuelService {
     pub async fn submit_and_await_commit(
         &self,
         tx: Transaction,
-    ) -> anyhow::Result<TransactionStatus> {
+    ) -> Result<TransactionStatus, QueryError> {
         let id = tx.id(&self
             .shared
             .config
             .chain_conf
             .transaction_parameters
             .chain_id);
-        let stream = self.transaction_status_change(id).await.filter(|status| {
-            futures::future::ready(!matches!(status, Ok(TransactionStatus::Submitted(_))))
-        });
-        futures::pin_mut!(stream);
+        let stream = self.transaction_status_change(id).await;
         self.submit(tx).await?;
-        stream
-            .next()
-            .await
-            .ok_or_else(|| anyhow::anyhow!("Stream closed without transaction status"))?
+        first_non_submitted(stream).await
+    }
+
+    /// Wait for the final status of the transaction with the `id`, without
+    /// submitting it. The transaction can be submitted by any other path, before
+    /// or after the call.
+    ///
+    /// Returns immediately if the transaction is already committed or squeezed out.
+    /// If the `tx_status_grace_period` is configured, the transaction must become
+    /// known to the node within it.
+    pub async fn await_transaction_committed(
+        &self,
+        id: Bytes32,
+    ) -> Result<TransactionStatus, QueryError> {
+        let stream = self.transaction_status_change(id).await;
+        first_non_submitted(stream).await
+    }
+
+    /// Submit a transaction to the txpool and wait until the block that includes it
+    /// is buried under `confirmations` blocks. Zero confirmations means the inclusion.
+    ///
+    /// The block importer only appends the next block and never reverts the committed
+    /// ones, so the inclusion block stays canonical while the count grows.
+    /// The transactions that are not included, like squeezed out ones,
+    /// return the status immediately.
+    pub async fn submit_and_await_confirmations(
+        &self,
+        tx: Transaction,
+        confirmations: u32,
+    ) -> Result<TransactionStatus, QueryError> {
+        let id = tx.id(&self
+            .shared
+            .config
+            .chain_conf
+            .transaction_parameters
+            .chain_id);
+        // Subscribe before the submission to not miss the blocks imported in between.
+        let mut blocks = self.shared.block_importer.block_importer.subscribe();
+        let status = self.submit_and_await_commit(tx).await?;
+        if !matches!(
+            status,
+            TransactionStatus::Success(_) | TransactionStatus::Failed(_)
+        ) {
+            return Ok(status)
+        }
+
+        loop {
+            if let Some(status) = self.confirmed_status(&id, confirmations)? {
+                return Ok(status)
+            }
+
+            match blocks.recv().await {
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => {
+                    return Err(anyhow::anyhow!(
+                        "The block importer stopped before the transaction was confirmed"
+                    )
+                    .into())
+                }
+            }
+        }
+    }
+
+    /// Returns the status of the transaction if its inclusion block has at least
+    /// `confirmations` blocks on top of it.
+    fn confirmed_status(
+        &self,
+        id: &Bytes32,
+        confirmations: u32,
+    ) -> anyhow::Result<Option<TransactionStatus>> {
+        let database = &self.shared.database;
+        let status = database.get_tx_status(id)?;
+        let block_id = match &status {
+            Some(txpool::TransactionStatus::Success { block_id, .. })
+            | Some(txpool::TransactionStatus::Failed { block_id, .. }) => *block_id,
+            _ => return Ok(None),
+        };
+        let inclusion_height = match database.storage::<FuelBlocks>().get(&block_id)? {
+            Some(block) => *block.header().height(),
+            None => return Ok(None),
+        };
+        // It can't happen today, because the importer never replaces the committed
+        // blocks. The check keeps the count honest if the reorgs are supported later.
+        if database.get_block_id(&inclusion_height)? != Some(block_id) {
+            return Ok(None)
+        }
+
+        let latest_height = database.latest_height()?;
+        let buried_under = (*latest_height).saturating_sub(*inclusion_height);
+        if buried_under >= confirmations {
+            Ok(status.map(Into::into))
+        } else {
+            Ok(None)
+        }
     }
 
     /// Return a stream of status changes for a transaction.
+    ///
+    /// If the `tx_status_grace_period` is configured and the transaction doesn't
+    /// appear in the database or the txpool within it, the stream ends with an error,
+    /// as the transaction will not be processed.
+    ///
+    /// If the txpool rejects the subscription because there are too many of them,
+    /// the stream yields only the error.
     pub async fn transaction_status_change(
         &self,
         id: Bytes32,
     ) -> impl Stream<Item = anyhow::Result<TransactionStatus>> {
         let txpool = self.shared.txpool.clone();
         let db = self.shared.database.clone();
-        let rx = Box::pin(txpool.tx_update_subscribe(id).await);
-        transaction_status_change(
+        let rx = match txpool.tx_update_subscribe(id).await {
+            Ok(rx) => Box::pin(rx),
+            Err(err) => {
+                return futures::stream::once(futures::future::ready(Err(err)))
+                    .left_stream()
+            }
+        };
+        let stream = transaction_status_change(
             move |id| match db.get_tx_status(&id)? {
                 Some(status) => Ok(Some(status)),
                 None => Ok(txpool.find_one(id).map(Into::into)),
@@ -92,6 +401,95 @@ impl FuelService {
             rx,
             id,
         )
-        .await
+        .await;
+        let stream = match self.shared.config.tx_status_grace_period {
+            Some(grace_period) => end_if_unknown(stream, id, grace_period).left_stream(),
+            None => stream.right_stream(),
+        };
+        stream.right_stream()
+    }
+
+    /// Returns the committed transaction with the `id`, or `None` if it is unknown.
+    ///
+    /// The pending transactions from the txpool are not returned.
+    pub fn transaction(&self, id: Bytes32) -> anyhow::Result<Option<Transaction>> {
+        SimpleTransactionData::transaction(&self.shared.database, &id).into_api_result()
+    }
+
+    /// Return a stream of the committed transactions owned by the `owner`, together
+    /// with their pointers, starting from the `start` pointer in the `direction`.
+    ///
+    /// The stream reads the database lazily, so the transactions committed while
+    /// it is consumed may or may not be included.
+    pub fn transactions(
+        &self,
+        owner: Address,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = anyhow::Result<(TxPointer, Transaction)>> + '_ {
+        let txs = TransactionQueryData::owned_transactions(
+            &self.shared.database,
+            owner,
+            start,
+            direction,
+        )
+        .map(|result| result.map_err(Into::into));
+        futures::stream::iter(txs)
+    }
+
+    /// Waits until the block at the `height` is committed and returns the reference
+    /// to it.
+    ///
+    /// Returns immediately if the `height` is already reached.
+    pub async fn await_block_height(&self, height: u32) -> anyhow::Result<BlockRef> {
+        let height: BlockHeight = height.into();
+        // Subscribe before checking the database to not miss the block
+        // imported in between.
+        let mut blocks = self.shared.block_importer.block_importer.subscribe();
+        loop {
+            if let Some(id) = self.shared.database.get_block_id(&height)? {
+                return Ok(BlockRef { height, id })
+            }
+
+            match blocks.recv().await {
+                Ok(result) => {
+                    let block = &result.sealed_block.entity;
+                    if *block.header().height() == height {
+                        return Ok(BlockRef {
+                            height,
+                            id: block.id(),
+                        })
+                    }
+                }
+                // Some notifications were skipped, the database is checked again.
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => {
+                    return Err(anyhow::anyhow!(
+                        "The block importer stopped before the height {}",
+                        *height
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Returns a stream of the headers of the newly committed blocks, in the order
+    /// of the import. Only the blocks committed after the call are yielded.
+    ///
+    /// The notifications are buffered up to the `max_block_notify_buffer` of
+    /// the block importer, which never waits for the subscribers. If the stream
+    /// isn't consumed fast enough, the oldest headers are dropped, and the stream
+    /// yields an error with the number of the skipped blocks before the next header.
+    pub fn subscribe_new_blocks(
+        &self,
+    ) -> impl Stream<Item = anyhow::Result<BlockHeader>> {
+        BroadcastStream::new(self.shared.block_importer.block_importer.subscribe()).map(
+            |result| match result {
+                Ok(result) => Ok(result.sealed_block.entity.header().clone()),
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => Err(anyhow::anyhow!(
+                    "The subscriber lagged behind and skipped {skipped} blocks"
+                )),
+            },
+        )
     }
 }
@@ -3,7 +3,10 @@ use super::adapters::P2PAdapter;
 
 use crate::{
     database::Database,
-    fuel_core_graphql_api::Config as GraphQLConfig,
+    fuel_core_graphql_api::{
+        max_transaction_size,
+        Config as GraphQLConfig,
+    },
     schema::build_schema,
     service::{
         adapters::{
@@ -71,6 +74,7 @@ pub fn init_sub_services(
             gas_costs: config.chain_conf.gas_costs.clone(),
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
+            index_contract_transactions: config.contract_transactions_index_enabled,
         }),
     };
 
@@ -178,6 +182,26 @@ pub fn init_sub_services(
             min_gas_price: config.txpool.min_gas_price,
             max_tx: config.txpool.max_tx,
             max_depth: config.txpool.max_depth,
+            submission_queue_depth: config.submission_queue_depth,
+            max_queries_per_minute: config.max_queries_per_minute,
+            max_mutations_per_minute: config.max_mutations_per_minute,
+            max_fee_statistics_blocks: config.max_fee_statistics_blocks,
+            resubmit_enabled: config.resubmit_enabled,
+            dry_run_timeout: config.dry_run_timeout,
+            max_concurrent_dry_runs: config.max_concurrent_dry_runs,
+            max_page_bytes: config.max_page_bytes,
+            max_receipts: config.max_receipts,
+            subscription_keep_alive_interval: config.subscription_keep_alive_interval,
+            dry_run_trace_enabled: config.dry_run_trace_enabled,
+            test_witnesses_enabled: config.test_witnesses_enabled,
+            estimate_predicates_gas_limit: config.estimate_predicates_gas_limit,
+            contract_transactions_index_enabled: config
+                .contract_transactions_index_enabled,
+            max_tx_size: config.max_tx_size.unwrap_or_else(|| {
+                max_transaction_size(&config.chain_conf.transaction_parameters)
+            }),
+            trusted_submitters: config.trusted_submitters.clone(),
+            block_gas_limit: config.chain_conf.block_gas_limit,
             transaction_parameters: config.chain_conf.transaction_parameters,
             gas_costs: config.chain_conf.gas_costs.clone(),
             consensus_key: config.consensus_key.clone(),
@@ -185,8 +209,9 @@ pub fn init_sub_services(
         schema,
         Box::new(database.clone()),
         Box::new(tx_pool_adapter),
-        Box::new(producer_adapter),
+        Arc::new(producer_adapter.clone()),
         Box::new(poa_adapter),
+        Box::new(importer_adapter.clone()),
         config.query_log_threshold_time,
     )?;
 
@@ -199,6 +224,7 @@ pub fn init_sub_services(
         graph_ql: graph_ql.shared.clone(),
         database: database.clone(),
         block_importer: importer_adapter,
+        block_producer: producer_adapter,
         config: config.clone(),
     };
 
@@ -66,6 +66,16 @@ impl MemoryTransactionView {
 
         self.data_source.batch_write(&mut iter)
     }
+
+    /// Returns the keys of the `column` modified or removed within the transaction.
+    pub fn changed_keys(&self, column: Column) -> Vec<Vec<u8>> {
+        self.changes[column.as_usize()]
+            .lock()
+            .expect("poisoned lock")
+            .keys()
+            .cloned()
+            .collect()
+    }
 }
 
 impl KeyValueStore for MemoryTransactionView {
@@ -118,6 +118,7 @@ impl RocksDb {
         match column {
             Column::OwnedCoins
             | Column::TransactionsByOwnerBlockIdx
+            | Column::TransactionsByContractBlockIdx
             | Column::OwnedMessageIds
             | Column::ContractsAssets
             | Column::ContractsState => {
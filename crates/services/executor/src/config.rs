@@ -18,4 +18,6 @@ pub struct Config {
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// Index the transactions by the contracts they called.
+    pub index_contract_transactions: bool,
 }
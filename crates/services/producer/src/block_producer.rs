@@ -25,10 +25,15 @@ use fuel_core_types::{
     fuel_types::{
         BlockHeight,
         Bytes32,
+        ContractId,
     },
     services::{
         block_producer::Components,
-        executor::UncommittedResult,
+        executor::{
+            BlockSimulation,
+            StorageSlotDelta,
+            UncommittedResult,
+        },
     },
     tai64::Tai64,
 };
@@ -53,6 +58,11 @@ pub enum Error {
         best: DaBlockHeight,
         previous_block: DaBlockHeight,
     },
+    #[error("Only the next block height {next} can be simulated, got {requested}")]
+    InvalidSimulationHeight {
+        requested: BlockHeight,
+        next: BlockHeight,
+    },
 }
 
 pub struct Producer<Database, TxPool, Executor> {
@@ -113,45 +123,140 @@ where
         Ok(result)
     }
 
-    // TODO: Support custom `block_time` for `dry_run`.
     /// Simulate a transaction without altering any state. Does not aquire the production lock
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
     /// production.
+    ///
+    /// The `block_time` is the time of the simulated block seen by the VM,
+    /// the current time is used if it is not specified.
     pub async fn dry_run(
         &self,
         transaction: Transaction,
         height: Option<BlockHeight>,
+        block_time: Option<Tai64>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<Receipt>> {
-        let height = match height {
-            None => self.db.current_block_height()?,
-            Some(height) => height,
-        } + 1.into();
-
         let is_script = transaction.is_script();
-        // The dry run execution should use the state of the blockchain based on the
-        // last available block, not on the upcoming one. It means that we need to
-        // use the same configuration as the last block -> the same DA height.
-        // It is deterministic from the result perspective, plus it is more performant
-        // because we don't need to wait for the relayer to sync.
-        let header = self._new_header(height, Tai64::now())?;
-        let gas_limit = match &transaction {
-            Transaction::Script(script) => *script.gas_limit(),
-            Transaction::Create(create) => *create.gas_limit(),
-            Transaction::Mint(_) => 0,
+        let component = self.dry_run_component(transaction, height, block_time)?;
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let res: Vec<_> =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Receipt>> {
+                Ok(executor
+                    .dry_run(component, utxo_validation)?
+                    .into_iter()
+                    .flatten()
+                    .collect())
+            })
+            .await?;
+        if is_script && res.is_empty() {
+            return Err(anyhow!("Expected at least one set of receipts"))
+        }
+        Ok(res)
+    }
+
+    /// Simulates the `transactions` one after another in the given order, as if they
+    /// were included into the same block. Each transaction sees the state changes
+    /// of the previous ones. Returns the receipts of each transaction in the same order.
+    pub async fn dry_run_many(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Vec<Receipt>>> {
+        let gas_limit = transactions
+            .iter()
+            .map(transaction_gas_limit)
+            .fold(0u64, u64::saturating_add);
+        let component = Components {
+            header_to_produce: self.dry_run_header(height, None)?,
+            transactions_source: transactions,
+            gas_limit,
         };
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let res =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Vec<Receipt>>> {
+                Ok(executor.dry_run_many(component, utxo_validation)?)
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Simulates each of the `transactions` independently on top of the same state
+    /// and in the same block context. No transaction sees the state changes of
+    /// the other ones. Returns the receipts of each transaction in the same order.
+    pub async fn dry_run_parallel(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Vec<Receipt>>> {
+        let gas_limit = transactions
+            .iter()
+            .map(transaction_gas_limit)
+            .max()
+            .unwrap_or_default();
         let component = Components {
-            header_to_produce: header,
-            transactions_source: transaction,
+            header_to_produce: self.dry_run_header(height, None)?,
+            transactions_source: transactions,
             gas_limit,
         };
 
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let res =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Vec<Receipt>>> {
+                Ok(executor.dry_run_parallel(component, utxo_validation)?)
+            })
+            .await?;
+        Ok(res)
+    }
+
+    /// Simulates the transaction the same way as `dry_run`, but returns the changes
+    /// of the contract storage slots along with their values before the execution.
+    pub async fn dry_run_storage_delta(
+        &self,
+        transaction: Transaction,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<StorageSlotDelta>> {
+        let component = self.dry_run_component(transaction, height, None)?;
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let deltas =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<StorageSlotDelta>> {
+                Ok(executor.dry_run_storage_delta(component, utxo_validation)?)
+            })
+            .await?;
+        Ok(deltas)
+    }
+
+    /// Simulates the transaction the same way as `dry_run`, but any call
+    /// to the `fail_contract` reverts.
+    pub async fn dry_run_with_forced_failure(
+        &self,
+        transaction: Transaction,
+        fail_contract: ContractId,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<Receipt>> {
+        let is_script = transaction.is_script();
+        let component = self.dry_run_component(transaction, height, None)?;
+
         let executor = self.executor.clone();
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
         let res: Vec<_> =
             tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Receipt>> {
                 Ok(executor
-                    .dry_run(component, utxo_validation)?
+                    .dry_run_with_forced_failure(
+                        component,
+                        fail_contract,
+                        utxo_validation,
+                    )?
                     .into_iter()
                     .flatten()
                     .collect())
@@ -162,6 +267,40 @@ where
         }
         Ok(res)
     }
+
+    /// Simulates the block made of the `transactions` at the next block height
+    /// without committing it. The `height`, if set, must be the next block height,
+    /// because the block is executed on top of the current state.
+    ///
+    /// The block is executed in the production mode, the transactions are taken
+    /// in the given order while they fit into the `gas_limit`.
+    pub async fn simulate_block(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        gas_limit: Word,
+    ) -> anyhow::Result<BlockSimulation> {
+        let header_to_produce = self.dry_run_header(None, None)?;
+        let next = *header_to_produce.height();
+        if let Some(requested) = height {
+            if requested != next {
+                return Err(Error::InvalidSimulationHeight { requested, next }.into())
+            }
+        }
+        let component = Components {
+            header_to_produce,
+            transactions_source: transactions,
+            gas_limit,
+        };
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let res = tokio_rayon::spawn_fifo(move || -> anyhow::Result<BlockSimulation> {
+            Ok(executor.simulate_block(component)?)
+        })
+        .await?;
+        Ok(res)
+    }
 }
 
 impl<Database, TxPool, Executor> Producer<Database, TxPool, Executor>
@@ -199,6 +338,42 @@ where
         Ok(best_height)
     }
 
+    /// Creates the components of the block for the dry run of the `transaction`.
+    fn dry_run_component(
+        &self,
+        transaction: Transaction,
+        height: Option<BlockHeight>,
+        block_time: Option<Tai64>,
+    ) -> anyhow::Result<Components<Transaction>> {
+        let gas_limit = transaction_gas_limit(&transaction);
+        Ok(Components {
+            header_to_produce: self.dry_run_header(height, block_time)?,
+            transactions_source: transaction,
+            gas_limit,
+        })
+    }
+
+    /// Creates the header of the block for the dry run on top of the `height`,
+    /// or on top of the latest block if it is not specified. The time of the block
+    /// is the `block_time`, or the current time if it is not specified.
+    fn dry_run_header(
+        &self,
+        height: Option<BlockHeight>,
+        block_time: Option<Tai64>,
+    ) -> anyhow::Result<PartialBlockHeader> {
+        let height = match height {
+            None => self.db.current_block_height()?,
+            Some(height) => height,
+        } + 1.into();
+
+        // The dry run execution should use the state of the blockchain based on the
+        // last available block, not on the upcoming one. It means that we need to
+        // use the same configuration as the last block -> the same DA height.
+        // It is deterministic from the result perspective, plus it is more performant
+        // because we don't need to wait for the relayer to sync.
+        self._new_header(height, block_time.unwrap_or_else(Tai64::now))
+    }
+
     fn _new_header(
         &self,
         height: BlockHeight,
@@ -248,3 +423,11 @@ struct PreviousBlockInfo {
     prev_root: Bytes32,
     da_height: DaBlockHeight,
 }
+
+fn transaction_gas_limit(transaction: &Transaction) -> u64 {
+    match transaction {
+        Transaction::Script(script) => *script.gas_limit(),
+        Transaction::Create(create) => *create.gas_limit(),
+        Transaction::Mint(_) => 0,
+    }
+}
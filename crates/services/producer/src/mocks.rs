@@ -29,13 +29,16 @@ use fuel_core_types::{
         Address,
         BlockHeight,
         Bytes32,
+        ContractId,
     },
     services::{
         block_producer::Components,
         executor::{
+            BlockSimulation,
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StorageSlotDelta,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -167,6 +170,47 @@ impl Executor for MockExecutor {
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         Ok(Default::default())
     }
+    fn dry_run_many(
+        &self,
+        _block: Components<Vec<fuel_tx::Transaction>>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        Ok(Default::default())
+    }
+    fn dry_run_parallel(
+        &self,
+        _block: Components<Vec<fuel_tx::Transaction>>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        Ok(Default::default())
+    }
+    fn simulate_block(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<BlockSimulation> {
+        Ok(BlockSimulation {
+            height: *block.header_to_produce.height(),
+            gas_used: 0,
+            fees: 0,
+            transactions: vec![],
+        })
+    }
+
+    fn dry_run_storage_delta(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<StorageSlotDelta>> {
+        Ok(Default::default())
+    }
+    fn dry_run_with_forced_failure(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _fail_contract: ContractId,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        Ok(Default::default())
+    }
 }
 
 pub struct FailingMockExecutor(pub Mutex<Option<ExecutorError>>);
@@ -209,6 +253,72 @@ impl Executor for FailingMockExecutor {
             Ok(Default::default())
         }
     }
+    fn dry_run_many(
+        &self,
+        _block: Components<Vec<fuel_tx::Transaction>>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
+    fn dry_run_parallel(
+        &self,
+        _block: Components<Vec<fuel_tx::Transaction>>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
+    fn simulate_block(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+    ) -> ExecutorResult<BlockSimulation> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(BlockSimulation {
+                height: *block.header_to_produce.height(),
+                gas_used: 0,
+                fees: 0,
+                transactions: vec![],
+            })
+        }
+    }
+
+    fn dry_run_storage_delta(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<StorageSlotDelta>> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
+    fn dry_run_with_forced_failure(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _fail_contract: ContractId,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -13,11 +13,16 @@ use fuel_core_types::{
         Receipt,
         Transaction,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
     services::{
         block_producer::Components,
         executor::{
+            BlockSimulation,
             Result as ExecutorResult,
+            StorageSlotDelta,
             UncommittedResult,
         },
     },
@@ -79,4 +84,45 @@ pub trait Executor: Send + Sync {
         block: Components<Transaction>,
         utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+
+    /// Executes the transactions of the block the same way as `dry_run`, one after
+    /// another in the given order. Returns the receipts of each transaction.
+    fn dry_run_many(
+        &self,
+        block: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+
+    /// Executes each transaction the same way as `dry_run`, on its own copy of
+    /// the same state, so the transactions don't see the changes of each other.
+    /// Returns the receipts of each transaction.
+    fn dry_run_parallel(
+        &self,
+        block: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+
+    /// Executes the transactions as a block in the production mode without committing
+    /// it. The transactions that don't fit into the gas limit of the block are excluded.
+    fn simulate_block(
+        &self,
+        block: Components<Vec<Transaction>>,
+    ) -> ExecutorResult<BlockSimulation>;
+
+    /// Executes the block the same way as `dry_run`. Returns the changes of the contract
+    /// storage slots made during execution along with their values before it.
+    fn dry_run_storage_delta(
+        &self,
+        block: Components<Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<StorageSlotDelta>>;
+
+    /// Executes the block the same way as `dry_run`, but any call to the `fail_contract`
+    /// reverts during execution.
+    fn dry_run_with_forced_failure(
+        &self,
+        block: Components<Transaction>,
+        fail_contract: ContractId,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<Vec<Receipt>>>;
 }
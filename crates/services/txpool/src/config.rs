@@ -19,6 +19,14 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// The subscriptions without status updates for longer than this are idle. When
+    /// all subscriptions are taken, an idle one is evicted for the new subscription,
+    /// preferring the transactions unknown to the pool, then the least recently
+    /// updated. Otherwise the new subscription is rejected.
+    pub subscription_idle_timeout: Duration,
+    /// The maximum number of the prevalidated transactions inside the pool. They skip
+    /// the verification of the signatures and predicates, so they can't take all slots.
+    pub max_prevalidated_tx: usize,
 }
 
 impl Default for Config {
@@ -31,6 +39,8 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let subscription_idle_timeout = Duration::from_secs(60);
+        let max_prevalidated_tx = max_tx / 4;
         Self::new(
             max_tx,
             max_depth,
@@ -40,6 +50,8 @@ impl Default for Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            subscription_idle_timeout,
+            max_prevalidated_tx,
         )
     }
 }
@@ -55,6 +67,8 @@ impl Config {
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        subscription_idle_timeout: Duration,
+        max_prevalidated_tx: usize,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -67,6 +81,8 @@ impl Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            subscription_idle_timeout,
+            max_prevalidated_tx,
         }
     }
 }
@@ -106,6 +106,30 @@ impl Dependency {
         }
     }
 
+    /// Returns the transactions inside txpool that spend any of the coins or messages
+    /// of the `inputs`.
+    pub fn find_spenders(&self, inputs: &[Input]) -> Vec<TxId> {
+        let mut spenders: Vec<TxId> = inputs
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                    self.coins.get(utxo_id).and_then(|state| state.is_spend_by)
+                }
+                Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+                | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                    self.messages.get(nonce).map(|state| state.spent_by)
+                }
+                Input::Contract(_) => None,
+            })
+            .collect();
+        spenders.sort();
+        spenders.dedup();
+        spenders
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -561,8 +585,13 @@ impl Dependency {
             self.check_for_collision(txs, db, tx)?;
 
         // now we are sure that transaction can be included. remove all collided transactions
-        let mut removed_tx = Vec::new();
+        let mut removed_tx: Vec<ArcPoolTx> = Vec::new();
         for collided in collided.into_iter() {
+            // The collided transaction depends on another collided one and is
+            // already removed with it.
+            if removed_tx.iter().any(|removed| removed.id() == collided) {
+                continue
+            }
             let collided = txs
                 .get(&collided)
                 .expect("Collided should be present in txpool");
@@ -6,9 +6,11 @@ use crate::{
     },
     transaction_selector::select_transactions,
     txpool::{
+        check_prevalidated_tx,
         check_single_tx,
         check_transactions,
     },
+    types::GasPrice,
     Config,
     Error as TxPoolError,
     TxInfo,
@@ -25,14 +27,17 @@ use fuel_core_services::{
 use fuel_core_types::{
     fuel_tx::{
         ConsensusParameters,
+        Input,
         Transaction,
         TxId,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::{
         BlockHeight,
         Bytes32,
     },
+    fuel_vm::checked_transaction::Checked,
     services::{
         block_importer::ImportResult,
         p2p::{
@@ -46,13 +51,18 @@ use fuel_core_types::{
             Error,
             InsertionResult,
             TransactionStatus,
+            TransactionStatusRecord,
         },
     },
     tai64::Tai64,
 };
 
 use parking_lot::Mutex as ParkingMutex;
-use std::sync::Arc;
+use std::{
+    ops::Deref,
+    sync::Arc,
+    time::Duration,
+};
 use tokio::{
     sync::broadcast,
     time::MissedTickBehavior,
@@ -76,9 +86,9 @@ pub struct TxStatusChange {
 }
 
 impl TxStatusChange {
-    pub fn new(capacity: usize) -> Self {
+    pub fn new(capacity: usize, subscription_idle_timeout: Duration) -> Self {
         let (new_tx_notification_sender, _) = broadcast::channel(capacity);
-        let update_sender = UpdateSender::new(capacity);
+        let update_sender = UpdateSender::new(capacity, subscription_idle_timeout);
         Self {
             new_tx_notification_sender,
             update_sender,
@@ -187,10 +197,13 @@ where
             }
 
             _ = self.ttl_timer.tick() => {
-                let removed = self.shared.txpool.lock().prune_old_txs();
+                let mut txpool = self.shared.txpool.lock();
+                let removed = txpool.prune_old_txs();
                 for tx in removed {
                     self.shared.tx_status_sender.send_squeezed_out(tx.id(), Error::TTLReason);
+                    txpool.record_squeezed_out(tx.id(), Error::TTLReason);
                 }
+                drop(txpool);
 
                 should_continue = true
             }
@@ -291,6 +304,14 @@ where
         self.txpool.lock().consumable_gas()
     }
 
+    pub fn gas_prices(&self) -> Vec<GasPrice> {
+        self.txpool.lock().gas_prices()
+    }
+
+    pub fn queue_position(&self, id: TxId) -> Option<usize> {
+        self.txpool.lock().queue_position(&id)
+    }
+
     pub fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
@@ -307,6 +328,28 @@ where
         self.txpool.lock().find_dependent(&ids)
     }
 
+    pub fn find_conflicting(&self, inputs: &[Input]) -> Vec<ArcPoolTx> {
+        self.txpool.lock().find_conflicting(inputs)
+    }
+
+    pub fn find_by_utxo(&self, utxo_id: UtxoId) -> Vec<ArcPoolTx> {
+        self.txpool.lock().find_by_utxo(&utxo_id)
+    }
+
+    pub fn committed_submission_time(&self, id: TxId) -> Option<Duration> {
+        self.txpool.lock().committed_submission_time(&id)
+    }
+
+    pub fn status_history(&self, id: TxId) -> Vec<TransactionStatusRecord> {
+        self.txpool.lock().status_history(&id)
+    }
+
+    /// Returns all pending transactions sorted by the gas price, the highest first.
+    /// They are taken under one lock, so the result is a consistent snapshot.
+    pub fn pending_transactions(&self) -> Vec<ArcPoolTx> {
+        self.txpool.lock().sorted_includable().collect()
+    }
+
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
         let txs = guard.includable();
@@ -326,11 +369,19 @@ where
         self.tx_status_sender.new_tx_notification_sender.subscribe()
     }
 
-    pub async fn tx_update_subscribe(&self, tx_id: Bytes32) -> TxStatusStream {
+    /// Subscribes to the status updates of the transaction. Fails if all subscriptions
+    /// are taken and none of them is idle.
+    pub async fn tx_update_subscribe(
+        &self,
+        tx_id: Bytes32,
+    ) -> anyhow::Result<TxStatusStream> {
+        // The `TxPool` is locked before the subscribers, in the same order as
+        // during the insertion, which sends the updates under the `TxPool` lock.
+        let txpool = self.txpool.lock();
         self.tx_status_sender
             .update_sender
-            .subscribe::<MpscChannel>(tx_id)
-            .await
+            .subscribe::<MpscChannel>(tx_id, |tx_id| txpool.txs().contains_key(tx_id))
+            .map_err(Into::into)
     }
 }
 
@@ -352,7 +403,42 @@ where
         };
 
         let checked_txs = check_transactions(&txs, current_height, &self.config).await;
+        self.insert_checked(txs, checked_txs, false)
+    }
 
+    /// Inserts the `txs` without verifying their signatures and predicates. It is
+    /// the fast path for the trusted callers that have already validated the `txs`.
+    ///
+    /// The `txs` aren't gossiped, because the peers penalize the node for
+    /// the invalid transactions, and this node hasn't verified them. Only
+    /// the `max_prevalidated_tx` of them can be inside the pool at the same time.
+    pub fn insert_prevalidated(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        let block_height = self.db.current_block_height();
+        let current_height = match block_height {
+            Ok(val) => val,
+            Err(e) => return vec![Err(e.into())],
+        };
+
+        let checked_txs = txs
+            .iter()
+            .map(|tx| {
+                check_prevalidated_tx(tx.deref().clone(), current_height, &self.config)
+            })
+            .collect();
+        self.insert_checked(txs, checked_txs, true)
+    }
+
+    /// Inserts the checked transactions into the pool, and gossips the inserted
+    /// ones to the peers unless they are `prevalidated`.
+    fn insert_checked(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        checked_txs: Vec<anyhow::Result<Checked<Transaction>>>,
+        prevalidated: bool,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
         let mut valid_txs = vec![];
 
         let checked_txs: Vec<_> = checked_txs
@@ -367,11 +453,18 @@ where
             .collect();
 
         // insert txs
-        let insertion = { self.txpool.lock().insert(&self.tx_status_sender, valid_txs) };
+        let insertion = {
+            let mut txpool = self.txpool.lock();
+            if prevalidated {
+                txpool.insert_prevalidated(&self.tx_status_sender, valid_txs)
+            } else {
+                txpool.insert(&self.tx_status_sender, valid_txs)
+            }
+        };
 
         for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
             match ret {
-                Ok(_) => {
+                Ok(_) if !prevalidated => {
                     let result = self.p2p.broadcast_transaction(tx.clone());
                     if let Err(e) = result {
                         // It can be only in the case of p2p being down or requests overloading it.
@@ -381,7 +474,7 @@ where
                         );
                     }
                 }
-                Err(_) => {}
+                _ => {}
             }
         }
 
@@ -425,6 +518,8 @@ impl TxUpdate {
 pub enum TxStatusMessage {
     Status(TransactionStatus),
     FailedStatus,
+    /// The subscription was evicted to free its permit for a new subscriber.
+    Evicted,
 }
 
 pub fn new_service<P2P, Importer, DB>(
@@ -445,12 +540,16 @@ where
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
     let consensus_params = config.chain_config.transaction_parameters;
     let number_of_active_subscription = config.number_of_active_subscription;
+    let subscription_idle_timeout = config.subscription_idle_timeout;
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config.clone(), db.clone())));
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
         shared: SharedState {
-            tx_status_sender: TxStatusChange::new(number_of_active_subscription),
+            tx_status_sender: TxStatusChange::new(
+                number_of_active_subscription,
+                subscription_idle_timeout,
+            ),
             txpool,
             p2p,
             consensus_params,
@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
-    future::Future,
     pin::Pin,
+    time::Instant,
 };
 
 use super::*;
@@ -33,13 +33,19 @@ const BUFFER_SIZE: usize = 2;
 /// UpdateSender is responsible for managing subscribers
 /// and sending transaction status updates to them.
 ///
-/// Subscribers are added only once a permit is available.
+/// Subscribers are added only once a permit is available. If all permits are
+/// taken, an idle subscriber is evicted to free its permit.
 #[derive(Debug)]
 pub struct UpdateSender {
     /// Map of senders, indexed by transaction hash.
     senders: Arc<Mutex<SenderMap<Permit, Tx>>>,
     /// Semaphore used to limit the number of concurrent subscribers.
     permits: GetPermit,
+    /// The number of permits, reported when the subscription is rejected.
+    capacity: usize,
+    /// The subscribers without updates for longer than this are idle,
+    /// and may be evicted when all permits are taken.
+    idle_timeout: Duration,
 }
 
 /// Error returned when a transaction status update cannot be sent.
@@ -78,6 +84,8 @@ struct Sender<P = OwnedSemaphorePermit, Tx = mpsc::Sender<TxStatusMessage>> {
     stream: TxUpdateStream,
     /// The sending end of the subscriber channel.
     tx: Tx,
+    /// The time of the subscription or of the last update sent to it.
+    last_update: Instant,
 }
 
 /// A trait for sending transaction status updates.
@@ -106,9 +114,6 @@ pub trait CreateChannel {
 trait Permits {
     /// Try to acquire a permit.
     fn try_acquire(self: Arc<Self>) -> Option<Permit>;
-
-    /// Wait for a permit to be available.
-    fn acquire(self: Arc<Self>) -> Pin<Box<dyn Future<Output = Permit> + Send + Sync>>;
 }
 
 /// Combines `Permits` and `std::fmt::Debug`.
@@ -134,16 +139,6 @@ impl Permits for Semaphore {
             b
         })
     }
-
-    fn acquire(self: Arc<Self>) -> Pin<Box<dyn Future<Output = Permit> + Send + Sync>> {
-        Box::pin(async move {
-            let p = Semaphore::acquire_owned(self)
-                .await
-                .expect("Semaphore is not ever closed");
-            let b: Permit = Box::new(p);
-            b
-        })
-    }
 }
 
 impl PermitTrait for OwnedSemaphorePermit {}
@@ -153,6 +148,9 @@ where
     Tx: SendStatus,
 {
     fn try_send(&mut self, msg: TxStatusMessage) -> Result<(), SendError> {
+        // The subscriber is not idle while it receives updates.
+        self.last_update = Instant::now();
+
         // Add the message to the stream.
         self.stream.add_msg(msg);
 
@@ -204,11 +202,14 @@ impl SendStatus for mpsc::Sender<TxStatusMessage> {
 }
 
 impl UpdateSender {
-    /// Create a new UpdateSender with a specified capacity for the semaphore
-    pub fn new(capacity: usize) -> UpdateSender {
+    /// Create a new UpdateSender with a specified capacity for the semaphore,
+    /// and the timeout after which the subscribers without updates are idle.
+    pub fn new(capacity: usize, idle_timeout: Duration) -> UpdateSender {
         UpdateSender {
             senders: Default::default(),
             permits: Arc::new(Semaphore::new(capacity)),
+            capacity,
+            idle_timeout,
         }
     }
 
@@ -218,37 +219,31 @@ impl UpdateSender {
     where
         C: CreateChannel,
     {
+        // Lock the senders Mutex
+        let mut senders = self.senders.lock();
+
         // Remove closed senders from the list
-        remove_closed(&mut self.senders.lock());
+        remove_closed(&mut senders);
 
         // Try to acquire a permit from the semaphore
         let permit = Arc::clone(&self.permits).try_acquire()?;
 
-        // Call subscribe_inner with the acquired permit
-        Some(self.subscribe_inner::<C>(tx_id, permit))
-    }
-
-    /// Asynchronously await a permit to subscribe
-    /// returns a TxStatusStream on completion
-    pub async fn subscribe<C>(&self, tx_id: Bytes32) -> TxStatusStream
-    where
-        C: CreateChannel,
-    {
-        // Remove closed senders from the list.
-        // Careful not to hold the lock while awaiting.
-        {
-            remove_closed(&mut self.senders.lock());
-        }
-
-        // Acquire a permit from the semaphore asynchronously.
-        let permit = Arc::clone(&self.permits).acquire().await;
-
-        // Call subscribe_inner with the acquired permit.
-        self.subscribe_inner::<C>(tx_id, permit)
+        // Call the subscribe function with the tx_id, senders, and permit
+        Some(subscribe::<_, C>(tx_id, &mut senders, permit))
     }
 
-    /// Subscribe to updates with the given transaction id and a permit.
-    fn subscribe_inner<C>(&self, tx_id: Bytes32, permit: Permit) -> TxStatusStream
+    /// Subscribe for updates, returns a TxStatusStream if a permit is available.
+    ///
+    /// If all permits are taken, an idle subscriber is evicted to free its permit.
+    /// The subscribers to the transactions that aren't `pending` are evicted first,
+    /// then the least recently updated ones. The evicted subscriber receives
+    /// the `Evicted` message. Fails with the `TooManySubscriptions` error if none of
+    /// the subscribers is idle.
+    pub fn subscribe<C>(
+        &self,
+        tx_id: Bytes32,
+        pending: impl Fn(&Bytes32) -> bool,
+    ) -> Result<TxStatusStream, TxPoolError>
     where
         C: CreateChannel,
     {
@@ -258,8 +253,21 @@ impl UpdateSender {
         // Remove closed senders from the list
         remove_closed(&mut senders);
 
+        // Try to acquire a permit, evicting the idle subscriber if there is none.
+        let permit = match Arc::clone(&self.permits).try_acquire() {
+            Some(permit) => permit,
+            None => {
+                evict_idle(&mut senders, self.idle_timeout, pending);
+                Arc::clone(&self.permits).try_acquire().ok_or(
+                    TxPoolError::TooManySubscriptions {
+                        limit: self.capacity,
+                    },
+                )?
+            }
+        };
+
         // Call the subscribe function with the tx_id, senders, and permit
-        subscribe::<_, C>(tx_id, &mut (*senders), permit)
+        Ok(subscribe::<_, C>(tx_id, &mut senders, permit))
     }
 
     /// Send updates to all subscribed senders.
@@ -308,6 +316,7 @@ where
         _permit: permit,
         stream: TxUpdateStream::new(),
         tx,
+        last_update: Instant::now(),
     });
 
     // Return the receiver part of the channel
@@ -327,6 +336,46 @@ where
     });
 }
 
+// Remove one sender without updates for longer than the `idle_timeout`, dropping
+// its permit. The senders of the transactions that aren't `pending` go first, since
+// they may never receive an update, then the least recently updated ones.
+fn evict_idle<P, Tx>(
+    senders: &mut SenderMap<P, Tx>,
+    idle_timeout: Duration,
+    pending: impl Fn(&Bytes32) -> bool,
+) where
+    Tx: SendStatus,
+{
+    let now = Instant::now();
+
+    // Find the idle sender to evict
+    let evicted = senders
+        .iter()
+        .flat_map(|(tx_id, senders)| {
+            senders
+                .iter()
+                .enumerate()
+                .map(move |(index, sender)| (*tx_id, index, sender.last_update))
+        })
+        .filter(|(_, _, last_update)| {
+            now.saturating_duration_since(*last_update) >= idle_timeout
+        })
+        .min_by_key(|(tx_id, _, last_update)| (pending(tx_id), *last_update));
+
+    // Remove the sender, and the tx_id if it has no senders left
+    if let Some((tx_id, index, _)) = evicted {
+        if let Some(tx_senders) = senders.get_mut(&tx_id) {
+            let mut sender = tx_senders.remove(index);
+            // Tell the subscriber why its stream ends. The message is lost
+            // if the subscriber doesn't read its channel.
+            let _ = sender.tx.try_send(TxStatusMessage::Evicted);
+            if tx_senders.is_empty() {
+                senders.remove(&tx_id);
+            }
+        }
+    }
+}
+
 impl<T> SendStatus for Box<T>
 where
     T: SendStatus + ?Sized,
@@ -349,6 +398,8 @@ impl Clone for UpdateSender {
         Self {
             senders: self.senders.clone(),
             permits: self.permits.clone(),
+            capacity: self.capacity,
+            idle_timeout: self.idle_timeout,
         }
     }
 }
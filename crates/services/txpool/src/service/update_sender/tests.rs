@@ -37,11 +37,16 @@ fn validate_tx_update_stream_state(state: State, transition: StateTransitions) -
         ) => Initial(TransactionStatus::Submitted { time }),
         // If not Submitted, it's an early success.
         (Empty, AddMsg(TxStatusMessage::Status(s))) => EarlySuccess(s),
-        (Empty, AddMsg(TxStatusMessage::FailedStatus)) => Failed,
+        (Empty, AddMsg(TxStatusMessage::FailedStatus | TxStatusMessage::Evicted)) => {
+            Failed
+        }
         (Empty, AddFailure) => Failed,
         (Empty | Initial(_), Next) => Empty,
         (Initial(s1), AddMsg(TxStatusMessage::Status(s2))) => Success(s1, s2),
-        (Initial(s1), AddMsg(TxStatusMessage::FailedStatus)) => LateFailed(s1),
+        (
+            Initial(s1),
+            AddMsg(TxStatusMessage::FailedStatus | TxStatusMessage::Evicted),
+        ) => LateFailed(s1),
         (Initial(s), AddFailure) => LateFailed(s),
         (_, CloseRecv) => Closed,
         (EarlySuccess(_) | Failed | SenderClosed(_), Next) => Closed,
@@ -22,9 +22,6 @@ impl Permits for Arc<usize> {
             p
         })
     }
-    fn acquire(self: Arc<Self>) -> Pin<Box<dyn Future<Output = Permit> + Send + Sync>> {
-        unimplemented!()
-    }
 }
 
 #[proptest]
@@ -68,6 +65,7 @@ fn test_try_subscribe_inner(senders: HashMap<Bytes32, Vec<Sender<(), MockSendSta
                         _permit: Arc::clone(&permits),
                         stream: v.stream,
                         tx: v.tx,
+                        last_update: v.last_update,
                     })
                     .collect(),
             )
@@ -94,6 +92,8 @@ fn test_try_subscribe_inner(senders: HashMap<Bytes32, Vec<Sender<(), MockSendSta
     let update = UpdateSender {
         senders: Arc::new(Mutex::new(box_senders(senders))),
         permits: Arc::new(permits),
+        capacity,
+        idle_timeout: Duration::MAX,
     };
 
     // Test the try_subscribe function on the UpdateSender
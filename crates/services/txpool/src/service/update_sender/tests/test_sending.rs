@@ -117,6 +117,8 @@ fn test_send_inner(
     let update = UpdateSender {
         senders: Arc::new(Mutex::new(box_senders(senders))),
         permits: Arc::new(()),
+        capacity: usize::MAX,
+        idle_timeout: Duration::MAX,
     };
     update.send(msg.clone());
 
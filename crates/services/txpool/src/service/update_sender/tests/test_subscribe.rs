@@ -31,3 +31,56 @@ fn test_subscriber(input: Input) {
     let len_after = senders.values().map(|v| v.len()).sum::<usize>();
     assert_eq!(len_before + 1, len_after);
 }
+
+#[test]
+fn test_subscribe_above_capacity() {
+    // Without idle subscribers the subscription above the capacity is rejected.
+    let update = UpdateSender::new(2, Duration::MAX);
+    let _first = update
+        .subscribe::<MpscChannel>(Bytes32::from([0; 32]), |_| true)
+        .unwrap();
+    let _second = update
+        .subscribe::<MpscChannel>(Bytes32::from([1; 32]), |_| true)
+        .unwrap();
+    let result = update.subscribe::<MpscChannel>(Bytes32::from([2; 32]), |_| true);
+    assert!(matches!(
+        result,
+        Err(TxPoolError::TooManySubscriptions { limit: 2 })
+    ));
+
+    // The idle subscriber is evicted to free its permit.
+    let update = UpdateSender::new(2, Duration::ZERO);
+    let _first = update
+        .subscribe::<MpscChannel>(Bytes32::from([0; 32]), |_| true)
+        .unwrap();
+    let _second = update
+        .subscribe::<MpscChannel>(Bytes32::from([1; 32]), |_| true)
+        .unwrap();
+    let result = update.subscribe::<MpscChannel>(Bytes32::from([2; 32]), |_| true);
+    assert!(result.is_ok());
+    assert!(!update.senders.lock().contains_key(&Bytes32::from([0; 32])));
+}
+
+#[tokio::test]
+async fn test_evicts_subscriber_of_unknown_transaction_first() {
+    use futures::StreamExt;
+
+    let update = UpdateSender::new(2, Duration::ZERO);
+    let pending = |tx_id: &Bytes32| *tx_id != Bytes32::from([1; 32]);
+    let _first = update
+        .subscribe::<MpscChannel>(Bytes32::from([0; 32]), pending)
+        .unwrap();
+    let second = update
+        .subscribe::<MpscChannel>(Bytes32::from([1; 32]), pending)
+        .unwrap();
+
+    // The second subscriber is more recent, but its transaction isn't pending.
+    let _third = update
+        .subscribe::<MpscChannel>(Bytes32::from([2; 32]), pending)
+        .unwrap();
+    assert!(update.senders.lock().contains_key(&Bytes32::from([0; 32])));
+    assert_eq!(
+        second.collect::<Vec<_>>().await,
+        vec![TxStatusMessage::Evicted]
+    );
+}
@@ -86,6 +86,7 @@ pub(super) fn senders_strategy(
             stream: TxUpdateStream::with_state(state),
             _permit: (),
             tx,
+            last_update: Instant::now(),
         }
     });
     prop::collection::hash_map(
@@ -167,6 +168,7 @@ pub(super) fn construct_senders(
                 stream: TxUpdateStream::with_state(state.clone()),
                 _permit: (),
                 tx,
+                last_update: Instant::now(),
             });
         }
         senders.insert(Bytes32::from([*i; 32]), v);
@@ -180,10 +182,6 @@ impl Permits for () {
     fn try_acquire(self: Arc<Self>) -> Option<Permit> {
         Some(Permit::from(Box::new(())))
     }
-
-    fn acquire(self: Arc<Self>) -> Pin<Box<dyn Future<Output = Permit> + Send + Sync>> {
-        Box::pin(async move { Permit::from(Box::new(())) })
-    }
 }
 
 pub(super) struct MockCreateChannel;
@@ -212,6 +210,7 @@ pub(super) fn box_senders<
                         _permit: Permit::from(Box::new(s._permit)),
                         stream: s.stream,
                         tx: Tx::from(Box::new(s.tx)),
+                        last_update: s.last_update,
                     })
                     .collect::<Vec<_>>(),
             )
@@ -43,7 +43,7 @@ impl TxUpdateStream {
                     State::Initial(TransactionStatus::Submitted { time })
                 }
                 TxStatusMessage::Status(s) => State::EarlySuccess(s),
-                TxStatusMessage::FailedStatus => State::Failed,
+                TxStatusMessage::FailedStatus | TxStatusMessage::Evicted => State::Failed,
             },
             State::Initial(s1) => {
                 if let TxStatusMessage::Status(s2) = msg {
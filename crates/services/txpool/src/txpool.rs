@@ -16,7 +16,9 @@ use fuel_core_metrics::txpool_metrics::TXPOOL_METRICS;
 use fuel_core_types::{
     fuel_tx::{
         Chargeable,
+        Input,
         Transaction,
+        UtxoId,
     },
     fuel_types::BlockHeight,
     fuel_vm::{
@@ -33,24 +35,55 @@ use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
         InsertionResult,
+        TransactionStatus,
+        TransactionStatusRecord,
     },
     tai64::Tai64,
 };
 
 use std::{
     cmp::Reverse,
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 use tokio_rayon::AsyncRayonHandle;
 
+/// The maximum number of committed transactions whose submission time is remembered.
+pub const COMMITTED_SUBMISSION_TIMES_CAPACITY: usize = 10_000;
+
+/// The maximum number of transactions whose status history is kept by the pool.
+pub const STATUS_HISTORY_CAPACITY: usize = 10_000;
+
+/// The maximum number of the status transitions kept by the pool per transaction.
+pub const MAX_STATUS_HISTORY_PER_TX: usize = 16;
+
 #[derive(Debug, Clone)]
 pub struct TxPool<DB> {
     by_hash: HashMap<TxId, TxInfo>,
     by_gas_price: PriceSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    /// The submission times of the recently committed transactions. They are
+    /// best-effort statistics, so they are not persisted.
+    committed_submission_times: HashMap<TxId, Duration>,
+    /// The committed transactions in the order of commit, so the oldest ones
+    /// are forgotten first.
+    committed_order: VecDeque<TxId>,
+    /// The statuses of the transactions recorded by the pool: the submissions and
+    /// the removals. The statuses of the committed transactions are persisted by
+    /// the executor, so the pool only keeps the latest ones in memory.
+    status_history: HashMap<TxId, Vec<TransactionStatusRecord>>,
+    /// The transactions with the recorded history in the order of the first record,
+    /// so the oldest ones are forgotten first.
+    status_history_order: VecDeque<TxId>,
+    /// The prevalidated transactions inside the pool, at most `max_prevalidated_tx`.
+    prevalidated: HashSet<TxId>,
     config: Config,
     database: DB,
 }
@@ -67,6 +100,11 @@ where
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
             by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            committed_submission_times: HashMap::new(),
+            committed_order: VecDeque::new(),
+            status_history: HashMap::new(),
+            status_history_order: VecDeque::new(),
+            prevalidated: HashSet::new(),
             config,
             database,
         }
@@ -151,10 +189,9 @@ where
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
             if max_limit_hit {
-                // remove last tx from sort
+                // remove last tx from sort, together with its dependents
                 let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
-                self.remove_inner(&rem_tx);
-                vec![rem_tx]
+                self.remove_inner(&rem_tx)
             } else {
                 Vec::new()
             }
@@ -204,6 +241,7 @@ where
 
     fn remove_tx(&mut self, tx_id: &TxId) -> Option<TxInfo> {
         let info = self.by_hash.remove(tx_id);
+        self.prevalidated.remove(tx_id);
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
@@ -236,7 +274,42 @@ where
             res.push(self.insert_inner(tx));
         }
 
-        // announce to subscribers
+        self.announce(tx_status_sender, &res);
+        res
+    }
+
+    /// Imports a set of the prevalidated transactions, that skipped the verification
+    /// of the signatures and predicates. Only `max_prevalidated_tx` of them can be
+    /// inside the pool at the same time.
+    pub fn insert_prevalidated(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        let mut res = Vec::new();
+
+        for tx in txs.into_iter() {
+            if self.prevalidated.len() >= self.config.max_prevalidated_tx {
+                res.push(Err(Error::NotInsertedPrevalidatedLimitHit.into()));
+                continue
+            }
+            let result = self.insert_inner(tx);
+            if let Ok(insertion) = &result {
+                self.prevalidated.insert(insertion.inserted.id());
+            }
+            res.push(result);
+        }
+
+        self.announce(tx_status_sender, &res);
+        res
+    }
+
+    /// Announces the results of the insertion to the subscribers.
+    fn announce(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        res: &[anyhow::Result<InsertionResult>],
+    ) {
         for ret in res.iter() {
             match ret {
                 Ok(InsertionResult {
@@ -248,10 +321,13 @@ where
                         // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
                         // but for now it is okay to just use Error::Removed.
                         tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        self.record_squeezed_out(removed.id(), Error::Removed);
                     }
-                    tx_status_sender.send_submitted(
+                    let time = Tai64::from_unix(submitted_time.as_secs() as i64);
+                    tx_status_sender.send_submitted(inserted.id(), time);
+                    self.record_status(
                         inserted.id(),
-                        Tai64::from_unix(submitted_time.as_secs() as i64),
+                        TransactionStatus::Submitted { time },
                     );
                 }
                 Err(_) => {
@@ -259,7 +335,6 @@ where
                 }
             }
         }
-        res
     }
 
     /// find all tx by its hash
@@ -275,6 +350,34 @@ where
         self.txs().get(hash).cloned()
     }
 
+    /// find all tx with an input that references the `utxo_id`, sorted by Price.
+    pub fn find_by_utxo(&self, utxo_id: &UtxoId) -> Vec<ArcPoolTx> {
+        let mut list: Vec<_> = self
+            .txs()
+            .values()
+            .filter(|info| {
+                info.tx()
+                    .inputs()
+                    .iter()
+                    .any(|input| input.utxo_id() == Some(utxo_id))
+            })
+            .map(|info| info.tx().clone())
+            .collect();
+        // sort from high to low price
+        list.sort_by_key(|tx| Reverse(tx.price()));
+
+        list
+    }
+
+    /// find all tx that spend any of the coins or messages of the `inputs`.
+    pub fn find_conflicting(&self, inputs: &[Input]) -> Vec<ArcPoolTx> {
+        self.dependency()
+            .find_spenders(inputs)
+            .iter()
+            .filter_map(|hash| self.txs().get(hash).map(|info| info.tx().clone()))
+            .collect()
+    }
+
     /// find all dependent tx and return them with requested dependencies in one list sorted by Price.
     pub fn find_dependent(&self, hashes: &[TxId]) -> Vec<ArcPoolTx> {
         let mut seen = HashMap::new();
@@ -301,6 +404,20 @@ where
         self.by_hash.len()
     }
 
+    /// Returns the position of the transaction in the order of the inclusion,
+    /// from the highest gas price, or `None` if it isn't in the pool.
+    pub fn queue_position(&self, tx_id: &TxId) -> Option<usize> {
+        self.sorted_includable().position(|tx| tx.id() == *tx_id)
+    }
+
+    /// The gas prices of all transactions in the pool.
+    pub fn gas_prices(&self) -> Vec<GasPrice> {
+        self.by_hash
+            .values()
+            .map(|info| info.tx().price())
+            .collect()
+    }
+
     /// The amount of gas in all includable transactions combined
     pub fn consumable_gas(&self) -> u64 {
         self.by_hash.values().map(|tx| tx.limit()).sum()
@@ -324,10 +441,35 @@ where
             let tx_id = *tx_id;
             let result = self.database.transaction_status(&tx_id);
             tx_status_sender.send_complete(tx_id, height, result);
+            if let Some(info) = self.by_hash.get(&tx_id) {
+                let submitted_time = info.submitted_time();
+                self.remember_submission_time(tx_id, submitted_time);
+            }
             self.remove_committed_tx(&tx_id);
         }
     }
 
+    /// Returns the time since the `UNIX_EPOCH` when the committed transaction
+    /// was submitted to this pool, if it is still remembered.
+    pub fn committed_submission_time(&self, tx_id: &TxId) -> Option<Duration> {
+        self.committed_submission_times.get(tx_id).copied()
+    }
+
+    fn remember_submission_time(&mut self, tx_id: TxId, submitted_time: Duration) {
+        if self
+            .committed_submission_times
+            .insert(tx_id, submitted_time)
+            .is_none()
+        {
+            self.committed_order.push_back(tx_id);
+        }
+        while self.committed_order.len() > COMMITTED_SUBMISSION_TIMES_CAPACITY {
+            if let Some(oldest) = self.committed_order.pop_front() {
+                self.committed_submission_times.remove(&oldest);
+            }
+        }
+    }
+
     /// remove transaction from pool needed on user demand. Low priority
     pub fn remove(
         &mut self,
@@ -338,11 +480,52 @@ where
         for tx_id in tx_ids {
             let rem = self.remove_by_tx_id(tx_id);
             tx_status_sender.send_squeezed_out(*tx_id, Error::Removed);
+            self.record_squeezed_out(*tx_id, Error::Removed);
             removed.extend(rem.into_iter());
         }
         removed
     }
 
+    /// Records the transaction squeezed out of the pool in its status history.
+    pub fn record_squeezed_out(&mut self, tx_id: TxId, reason: Error) {
+        self.record_status(
+            tx_id,
+            TransactionStatus::SqueezedOut {
+                reason: reason.to_string(),
+            },
+        );
+    }
+
+    /// Returns the statuses of the transaction recorded by the pool, from the oldest
+    /// to the latest. Empty if the transaction is unknown or already forgotten.
+    pub fn status_history(&self, tx_id: &TxId) -> Vec<TransactionStatusRecord> {
+        self.status_history.get(tx_id).cloned().unwrap_or_default()
+    }
+
+    /// Records the `status` in the in-memory history of the transaction. Only the
+    /// latest `STATUS_HISTORY_CAPACITY` transactions keep their history.
+    fn record_status(&mut self, tx_id: TxId, status: TransactionStatus) {
+        let time = match &status {
+            TransactionStatus::Submitted { time }
+            | TransactionStatus::Success { time, .. }
+            | TransactionStatus::Failed { time, .. } => *time,
+            TransactionStatus::SqueezedOut { .. } => Tai64::now(),
+        };
+        let history = self.status_history.entry(tx_id).or_insert_with(|| {
+            self.status_history_order.push_back(tx_id);
+            vec![]
+        });
+        history.push(TransactionStatusRecord { time, status });
+        if history.len() > MAX_STATUS_HISTORY_PER_TX {
+            history.drain(..history.len() - MAX_STATUS_HISTORY_PER_TX);
+        }
+        while self.status_history_order.len() > STATUS_HISTORY_CAPACITY {
+            if let Some(oldest) = self.status_history_order.pop_front() {
+                self.status_history.remove(&oldest);
+            }
+        }
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let deadline = tokio::time::Instant::now() - self.config.transaction_ttl;
@@ -416,6 +599,25 @@ pub async fn check_single_tx(
     Ok(tx)
 }
 
+/// Performs only the checks required to insert the prevalidated `tx` into the pool.
+/// Unlike [`check_single_tx`], the signatures and the predicates are not verified.
+pub fn check_prevalidated_tx(
+    tx: Transaction,
+    current_height: BlockHeight,
+    config: &Config,
+) -> anyhow::Result<Checked<Transaction>> {
+    if tx.is_mint() {
+        return Err(Error::NotSupportedTransactionType.into())
+    }
+
+    verify_tx_min_gas_price(&tx, config)?;
+
+    Ok(tx.into_checked_basic(
+        current_height,
+        &config.chain_config.transaction_parameters,
+    )?)
+}
+
 fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Error> {
     let price = match tx {
         Transaction::Script(script) => script.price(),
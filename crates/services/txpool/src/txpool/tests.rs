@@ -592,6 +592,59 @@ async fn tx_limit_hit() {
     ));
 }
 
+#[tokio::test]
+async fn tx_limit_hit_removes_lowest_priced_tx_and_its_dependents() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx: 2,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(
+        tx1.id(&fuel_tx::ConsensusParameters::DEFAULT.chain_id),
+        0,
+    ));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(5)
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&fuel_tx::ConsensusParameters::DEFAULT.chain_id);
+    let tx2_id = tx2.id(&fuel_tx::ConsensusParameters::DEFAULT.chain_id);
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be OK, got Err");
+    let result = txpool.insert_inner(tx3).expect("Tx3 should be OK, got Err");
+
+    let removed: Vec<_> = result.removed.iter().map(|tx| tx.id()).collect();
+    assert_eq!(removed, vec![tx1_id, tx2_id]);
+    assert_eq!(txpool.txs().len(), 1);
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -1105,3 +1158,81 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn status_history_forgets_the_oldest_transactions() {
+    use super::STATUS_HISTORY_CAPACITY;
+    use fuel_core_types::services::txpool::TransactionStatus;
+
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db);
+
+    let ids: Vec<fuel_tx::TxId> = (0..=STATUS_HISTORY_CAPACITY as u64)
+        .map(|i| {
+            let mut id = [0u8; 32];
+            id[..8].copy_from_slice(&i.to_be_bytes());
+            id.into()
+        })
+        .collect();
+    for id in &ids {
+        txpool.record_squeezed_out(*id, Error::Removed);
+    }
+
+    assert!(txpool.status_history(&ids[0]).is_empty());
+    let history = txpool.status_history(ids.last().unwrap());
+    assert_eq!(history.len(), 1);
+    assert!(matches!(
+        history[0].status,
+        TransactionStatus::SqueezedOut { .. }
+    ));
+}
+
+#[tokio::test]
+async fn prevalidated_limit_hit_keeps_the_other_slots() {
+    use crate::service::TxStatusChange;
+    use std::time::Duration;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_prevalidated_tx: 1,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+    let sender = TxStatusChange::new(10, Duration::from_secs(60));
+
+    let mut txs = vec![];
+    for _ in 0..3 {
+        let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction();
+        txs.push(tx);
+    }
+    let tx1_id = txs[0].id(&fuel_tx::ConsensusParameters::DEFAULT.chain_id);
+    let tx3 = check_unwrap_tx(txs.pop().unwrap(), db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(txs.pop().unwrap(), db.clone(), &txpool.config).await;
+    let tx1 = check_unwrap_tx(txs.pop().unwrap(), db.clone(), &txpool.config).await;
+
+    let mut res = txpool.insert_prevalidated(&sender, vec![tx1, tx2.clone()]);
+    let err = res.pop().unwrap().expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedPrevalidatedLimitHit)
+    ));
+    res.pop().unwrap().expect("Tx1 should be Ok, got Err");
+
+    // The regular transactions aren't limited by the prevalidated ones
+    txpool.insert(&sender, vec![tx3]).pop().unwrap().unwrap();
+
+    // The removal of the prevalidated transaction frees its slot
+    txpool.remove_by_tx_id(&tx1_id);
+    txpool
+        .insert_prevalidated(&sender, vec![tx2])
+        .pop()
+        .unwrap()
+        .expect("Tx2 should be Ok, got Err");
+}
@@ -10,13 +10,16 @@ use crate::{
     },
     fuel_tx::{
         CheckError,
+        Receipt,
         TxId,
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
         Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     fuel_vm::{
         Backtrace,
@@ -71,6 +74,58 @@ pub enum TransactionExecutionResult {
     },
 }
 
+/// The change of the contract storage slot made by the dry run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotDelta {
+    /// The contract that owns the slot.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The value of the slot before the execution, `None` if the slot didn't exist.
+    pub before: Option<Bytes32>,
+    /// The value of the slot after the execution. The cleared slot is zeroed.
+    pub after: Bytes32,
+}
+
+/// The result of the simulation of the block made of the chosen transactions.
+#[derive(Debug, Clone)]
+pub struct BlockSimulation {
+    /// The height of the simulated block.
+    pub height: BlockHeight,
+    /// The gas used by the included transactions.
+    pub gas_used: Word,
+    /// The fees paid to the block producer by the included transactions.
+    pub fees: Word,
+    /// The outcome of each transaction, in the order of the simulated transactions.
+    pub transactions: Vec<SimulatedTransaction>,
+}
+
+/// The outcome of the transaction in the simulated block.
+#[derive(Debug, Clone)]
+pub enum SimulatedTransaction {
+    /// The transaction was included into the block.
+    Included {
+        /// The id of the transaction.
+        id: TxId,
+        /// The result of the execution of the transaction.
+        result: TransactionExecutionResult,
+        /// The receipts produced by the transaction.
+        receipts: Vec<Receipt>,
+    },
+    /// The transaction was rejected by the execution as invalid.
+    Skipped {
+        /// The id of the transaction.
+        id: TxId,
+        /// The reason of the rejection.
+        reason: String,
+    },
+    /// The transaction didn't fit into the gas limit of the block.
+    Excluded {
+        /// The id of the transaction.
+        id: TxId,
+    },
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
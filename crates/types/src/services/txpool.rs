@@ -112,6 +112,13 @@ impl PoolTransaction {
             PoolTransaction::Create(create) => create.metadata().fee.max_gas(),
         }
     }
+
+    pub fn max_fee(&self) -> Word {
+        match self {
+            PoolTransaction::Script(script) => script.metadata().fee.max_fee(),
+            PoolTransaction::Create(create) => create.metadata().fee.max_fee(),
+        }
+    }
 }
 
 impl From<&PoolTransaction> for Transaction {
@@ -156,7 +163,8 @@ pub struct InsertionResult {
     pub inserted: ArcPoolTx,
     /// The time the transaction was inserted.
     pub submitted_time: Duration,
-    /// These were removed during the insertion
+    /// These were removed during the insertion, including the transactions that
+    /// depended on the outputs of the removed ones
     pub removed: Vec<ArcPoolTx>,
 }
 
@@ -196,6 +204,17 @@ pub enum TransactionStatus {
     },
 }
 
+/// The status of the transaction with the time of the transition to it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionStatusRecord {
+    /// The time of the submission for `Submitted`, the time of the block for
+    /// `Success` and `Failed`, and the time of the removal for `SqueezedOut`.
+    pub time: Tai64,
+    /// The status of the transaction
+    pub status: TransactionStatus,
+}
+
 #[allow(missing_docs)]
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -208,6 +227,8 @@ pub enum Error {
     NotInsertedTxKnown,
     #[error("Transaction is not inserted. Pool limit is hit, try to increase gas_price")]
     NotInsertedLimitHit,
+    #[error("Transaction is not inserted. The limit of the prevalidated transactions is hit")]
+    NotInsertedPrevalidatedLimitHit,
     #[error("Transaction is not inserted. The gas price is too low.")]
     NotInsertedGasPriceTooLow,
     #[error(
@@ -267,6 +288,10 @@ pub enum Error {
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
     SqueezedOut(String),
+    #[error(
+        "Too many subscriptions to the transaction status updates, the limit is {limit}"
+    )]
+    TooManySubscriptions { limit: usize },
     // TODO: We need it for now until channels are removed from TxPool.
     #[error("Got some unexpected error: {0}")]
     Other(String),
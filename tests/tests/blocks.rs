@@ -26,7 +26,11 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
-    fuel_tx::*,
+    fuel_asm::*,
+    fuel_tx::{
+        field::Outputs,
+        *,
+    },
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
@@ -150,6 +154,133 @@ async fn produce_block_manually() {
     assert_eq!(*actual_pub_key, expected_pub_key);
 }
 
+#[tokio::test]
+async fn await_block_height_returns_immediately_if_reached() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let block_ref = srv.await_block_height(0).await.unwrap();
+
+    let genesis_id = srv.shared.database.get_block_id(&0u32.into()).unwrap();
+    assert_eq!(Some(block_ref.id), genesis_id);
+    assert_eq!(block_ref.height, 0u32.into());
+}
+
+#[tokio::test]
+async fn await_block_height_waits_for_the_block() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let (block_ref, new_height) =
+        tokio::join!(srv.await_block_height(2), client.produce_blocks(3, None));
+
+    let block_ref = block_ref.unwrap();
+    assert_eq!(3, *new_height.unwrap());
+    assert_eq!(block_ref.height, 2u32.into());
+    let block = client.block_by_height(2).await.unwrap().unwrap();
+    assert_eq!(block.id, block_ref.id.into());
+}
+
+#[tokio::test]
+async fn subscribe_new_blocks_yields_committed_headers_in_order() {
+    use futures::StreamExt;
+
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let blocks = srv.subscribe_new_blocks();
+    client.produce_blocks(3, None).await.unwrap();
+
+    let headers: Vec<_> = blocks.take(3).collect().await;
+    let heights: Vec<u32> = headers
+        .into_iter()
+        .map(|header| **header.unwrap().height())
+        .collect();
+    assert_eq!(heights, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn subscribe_chain_tip_reaches_the_final_tip() {
+    use futures::StreamExt;
+
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut tips = client.subscribe_chain_tip().await.unwrap();
+    let last_tip = tokio::spawn(async move {
+        loop {
+            let tip = tips.next().await.unwrap().unwrap();
+            if tip.height == 5 {
+                return tip
+            }
+        }
+    });
+    // give the subscription time to connect before producing the blocks
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    client.produce_blocks(5, None).await.unwrap();
+
+    let tip = tokio::time::timeout(Duration::from_secs(5), last_tip)
+        .await
+        .expect("the subscriber should see the final tip")
+        .unwrap();
+    let block = client.block_by_height(5).await.unwrap().unwrap();
+    assert_eq!(tip.block_id, block.id);
+}
+
+#[tokio::test]
+async fn coinbase_revenue_sums_fees_paid_to_the_recipient() {
+    let recipient = Address::from([7; 32]);
+    let mut config = Config::local_node();
+    config.block_producer.coinbase_recipient = recipient;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_price(1)
+        .gas_limit(10_000)
+        .add_input(Input::coin_signed(
+            UtxoId::new([1; 32].into(), 0),
+            Default::default(),
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            0,
+            Default::default(),
+        ))
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    let expected = match srv.transaction(block.transactions[0]).unwrap() {
+        Some(Transaction::Mint(mint)) => match mint.outputs().as_slice() {
+            [Output::Coin { to, amount, .. }] if *to == recipient => *amount,
+            outputs => panic!("Unexpected coinbase outputs: {outputs:?}"),
+        },
+        tx => panic!("The first transaction should be the mint: {tx:?}"),
+    };
+    assert!(expected > 0);
+
+    let revenue = client.coinbase_revenue(&recipient, 10).await.unwrap();
+    assert_eq!(revenue, expected);
+    let revenue = client
+        .coinbase_revenue(&Address::from([8; 32]), 10)
+        .await
+        .unwrap();
+    assert_eq!(revenue, 0);
+
+    let err = client
+        .coinbase_revenue(&recipient, u32::MAX)
+        .await
+        .expect_err("The window should be bounded");
+    assert!(err.to_string().contains("blocks can be scanned"), "{err}");
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
@@ -3,6 +3,17 @@ use fuel_core::service::{
     FuelService,
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+};
 
 #[tokio::test]
 async fn chain_info() {
@@ -23,3 +34,32 @@ async fn chain_info() {
         chain_info.gas_costs.into()
     );
 }
+
+#[tokio::test]
+async fn chain_parameters_match_ids_computed_by_node() {
+    let mut node_config = Config::local_node();
+    node_config.chain_conf.transaction_parameters.chain_id = ChainId::new(42);
+    node_config.txpool.chain_config = node_config.chain_conf.clone();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let parameters = client.chain_parameters().await.unwrap();
+
+    assert_eq!(parameters.chain_id, ChainId::new(42));
+    assert_eq!(
+        node_config.chain_conf.transaction_parameters,
+        parameters.consensus_parameters.into()
+    );
+    assert_eq!(
+        node_config.chain_conf.gas_costs,
+        parameters.gas_costs.into()
+    );
+
+    // The id computed with the returned chain id matches the id of the node
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = client.submit(&tx).await.unwrap();
+    assert_eq!(id, tx.id(&parameters.chain_id));
+}
@@ -1,5 +1,6 @@
 use fuel_core::{
     database::Database,
+    fuel_core_graphql_api::coin_reservations::MAX_RESERVED_COINS_PER_CALL,
     service::{
         Config,
         FuelService,
@@ -13,6 +14,7 @@ use fuel_core_client::client::{
     types::primitives::{
         Address,
         AssetId,
+        Bytes32,
         UtxoId,
     },
     FuelClient,
@@ -188,3 +190,72 @@ async fn get_coins_forwards_backwards(
     assert!(!coins.results.is_empty());
     assert_eq!(coins.results.len(), 10);
 }
+
+#[tokio::test]
+async fn reserved_coin_is_freed_after_ttl() {
+    let coin = Coin {
+        utxo_id: UtxoId::new([1; 32].into(), 0),
+        owner: Address::default(),
+        amount: 100,
+        asset_id: Default::default(),
+        maturity: Default::default(),
+        tx_pointer: Default::default(),
+    };
+    let mut db = Database::default();
+    db.storage::<Coins>()
+        .insert(&coin.utxo_id, &coin.compress())
+        .unwrap();
+
+    let srv = FuelService::from_database(db, Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let is_reserved = |token| async move {
+        client
+            .is_coin_reserved(&coin.utxo_id, &token)
+            .await
+            .unwrap()
+    };
+
+    let reservation = client
+        .reserve_coins(&[coin.utxo_id], Some(1), None)
+        .await
+        .unwrap();
+    assert!(is_reserved(reservation.token).await);
+    // The reservations are visible only with their token
+    assert!(!is_reserved(Bytes32::zeroed()).await);
+
+    tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+    assert!(!is_reserved(reservation.token).await);
+}
+
+#[tokio::test]
+async fn reserve_coins_rejects_too_many_coins() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let coin_ids: Vec<_> = (0..=MAX_RESERVED_COINS_PER_CALL)
+        .map(|i| UtxoId::new([(i / 256) as u8; 32].into(), (i % 256) as u8))
+        .collect();
+    let err = client
+        .reserve_coins(&coin_ids, None, None)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("coins can be reserved at once"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn reserve_coins_rejects_unknown_coin() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client
+        .reserve_coins(&[UtxoId::new([7; 32].into(), 0)], None, None)
+        .await;
+
+    assert!(result.is_err());
+}
@@ -106,6 +106,49 @@ async fn test_5_contract_balances(
     }
 }
 
+#[tokio::test]
+async fn contract_all_balances() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(
+        vec![],
+        Some(vec![
+            (AssetId::new([1u8; 32]), 1000),
+            (AssetId::new([2u8; 32]), 400),
+        ]),
+        None,
+        None,
+    );
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let balances = client
+        .contract_all_balances(&contract_id)
+        .await
+        .unwrap()
+        .expect("The contract should exist");
+    let balances = balances
+        .into_iter()
+        .map(|balance| (balance.asset_id, balance.amount))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        balances,
+        vec![
+            (AssetId::new([1u8; 32]), 1000),
+            (AssetId::new([2u8; 32]), 400)
+        ]
+    );
+
+    let unknown_contract = client
+        .contract_all_balances(&ContractId::new([9u8; 32]))
+        .await
+        .unwrap();
+    assert!(unknown_contract.is_none());
+}
+
 fn key(i: u8) -> Bytes32 {
     Bytes32::new(
         [0u8; 31]
@@ -3,9 +3,17 @@ use fuel_core::{
     service::{
         Config,
         FuelService,
+        ServiceTrait,
     },
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::TransactionBuilder,
+};
 
 #[tokio::test]
 async fn health() {
@@ -18,6 +26,31 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn await_ready_before_submit() {
+    let srv = FuelService::new(Database::default(), Config::local_node()).unwrap();
+    assert!(!srv.is_ready());
+
+    srv.start().unwrap();
+    srv.await_ready().await.unwrap();
+    assert!(srv.is_ready());
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    srv.submit(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn await_ready_fails_if_node_is_stopped() {
+    let srv = FuelService::new(Database::default(), Config::local_node()).unwrap();
+    srv.stop();
+
+    assert!(srv.await_ready().await.is_err());
+    assert!(!srv.is_ready());
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -26,7 +59,6 @@ async fn can_restart_node() {
 
     // start node once
     {
-        use fuel_core::service::ServiceTrait;
         let database = Database::open(tmp_dir.path(), None).unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
@@ -28,7 +28,27 @@ use rand::{
     Rng,
     SeedableRng,
 };
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt,
+    net::IpAddr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    span,
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::Context,
+    Layer,
+};
 
 /// Helper for wrapping a currently running node environment
 pub struct TestContext {
@@ -55,6 +75,8 @@ pub struct TestSetupBuilder {
     pub gas_limit: u64,
     pub starting_block: Option<BlockHeight>,
     pub utxo_validation: bool,
+    pub trusted_submitters: Vec<IpAddr>,
+    pub dry_run_trace_enabled: bool,
 }
 
 impl TestSetupBuilder {
@@ -158,6 +180,8 @@ impl TestSetupBuilder {
         chain_config.block_gas_limit = self.gas_limit;
         let config = Config {
             utxo_validation: self.utxo_validation,
+            trusted_submitters: self.trusted_submitters.clone(),
+            dry_run_trace_enabled: self.dry_run_trace_enabled,
             txpool: fuel_core_txpool::Config {
                 chain_config: chain_config.clone(),
                 min_gas_price: self.min_gas_price,
@@ -188,6 +212,72 @@ impl Default for TestSetupBuilder {
             gas_limit: u64::MAX,
             starting_block: None,
             utxo_validation: true,
+            trusted_submitters: vec![],
+            dry_run_trace_enabled: false,
+        }
+    }
+}
+
+/// The span recorded by the [`SpanCapture`], with the debug representation
+/// of its recorded fields.
+#[derive(Clone, Debug)]
+pub struct CapturedSpan {
+    pub name: &'static str,
+    pub fields: HashMap<String, String>,
+}
+
+impl Visit for CapturedSpan {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+/// The tracing layer that records all spans with their fields, to inspect them
+/// in the tests.
+#[derive(Clone, Default)]
+pub struct SpanCapture {
+    spans: Arc<Mutex<Vec<(span::Id, CapturedSpan)>>>,
+}
+
+impl SpanCapture {
+    /// Returns the captured spans with the `name`, in the order of their creation.
+    pub fn spans(&self, name: &str) -> Vec<CapturedSpan> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, span)| span.name == name)
+            .map(|(_, span)| span.clone())
+            .collect()
+    }
+}
+
+impl<S: Subscriber> Layer<S> for SpanCapture {
+    fn on_new_span(
+        &self,
+        attrs: &span::Attributes<'_>,
+        id: &span::Id,
+        _: Context<'_, S>,
+    ) {
+        let mut span = CapturedSpan {
+            name: attrs.metadata().name(),
+            fields: HashMap::new(),
+        };
+        attrs.record(&mut span);
+        self.spans.lock().unwrap().push((id.clone(), span));
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, _: Context<'_, S>) {
+        if let Some((_, span)) = self
+            .spans
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .rev()
+            .find(|(span_id, _)| span_id == id)
+        {
+            values.record(span);
         }
     }
 }
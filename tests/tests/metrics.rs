@@ -70,3 +70,79 @@ async fn test_metrics_endpoint() {
         assert!(value >= 0.0);
     }
 }
+
+/// Returns the number of the database reads reported by the `/metrics` endpoint.
+async fn database_reads(srv: &FuelService) -> f64 {
+    let resp = reqwest::get(format!("http://{}/metrics", srv.bound_address))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    resp.lines()
+        .find(|line| line.starts_with("Database_Reads"))
+        .and_then(|line| line.split(' ').nth(1))
+        .unwrap()
+        .parse()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn id_only_transactions_query_skips_reading_transactions() {
+    let mut config = Config::local_node();
+    let tmp_dir = TempDir::new().unwrap();
+    config.database_type = DbType::RocksDb;
+    config.database_path = tmp_dir.path().to_path_buf();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    const TXS: usize = 10;
+    for _ in 0..TXS {
+        let tx =
+            TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+                .gas_limit(1_000_000)
+                .add_random_fee_input()
+                .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+
+    let query = |selection: &str| {
+        let query =
+            format!("{{ transactions(first: 100) {{ nodes {{ {selection} }} }} }}");
+        let query = serde_json::json!({ "query": query });
+        let srv = &srv;
+        async move {
+            let before = database_reads(srv).await;
+            let response = reqwest::Client::new()
+                .post(format!("http://{}/graphql", srv.bound_address))
+                .header("Content-Type", "application/json")
+                .body(query.to_string())
+                .send()
+                .await
+                .unwrap()
+                .text()
+                .await
+                .unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            let after = database_reads(srv).await;
+            let nodes = response["data"]["transactions"]["nodes"]
+                .as_array()
+                .unwrap();
+            (nodes.len(), after - before)
+        }
+    };
+
+    let (id_only_txs, id_only_reads) = query("id").await;
+    let (full_txs, full_reads) = query("id rawPayload").await;
+
+    // Each block also has the mint transaction
+    assert!(id_only_txs >= TXS);
+    assert_eq!(id_only_txs, full_txs);
+    // The full query reads each of the transactions
+    assert!(
+        full_reads - id_only_reads >= full_txs as f64,
+        "id only: {id_only_reads}, full: {full_reads}"
+    );
+
+    srv.stop_and_await().await.unwrap();
+}
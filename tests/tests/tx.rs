@@ -1,12 +1,20 @@
-use crate::helpers::TestContext;
+use crate::helpers::{
+    SpanCapture,
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     database::Database,
     executor::Executor,
-    schema::tx::receipt::all_receipts,
+    schema::tx::{
+        receipt::all_receipts,
+        MAX_CONFIRMATION_TIME_SCAN_BLOCKS,
+    },
     service::{
         adapters::MaybeRelayerAdapter,
         Config,
         FuelService,
+        QueryError,
     },
 };
 use fuel_core_client::client::{
@@ -14,9 +22,20 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        AssetAmount,
+        BalanceChange,
+        BatchSubmissionCategory,
+        CallFrameOutcome,
+        InputUtxoState,
+        OwnerRole,
+        ResubmissionStatus,
+        TransactionOrder,
+        TransactionStatus,
+    },
     FuelClient,
 };
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::{
     blockchain::{
         block::PartialFuelBlock,
@@ -26,11 +45,17 @@ use fuel_core_types::{
         },
     },
     fuel_asm::*,
+    fuel_crypto::SecretKey,
     fuel_tx,
     fuel_tx::*,
+    fuel_types::bytes::{
+        Deserializable,
+        SerializableVec,
+    },
     services::executor::ExecutionBlock,
     tai64::Tai64,
 };
+use futures::TryStreamExt;
 use itertools::Itertools;
 use rand::{
     prelude::StdRng,
@@ -39,7 +64,12 @@ use rand::{
 };
 use std::{
     io,
-    io::ErrorKind::NotFound,
+    io::{
+        ErrorKind::NotFound,
+        Read,
+    },
+    iter,
+    net::Ipv4Addr,
 };
 
 mod predicates;
@@ -114,42 +144,95 @@ async fn dry_run_script() {
 }
 
 #[tokio::test]
-async fn dry_run_create() {
-    let mut rng = StdRng::seed_from_u64(2322);
+async fn dry_run_receipts_end_with_script_result_of_finalization() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
     let client = FuelClient::from(srv.bound_address);
+    let mut rng = StdRng::seed_from_u64(2322);
 
-    let salt: Salt = rng.gen();
-    let contract_code = vec![];
-    let contract = Contract::from(contract_code.clone());
-    let root = contract.root();
-    let state_root = Contract::default_state_root();
-    let contract_id = contract.id(&salt, &root, &state_root);
-
-    let tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+    let gas_limit = 10_000;
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(gas_limit)
         .add_random_fee_input()
-        .add_output(Output::contract_created(contract_id, state_root))
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
         .finalize_as_transaction();
 
     let receipts = client.dry_run(&tx).await.unwrap();
-    assert_eq!(0, receipts.len());
 
-    // ensure the tx isn't available in the blockchain history
-    let err = client
-        .transaction_status(&tx.id(&fuel_tx::ConsensusParameters::DEFAULT.chain_id))
-        .await
-        .unwrap_err();
-    assert_eq!(err.kind(), NotFound);
+    // The receipt of the script is followed by the `ScriptResult` of the whole
+    // transaction, and the computation of the change emits no further receipts
+    assert_eq!(receipts.len(), 2);
+    assert!(matches!(receipts[0], Receipt::Return { val: 1, .. }));
+    assert!(matches!(
+        receipts[1],
+        Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used,
+        } if gas_used > 0 && gas_used <= gas_limit
+    ));
 }
 
 #[tokio::test]
-async fn submit() {
+async fn dry_run_raw_receipts_decode_to_the_same_receipts() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
     let client = FuelClient::from(srv.bound_address);
 
-    let gas_price = Default::default();
-    let gas_limit = 1_000_000;
-    let maturity = Default::default();
+    // Logs 4 bytes of the script data
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, RegId::ZERO, 4),
+        op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![1, 2, 3, 4])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let decoded = client.dry_run(&tx).await.unwrap();
+    let raw = client.dry_run_raw(&tx, None).await.unwrap();
+    assert!(decoded
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::LogData { .. })));
+    assert_eq!(raw, decoded);
+}
+
+#[tokio::test]
+async fn dry_run_is_traced_with_timings() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    // The test runtime is single-threaded, so the node reports to this subscriber
+    let capture = SpanCapture::default();
+    let _guard = tracing::subscriber::set_default(
+        tracing_subscriber::registry().with(capture.clone()),
+    );
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ConsensusParameters::DEFAULT.chain_id).to_string();
+
+    client.dry_run(&tx).await.unwrap();
+    let spans = capture.spans("dry_run");
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].fields.get("tx_id"), Some(&tx_id));
+    assert!(spans[0].fields.contains_key("decode_time_us"));
+    assert!(spans[0].fields.contains_key("vm_time_us"));
+
+    srv.dry_run(tx, None).await.unwrap();
+    let spans = capture.spans("dry_run");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[1].fields.get("tx_id"), Some(&tx_id));
+    assert!(spans[1].fields.contains_key("vm_time_us"));
+}
+
+#[tokio::test]
+async fn dry_run_gas_limit_override() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
 
     let script = vec![
         op::addi(0x10, RegId::ZERO, 0xca),
@@ -162,161 +245,3112 @@ async fn submit() {
         .flat_map(|op| u32::from(*op).to_be_bytes())
         .collect();
 
+    // The own gas limit of the transaction is too low to run the script
     let tx = TransactionBuilder::script(script, vec![])
-        .gas_limit(gas_limit)
-        .gas_price(gas_price)
-        .maturity(maturity)
+        .gas_limit(1)
         .add_random_fee_input()
         .finalize_as_transaction();
 
-    client.submit_and_await_commit(&tx).await.unwrap();
-    // verify that the tx returned from the api matches the submitted tx
-    let ret_tx = client
-        .transaction(&tx.id(&ConsensusParameters::DEFAULT.chain_id))
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Panic,
+            ..
+        })
+    ));
+
+    let receipts = client
+        .dry_run_with_gas_limit(&tx, None, 1_000_000)
         .await
-        .unwrap()
-        .unwrap()
-        .transaction;
-    assert_eq!(
-        tx.id(&ConsensusParameters::DEFAULT.chain_id),
-        ret_tx.id(&ConsensusParameters::DEFAULT.chain_id)
-    );
+        .unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
 }
 
-#[ignore]
 #[tokio::test]
-async fn transaction_status_submitted() {
-    // This test should ensure a transaction's status is Submitted while it is in the mempool
-    // This test should also ensure a transaction's time of submission is correct in the returned status
-    // Currently blocked until https://github.com/FuelLabs/fuel-core/issues/50 is resolved
-    // as execution must be separate from submission for a tx to persist inside of the txpool
-    // Merge with the submit_utxo_verified_tx test once utxo_verification is the default
-    todo!();
+async fn dry_run_at_time_reaches_both_branches_of_time_check() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Returns `1` if the time of the block is before the threshold from the script data
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::lw(0x10, 0x10, 0),
+        op::bhei(0x11),
+        op::time(0x12, 0x11),
+        op::lt(0x13, 0x12, 0x10),
+        op::ret(0x13),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let now = Tai64::now();
+    let threshold = Tai64(now.0 + 60 * 60);
+    let tx = TransactionBuilder::script(script, threshold.0.to_be_bytes().to_vec())
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let returned = |receipts: Vec<Receipt>| {
+        receipts.iter().find_map(|receipt| match receipt {
+            Receipt::Return { val, .. } => Some(*val),
+            _ => None,
+        })
+    };
+    let receipts = client.dry_run_at_time(&tx, None, now).await.unwrap();
+    assert_eq!(returned(receipts), Some(1));
+    let later = Tai64(threshold.0 + 60 * 60);
+    let receipts = client.dry_run_at_time(&tx, None, later).await.unwrap();
+    assert_eq!(returned(receipts), Some(0));
+
+    let err = client
+        .dry_run_at_time(&tx, None, Tai64::from_unix(0))
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("before the genesis block time"),
+        "got unexpected error {err}"
+    );
 }
 
 #[tokio::test]
-async fn receipts() {
-    let transaction = Transaction::default_test_tx();
-    let id = transaction.id(&ConsensusParameters::DEFAULT.chain_id);
-    // setup server & client
-    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+async fn dry_run_rejected_when_concurrency_limit_is_reached() {
+    let mut config = Config::local_node();
+    config.max_concurrent_dry_runs = 0;
+    let srv = FuelService::new_node(config).await.unwrap();
     let client = FuelClient::from(srv.bound_address);
-    // submit tx
-    client
-        .submit_and_await_commit(&transaction)
-        .await
-        .expect("transaction should insert");
-    // run test
-    let receipts = client.receipts(&id).await.unwrap();
-    assert!(receipts.is_some());
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let err = client.dry_run(&tx).await.unwrap_err();
+    assert!(
+        err.to_string().contains("dry-runs are already executing"),
+        "got unexpected error {err}"
+    );
+    let err = client.dry_run_many(&[tx], None, None).await.unwrap_err();
+    assert!(
+        err.to_string().contains("dry-runs are already executing"),
+        "got unexpected error {err}"
+    );
 }
 
 #[tokio::test]
-async fn receipts_decoding() {
+async fn dry_run_of_committed_transaction_reports_its_block() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
     let client = FuelClient::from(srv.bound_address);
 
-    let actual_receipts = client.all_receipts().await.unwrap();
-    assert_eq!(actual_receipts, all_receipts())
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let err = client.dry_run(&tx).await.unwrap_err();
+    assert!(
+        err.to_string().contains("is already committed at block 1"),
+        "got unexpected error {err}"
+    );
 }
 
-#[tokio::test]
-async fn get_transaction_by_id() {
-    // setup test data in the node
-    let transaction = Transaction::default_test_tx();
-    let id = transaction.id(&ConsensusParameters::DEFAULT.chain_id);
+/// The script that jumps to itself until it runs out of the maximum gas of the chain.
+fn long_running_script_tx() -> Transaction {
+    let script = vec![op::movi(0x10, 1), op::jmp(0x10)];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    TransactionBuilder::script(script, vec![])
+        .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx)
+        .add_random_fee_input()
+        .finalize_as_transaction()
+}
 
-    // setup server & client
-    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+#[tokio::test]
+async fn dry_run_above_limit_is_rejected_while_another_one_runs() {
+    let mut config = Config::local_node();
+    config.max_concurrent_dry_runs = 1;
+    let srv = FuelService::new_node(config).await.unwrap();
     let client = FuelClient::from(srv.bound_address);
-    // submit tx to api
-    client.submit_and_await_commit(&transaction).await.unwrap();
 
-    // run test
-    let transaction_response = client.transaction(&id).await.unwrap();
-    assert!(transaction_response.is_some());
-    if let Some(transaction_response) = transaction_response {
-        assert!(matches!(
-            transaction_response.status,
-            TransactionStatus::Success { .. }
-        ))
-    }
+    let long_running = tokio::spawn({
+        let client = client.clone();
+        let tx = long_running_script_tx();
+        async move { client.dry_run(&tx).await }
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let err = client.dry_run(&tx).await.unwrap_err();
+    assert!(
+        err.to_string().contains("dry-runs are already executing"),
+        "got unexpected error {err}"
+    );
+    assert!(!long_running.is_finished());
+
+    // The permit is released when the dry-run finishes
+    long_running.await.unwrap().unwrap();
+    client.dry_run(&tx).await.unwrap();
 }
 
 #[tokio::test]
-async fn get_transparent_transaction_by_id() {
-    let transaction = Transaction::default_test_tx();
-    let id = transaction.id(&ConsensusParameters::DEFAULT.chain_id);
+async fn dry_run_permit_is_held_until_timed_out_execution_stops() {
+    let mut config = Config::local_node();
+    config.max_concurrent_dry_runs = 1;
+    config.dry_run_timeout = Some(std::time::Duration::from_millis(100));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
 
-    // setup server & client
-    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    // The batch dry-run can't stop the VM, so it keeps running after the timeout
+    let err = client
+        .dry_run_many(&[long_running_script_tx()], None, None)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("exceeded the time limit"),
+        "got unexpected error {err}"
+    );
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let err = client.dry_run_many(&[tx], None, None).await.unwrap_err();
+    assert!(
+        err.to_string().contains("dry-runs are already executing"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn dry_run_timeout_fails_long_running_script() {
+    let mut config = Config::local_node();
+    config.dry_run_timeout = Some(std::time::Duration::from_millis(500));
+    let srv = FuelService::new_node(config).await.unwrap();
     let client = FuelClient::from(srv.bound_address);
 
-    // submit tx
-    let result = client.submit_and_await_commit(&transaction).await;
-    assert!(result.is_ok());
+    // The script that finishes in time is dry-run as usual
+    let tx = create_mock_tx(0);
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult { .. })
+    ));
 
-    let opaque_tx = client
-        .transaction(&id)
+    // Jumps to itself until it runs out of gas
+    let script = vec![op::movi(0x10, 1), op::jmp(0x10)];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx: String = TransactionBuilder::script(script, vec![])
+        .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx)
+        .add_random_fee_input()
+        .finalize_as_transaction()
+        .to_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    let mutation = serde_json::json!({
+        "query": format!("mutation {{ dryRun(tx: \"0x{tx}\") {{ receiptType }} }}")
+    });
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .body(mutation.to_string())
+        .send()
         .await
         .unwrap()
-        .expect("expected some result")
-        .transaction;
-
-    // run test
-    let transparent_transaction = client
-        .transparent_transaction(&id)
+        .text()
         .await
-        .unwrap()
-        .expect("expected some value");
-
-    // verify transaction round-trips via transparent graphql
-    assert_eq!(opaque_tx, transparent_transaction);
+        .unwrap();
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    let error = &response["errors"][0];
+    assert!(
+        error["message"]
+            .as_str()
+            .unwrap()
+            .contains("exceeded the time limit of 500 milliseconds"),
+        "got unexpected error {error}"
+    );
+    assert_eq!(error["extensions"]["code"], "DRY_RUN_TIMEOUT");
 }
 
 #[tokio::test]
-async fn get_transactions() {
-    let alice = Address::from([1; 32]);
-    let bob = Address::from([2; 32]);
-    let charlie = Address::from([3; 32]);
+async fn dry_run_on_service_matches_graphql_dry_run() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
 
-    let mut context = TestContext::new(100).await;
-    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
-    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
-    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
-    let tx4 = context.transfer(bob, charlie, 3).await.unwrap();
-    let tx5 = context.transfer(charlie, alice, 1).await.unwrap();
-    let tx6 = context.transfer(alice, charlie, 1).await.unwrap();
+    let script = vec![
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    // The decoded transaction doesn't have the precomputed metadata
+    let not_precomputed = Transaction::from_bytes(&tx.clone().to_bytes()).unwrap();
 
-    // there are 12 transactions
-    // [
-    //  coinbase_tx1, tx1, coinbase_tx2, tx2, coinbase_tx3, tx3,
-    //  coinbase_tx4, tx4, coinbase_tx5, tx5, coinbase_tx6, tx6
-    // ]
+    let receipts = srv.dry_run(not_precomputed, None).await.unwrap();
+    assert_eq!(receipts, client.dry_run(&tx).await.unwrap());
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
 
-    // Query for first 6: [coinbase_tx1, tx1, coinbase_tx2, tx2, coinbase_tx3, tx3]
-    let client = context.client;
-    let page_request = PaginationRequest {
-        cursor: None,
-        results: 6,
-        direction: PageDirection::Forward,
-    };
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn dry_run_streaming_matches_dry_run() {
+    use futures::StreamExt;
 
-    let response = client.transactions(page_request.clone()).await.unwrap();
-    let transactions = &response
-        .results
-        .iter()
-        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
-        .collect_vec();
-    // coinbase_tx1
-    assert_eq!(transactions[1], tx1);
-    // coinbase_tx2
-    assert_eq!(transactions[3], tx2);
-    // coinbase_tx3
-    assert_eq!(transactions[5], tx3);
-    // Check pagination state for first page
-    assert!(response.has_next_page);
-    assert!(!response.has_previous_page);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = iter::repeat(op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO))
+        .take(10)
+        .chain(iter::once(op::ret(RegId::ONE)))
+        .collect_vec();
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let mut stream = client.dry_run_streaming(&tx).await.unwrap();
+    // The first log is delivered separately from the rest of the execution.
+    let first = stream.next().await.unwrap().unwrap();
+    assert!(matches!(first, Receipt::Log { .. }));
+    let rest: Vec<_> = stream
+        .map(|receipt| receipt.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert_eq!(rest.len() + 1, receipts.len());
+    assert_eq!(receipts[0], first);
+    assert!(matches!(
+        rest.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn dry_run_create() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let salt: Salt = rng.gen();
+    let contract_code = vec![];
+    let contract = Contract::from(contract_code.clone());
+    let root = contract.root();
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &root, &state_root);
+
+    let tx = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+
+    let receipts = client.dry_run(&tx).await.unwrap();
+    assert_eq!(0, receipts.len());
+
+    // ensure the tx isn't available in the blockchain history
+    let err = client
+        .transaction_status(&tx.id(&fuel_tx::ConsensusParameters::DEFAULT.chain_id))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), NotFound);
+}
+
+#[tokio::test]
+async fn dry_run_arithmetic_check_reports_overflowing_instruction() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![
+        op::not(0x10, RegId::ZERO),
+        op::add(0x11, 0x10, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let panic = client
+        .dry_run_arithmetic_check(&tx)
+        .await
+        .unwrap()
+        .expect("The script should overflow");
+    assert_eq!(panic.operation, "ADD");
+    assert_eq!(panic.contract_id, ContractId::zeroed());
+}
+
+#[tokio::test]
+async fn dry_run_arithmetic_check_returns_none_without_overflow() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![op::ret(RegId::ONE)];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let panic = client.dry_run_arithmetic_check(&tx).await.unwrap();
+    assert!(panic.is_none());
+}
+
+#[tokio::test]
+async fn dry_run_trace_records_executed_instructions() {
+    let mut config = Config::local_node();
+    config.dry_run_trace_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![
+        op::addi(0x10, RegId::ZERO, 5),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let trace = client.dry_run_trace(&tx, Some(&[1])).await.unwrap();
+
+    let opcodes: Vec<_> = trace
+        .steps
+        .iter()
+        .map(|step| step.opcode.as_str())
+        .collect();
+    assert_eq!(opcodes, vec!["ADDI", "LOG", "RET"]);
+    let indexes: Vec<_> = trace.steps.iter().map(|step| step.index).collect();
+    assert_eq!(indexes, vec![0, 1, 2]);
+    assert!(trace.steps[0].registers.is_none());
+    let registers = trace.steps[1]
+        .registers
+        .as_ref()
+        .expect("The registers should be recorded before the `LOG`");
+    assert_eq!(registers[0x10], 5);
+    assert!(!trace.truncated);
+    assert_eq!(
+        trace.state,
+        Some(fuel_core_types::fuel_vm::ProgramState::Return(1))
+    );
+    assert!(matches!(
+        trace.receipts.as_slice(),
+        [Receipt::Log { ra: 5, .. }, Receipt::Return { val: 1, .. }]
+    ));
+}
+
+#[tokio::test]
+async fn dry_run_coverage_reports_only_taken_branch() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    test_builder.dry_run_trace_enabled = true;
+    // `$one` is never zero, so the contract jumps over the `NOOP` and the `RVRT`
+    let contract_code = vec![
+        op::jnzi(RegId::ONE, 3),
+        op::noop(),
+        op::rvrt(RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let contract_code: Vec<u8> = contract_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let coverage = client.dry_run_coverage(&tx).await.unwrap();
+    assert!(!coverage.truncated);
+    assert_eq!(coverage.contracts.len(), 1);
+    assert_eq!(coverage.contracts[0].contract_id, contract_id);
+    assert_eq!(coverage.contracts[0].ranges, vec![0..4, 12..16]);
+}
+
+#[tokio::test]
+async fn dry_run_trace_requires_tracing_enabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let err = client.dry_run_trace(&tx, None).await.unwrap_err();
+    assert!(
+        err.to_string().contains("Tracing must be enabled"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn dry_run_detailed_decodes_contract_revert() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![op::movi(0x10, 42), op::rvrt(0x10)];
+    let contract_code: Vec<u8> = contract_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let details = client.dry_run_detailed(&tx, Some(false)).await.unwrap();
+    let revert_reason = details.revert_reason.expect("The contract should revert");
+    assert!(!revert_reason.is_panic);
+    assert_eq!(revert_reason.reason_code, 42);
+    assert_eq!(revert_reason.contract_id, contract_id);
+    assert!(details
+        .receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Revert { ra: 42, .. })));
+}
+
+#[tokio::test]
+async fn transactions_by_panic_reason_finds_failed_transactions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![
+        op::not(0x10, RegId::ZERO),
+        op::add(0x11, 0x10, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let panicking_tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let successful_tx =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+    client.submit_and_await_commit(&panicking_tx).await.unwrap();
+    client
+        .submit_and_await_commit(&successful_tx)
+        .await
+        .unwrap();
+
+    let tx_ids = client
+        .transactions_by_panic_reason(PanicReason::ArithmeticOverflow, 10)
+        .await
+        .unwrap();
+    assert_eq!(
+        tx_ids,
+        vec![panicking_tx.id(&ConsensusParameters::DEFAULT.chain_id)]
+    );
+
+    let tx_ids = client
+        .transactions_by_panic_reason(PanicReason::OutOfGas, 10)
+        .await
+        .unwrap();
+    assert!(tx_ids.is_empty());
+
+    // The panicking transaction is outside of the scanned window
+    let tx_ids = client
+        .transactions_by_panic_reason(PanicReason::ArithmeticOverflow, 1)
+        .await
+        .unwrap();
+    assert!(tx_ids.is_empty());
+
+    // The window is bounded
+    let result = client
+        .transactions_by_panic_reason(PanicReason::ArithmeticOverflow, u32::MAX)
+        .await;
+    assert!(result.is_err());
+
+    // The reason must be one of the known panic reasons
+    let query = serde_json::json!({
+        "query": "{ transactionsByPanicReason(reason: \"NotAReason\", blocks: 1) }"
+    });
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .body(query.to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(
+        response.contains("Unknown panic reason `NotAReason`"),
+        "got {response}"
+    );
+}
+
+#[tokio::test]
+async fn dry_run_storage_delta_reports_prior_and_new_values() {
+    let word_slot = |word: u64| {
+        let mut slot = [0u8; 32];
+        slot[..8].copy_from_slice(&word.to_be_bytes());
+        Bytes32::new(slot)
+    };
+
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![
+        // Increment the existing slot with the key equal to the contract id
+        op::srw(0x10, 0x11, RegId::FP),
+        op::addi(0x10, 0x10, 1),
+        op::sww(RegId::FP, 0x11, 0x10),
+        // Create the new slot with the zeroed key
+        op::movi(0x12, 32),
+        op::aloc(0x12),
+        op::move_(0x13, RegId::HP),
+        op::sww(0x13, 0x11, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let contract_code: Vec<u8> = contract_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    test_builder.contracts.get_mut(&contract_id).unwrap().state =
+        Some(vec![(Bytes32::new(*contract_id), word_slot(1))]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let deltas = client
+        .dry_run_storage_delta(&tx, Some(false))
+        .await
+        .unwrap();
+    assert_eq!(deltas.len(), 2);
+    // The newly created slot
+    assert_eq!(deltas[0].contract_id, contract_id);
+    assert_eq!(deltas[0].key, Bytes32::zeroed());
+    assert_eq!(deltas[0].before, None);
+    assert_eq!(deltas[0].after, word_slot(1));
+    // The modified slot
+    assert_eq!(deltas[1].contract_id, contract_id);
+    assert_eq!(deltas[1].key, Bytes32::new(*contract_id));
+    assert_eq!(deltas[1].before, Some(word_slot(1)));
+    assert_eq!(deltas[1].after, word_slot(2));
+
+    // The dry run doesn't change the state
+    let repeated = client
+        .dry_run_storage_delta(&tx, Some(false))
+        .await
+        .unwrap();
+    assert_eq!(repeated, deltas);
+}
+
+#[tokio::test]
+async fn dry_run_with_forced_failure_reverts_calls_to_contract() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract_code = op::ret(RegId::ONE).to_bytes().to_vec();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+    let contract_returned = |receipts: &[Receipt]| {
+        receipts.iter().any(
+            |receipt| matches!(receipt, Receipt::Return { id, .. } if *id == contract_id),
+        )
+    };
+    let contract_reverted = |receipts: &[Receipt]| {
+        receipts.iter().any(
+            |receipt| matches!(receipt, Receipt::Revert { id, .. } if *id == contract_id),
+        )
+    };
+
+    let receipts = client
+        .dry_run_with_forced_failure(&tx, &contract_id, Some(false))
+        .await
+        .unwrap();
+    assert!(contract_reverted(&receipts), "{receipts:?}");
+
+    // Other contracts don't affect the execution
+    let receipts = client
+        .dry_run_with_forced_failure(&tx, &ContractId::from([1; 32]), Some(false))
+        .await
+        .unwrap();
+    assert!(contract_returned(&receipts), "{receipts:?}");
+
+    // The contract isn't changed by the forced failure
+    let receipts = client.dry_run_opt(&tx, Some(false)).await.unwrap();
+    assert!(contract_returned(&receipts), "{receipts:?}");
+}
+
+#[tokio::test]
+async fn dry_run_events_annotates_logs_with_call_context() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![
+        op::movi(0x10, 7),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let contract_code: Vec<u8> = contract_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::movi(0x11, 3),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let events = client.dry_run_events(&tx, Some(false)).await.unwrap();
+    assert_eq!(events.len(), 2);
+
+    assert_eq!(events[0].contract_id, ContractId::zeroed());
+    assert_eq!(events[0].call_depth, 0);
+    assert!(!events[0].is_log_data);
+    assert_eq!(events[0].data[..8], 3u64.to_be_bytes());
+
+    assert_eq!(events[1].contract_id, contract_id);
+    assert_eq!(events[1].call_depth, 1);
+    assert!(!events[1].is_log_data);
+    assert_eq!(events[1].data[..8], 7u64.to_be_bytes());
+}
+
+#[tokio::test]
+async fn dry_run_execution_graph_nests_contract_call_under_script() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![
+        op::movi(0x10, 7),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::rvrt(RegId::ONE),
+    ];
+    let contract_code: Vec<u8> = contract_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::movi(0x11, 3),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, Call::new(contract_id, 0, 0).to_bytes())
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let graph = client
+        .dry_run_execution_graph(&tx, Some(false))
+        .await
+        .unwrap();
+    assert!(graph.gas_used > 0);
+
+    // The script doesn't reach its `RET` because the call reverts
+    let root = graph.root;
+    assert_eq!(root.contract_id, ContractId::zeroed());
+    assert_eq!(root.outcome, CallFrameOutcome::Incomplete);
+    assert_eq!(root.logs.len(), 1);
+    assert_eq!(root.logs[0].data[..8], 3u64.to_be_bytes());
+    assert_eq!(root.calls.len(), 1);
+
+    let call = &root.calls[0];
+    assert_eq!(call.contract_id, contract_id);
+    assert_eq!(call.outcome, CallFrameOutcome::Revert);
+    assert_eq!(call.logs.len(), 1);
+    assert_eq!(call.logs[0].call_depth, 1);
+    assert_eq!(call.logs[0].data[..8], 7u64.to_be_bytes());
+    assert!(call.calls.is_empty());
+    assert!(call.transfers.is_empty());
+}
+
+#[tokio::test]
+async fn dry_run_detailed_reports_gas_of_nested_calls() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let inner_code = op::ret(RegId::ONE).to_bytes().to_vec();
+    let (_, inner_id) = test_builder.setup_contract(inner_code, None, None, None);
+    // The outer contract calls the one placed after its own `Call` in the script data
+    let call_size = Call::new(ContractId::zeroed(), 0, 0).to_bytes().len();
+    let outer_code = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::addi(0x10, 0x10, call_size as u16),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let outer_code: Vec<u8> = outer_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, outer_id) = test_builder.setup_contract(outer_code, None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let mut script_data = Call::new(outer_id, 0, 0).to_bytes();
+    script_data.extend(Call::new(inner_id, 0, 0).to_bytes());
+
+    let gas_limit = 1_000_000;
+    let tx = TransactionBuilder::script(script, script_data)
+        .gas_limit(gas_limit)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            outer_id,
+        ))
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            inner_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let details = client.dry_run_detailed(&tx, Some(false)).await.unwrap();
+    assert!(details.revert_reason.is_none());
+
+    let breakdown = details.gas_breakdown;
+    assert_eq!(breakdown.gas_limit, gas_limit);
+    assert!(breakdown.gas_used > 0);
+    assert_eq!(breakdown.gas_used + breakdown.gas_refunded, gas_limit);
+    assert_eq!(breakdown.calls.len(), 2);
+
+    let (outer, inner) = (&breakdown.calls[0], &breakdown.calls[1]);
+    assert_eq!((outer.contract_id, outer.depth), (outer_id, 0));
+    assert_eq!((inner.contract_id, inner.depth), (inner_id, 1));
+    assert!(outer.returned && inner.returned);
+    // Each call can use only the gas left to its caller
+    assert!(outer.gas_forwarded < gas_limit);
+    assert!(inner.gas_forwarded < outer.gas_forwarded);
+}
+
+#[tokio::test]
+async fn dry_run_find_min_gas_discovers_gas_used_by_script() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The script uses much more gas than a single instruction
+    let script: Vec<u8> = iter::repeat(op::noop())
+        .take(100)
+        .chain(iter::once(op::ret(RegId::ONE)))
+        .flat_map(|op| u32::from(op).to_be_bytes())
+        .collect();
+    let script_with_limit = |gas_limit: u64| {
+        TransactionBuilder::script(script.clone(), vec![])
+            .gas_limit(gas_limit)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    };
+
+    let expected = client
+        .dry_run_detailed(&script_with_limit(1_000_000), None)
+        .await
+        .unwrap()
+        .gas_breakdown
+        .gas_used;
+
+    // The search starts from too low and too high limits
+    for gas_limit in [1, 1_000_000] {
+        let details = client
+            .dry_run_find_min_gas(&script_with_limit(gas_limit), None)
+            .await
+            .unwrap();
+        assert_eq!(details.min_gas_limit, Some(expected));
+    }
+
+    let details = client
+        .dry_run_detailed(&script_with_limit(expected - 1), None)
+        .await
+        .unwrap();
+    assert!(details.revert_reason.is_some());
+}
+
+#[tokio::test]
+async fn dry_run_detailed_decodes_panic_reason() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![
+        op::not(0x10, RegId::ZERO),
+        op::add(0x11, 0x10, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let details = client.dry_run_detailed(&tx, None).await.unwrap();
+    let revert_reason = details.revert_reason.expect("The script should panic");
+    assert!(revert_reason.is_panic);
+    assert_eq!(
+        revert_reason.reason_code,
+        PanicReason::ArithmeticOverflow as u8 as u64
+    );
+    assert_eq!(revert_reason.reason, "An arithmetic operation overflowed");
+    assert_eq!(revert_reason.contract_id, ContractId::zeroed());
+}
+
+#[tokio::test]
+async fn dry_run_detailed_reports_effective_utxo_validation() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = true;
+    let secret_key: SecretKey = rng.gen();
+    let amount = 1000;
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret_key,
+            rng.gen(),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.gen(), amount, AssetId::BASE))
+        .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+    let tx: Transaction = tx.into();
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // Defaults to the configuration of the node
+    let details = client.dry_run_detailed(&tx, None).await.unwrap();
+    assert!(details.utxo_validation);
+
+    let details = client.dry_run_detailed(&tx, Some(false)).await.unwrap();
+    assert!(!details.utxo_validation);
+
+    let details = client.dry_run_detailed(&tx, Some(true)).await.unwrap();
+    assert!(details.utxo_validation);
+}
+
+#[tokio::test]
+async fn dry_run_summary_reports_net_amounts_for_owner() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let sender = Input::owner(&secret.public_key());
+    let recipient: Address = rng.gen();
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(recipient, 100, AssetId::BASE))
+        .add_output(Output::change(sender, 0, AssetId::BASE))
+        .finalize_as_transaction();
+
+    let summary = client.dry_run_summary(&tx, &sender).await.unwrap();
+    assert_eq!(summary.fee, 0);
+    assert_eq!(
+        summary.sends,
+        vec![AssetAmount {
+            asset_id: AssetId::BASE,
+            amount: 100
+        }]
+    );
+    assert!(summary.receives.is_empty());
+    assert!(summary.contracts_called.is_empty());
+
+    let summary = client.dry_run_summary(&tx, &recipient).await.unwrap();
+    assert!(summary.sends.is_empty());
+    assert_eq!(
+        summary.receives,
+        vec![AssetAmount {
+            asset_id: AssetId::BASE,
+            amount: 100
+        }]
+    );
+}
+
+#[tokio::test]
+async fn dry_run_detailed_reports_balance_changes_of_owner() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let counterparty_secret = SecretKey::random(&mut rng);
+    let counterparty = Input::owner(&counterparty_secret.public_key());
+    let sold: AssetId = rng.gen();
+    let bought: AssetId = rng.gen();
+    // The owner pays with the base asset and the `sold` asset for the `bought` asset
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            500,
+            sold,
+            Default::default(),
+            Default::default(),
+        )
+        .add_unsigned_coin_input(
+            counterparty_secret,
+            rng.gen(),
+            300,
+            bought,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(counterparty, 100, AssetId::BASE))
+        .add_output(Output::coin(counterparty, 200, sold))
+        .add_output(Output::coin(owner, 300, bought))
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .add_output(Output::change(owner, 0, sold))
+        .finalize_as_transaction();
+
+    let details = client.dry_run_detailed(&tx, None).await.unwrap();
+    assert!(details.balance_changes.is_none());
+
+    let details = client
+        .dry_run_detailed_for_owner(&tx, None, Some(&owner))
+        .await
+        .unwrap();
+    let mut expected = vec![
+        BalanceChange {
+            asset_id: AssetId::BASE,
+            sent: 100,
+            received: 0,
+        },
+        BalanceChange {
+            asset_id: sold,
+            sent: 200,
+            received: 0,
+        },
+        BalanceChange {
+            asset_id: bought,
+            sent: 0,
+            received: 300,
+        },
+    ];
+    expected.sort_by_key(|change| change.asset_id);
+    assert_eq!(details.balance_changes, Some(expected));
+}
+
+#[tokio::test]
+async fn submit() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let gas_price = Default::default();
+    let gas_limit = 1_000_000;
+    let maturity = Default::default();
+
+    let script = vec![
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(gas_limit)
+        .gas_price(gas_price)
+        .maturity(maturity)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    client.submit_and_await_commit(&tx).await.unwrap();
+    // verify that the tx returned from the api matches the submitted tx
+    let ret_tx = client
+        .transaction(&tx.id(&ConsensusParameters::DEFAULT.chain_id))
+        .await
+        .unwrap()
+        .unwrap()
+        .transaction;
+    assert_eq!(
+        tx.id(&ConsensusParameters::DEFAULT.chain_id),
+        ret_tx.id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+}
+
+#[tokio::test]
+async fn submit_and_await_confirmations_waits_for_blocks_on_top() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Interval {
+        block_time: std::time::Duration::from_millis(100),
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = create_mock_tx(0);
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let status = srv.submit_and_await_confirmations(tx, 2).await.unwrap();
+    assert!(matches!(
+        status,
+        fuel_core::schema::tx::types::TransactionStatus::Success(_)
+            | fuel_core::schema::tx::types::TransactionStatus::Failed(_)
+    ));
+
+    let block_id = match srv.shared.database.get_tx_status(&id).unwrap() {
+        Some(
+            fuel_core_types::services::txpool::TransactionStatus::Success {
+                block_id,
+                ..
+            }
+            | fuel_core_types::services::txpool::TransactionStatus::Failed {
+                block_id, ..
+            },
+        ) => block_id,
+        status => panic!("The transaction should be included: {status:?}"),
+    };
+    let inclusion_height = client
+        .block(&block_id.into())
+        .await
+        .unwrap()
+        .unwrap()
+        .header
+        .height;
+    let latest_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert!(latest_height >= inclusion_height + 2);
+}
+
+#[tokio::test]
+async fn submit_and_await_confirmations_with_zero_confirmations_returns_on_inclusion() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let status = srv
+        .submit_and_await_confirmations(create_mock_tx(0), 0)
+        .await
+        .unwrap();
+    assert!(matches!(
+        status,
+        fuel_core::schema::tx::types::TransactionStatus::Success(_)
+            | fuel_core::schema::tx::types::TransactionStatus::Failed(_)
+    ));
+}
+
+#[tokio::test]
+async fn submit_and_await_delivers_status_of_instantly_committed_tx() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // with the instant trigger the block is produced right after insertion,
+    // so the terminal status must not be lost before the client listens
+    for i in 0..10 {
+        let status = client
+            .submit_and_await_commit(&create_mock_tx(i))
+            .await
+            .unwrap();
+        assert!(matches!(
+            status,
+            TransactionStatus::Success { .. } | TransactionStatus::Failed { .. }
+        ));
+    }
+}
+
+#[tokio::test]
+async fn prevalidated_transaction_is_committed() {
+    use futures::StreamExt;
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let tx: Transaction =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let statuses = srv.transaction_status_change(id).await;
+    srv.submit_prevalidated(tx.clone()).unwrap();
+    let statuses = statuses.filter(|status| {
+        futures::future::ready(!matches!(
+            status,
+            Ok(fuel_core::schema::tx::types::TransactionStatus::Submitted(
+                _
+            ))
+        ))
+    });
+    futures::pin_mut!(statuses);
+    let status = statuses.next().await.unwrap().unwrap();
+    assert!(matches!(
+        status,
+        fuel_core::schema::tx::types::TransactionStatus::Success(_)
+    ));
+    assert_eq!(srv.transaction(id).unwrap(), Some(tx));
+
+    // The fast path still rejects the transactions that can't be in the txpool
+    let mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+    assert!(srv.submit_prevalidated(mint).is_err());
+}
+
+fn false_predicate_tx(rng: &mut StdRng) -> Transaction {
+    let predicate = op::ret(RegId::ZERO).to_bytes().to_vec();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let asset_id = rng.gen();
+    TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn submit_prevalidated_skips_predicates_for_trusted_submitter() {
+    use futures::StreamExt;
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let tx = false_predicate_tx(&mut rng);
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    let context = TestSetupBuilder {
+        trusted_submitters: vec![Ipv4Addr::LOCALHOST.into()],
+        ..Default::default()
+    }
+    .config_coin_inputs_from_transactions(&[tx.as_script().unwrap()])
+    .finalize()
+    .await;
+
+    // The regular submission executes the predicate and rejects the transaction
+    assert!(context.client.submit(&tx).await.is_err());
+
+    let statuses = context
+        .client
+        .subscribe_transaction_status(&id)
+        .await
+        .unwrap();
+    let insertion = context.client.submit_prevalidated(&tx).await.unwrap();
+    assert!(insertion.inserted);
+
+    // The block producer verifies the predicate and skips the transaction
+    let statuses = statuses.filter(|status| {
+        futures::future::ready(!matches!(status, Ok(TransactionStatus::Submitted { .. })))
+    });
+    futures::pin_mut!(statuses);
+    let status = statuses.next().await.unwrap().unwrap();
+    assert!(
+        matches!(status, TransactionStatus::SqueezedOut { .. }),
+        "{status:?}"
+    );
+    assert!(context.client.transaction(&id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn submit_prevalidated_is_rejected_for_untrusted_submitter() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let tx = false_predicate_tx(&mut rng);
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[tx.as_script().unwrap()])
+        .finalize()
+        .await;
+
+    let err = context.client.submit_prevalidated(&tx).await.unwrap_err();
+    assert!(err.to_string().contains("isn't allowed"), "{err}");
+    assert_eq!(context.srv.tx_pool_contents().len(), 0);
+}
+
+#[tokio::test]
+async fn submit_of_rejected_transaction_returns_submission_failed() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    // The txpool doesn't accept the `Mint` transactions
+    let mint: Transaction = Transaction::mint(Default::default(), vec![]).into();
+    let result = srv.submit(mint.clone()).await;
+    assert!(
+        matches!(result, Err(QueryError::SubmissionFailed(_))),
+        "{result:?}"
+    );
+
+    let result = srv.submit_and_await_commit(mint).await;
+    assert!(
+        matches!(result, Err(QueryError::SubmissionFailed(_))),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn tx_pool_contents_returns_pending_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    assert!(srv.tx_pool_contents().is_empty());
+
+    let transactions: Vec<Transaction> = [1, 3, 2]
+        .into_iter()
+        .map(|gas_price| {
+            TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+                .gas_price(gas_price)
+                .gas_limit(10_000)
+                .add_random_fee_input()
+                .finalize_as_transaction()
+        })
+        .collect();
+    for tx in &transactions {
+        srv.submit(tx.clone()).await.unwrap();
+    }
+
+    // The highest gas price comes first
+    let contents = srv.tx_pool_contents();
+    assert_eq!(
+        contents,
+        vec![
+            transactions[1].clone(),
+            transactions[2].clone(),
+            transactions[0].clone()
+        ]
+    );
+
+    // The committed transactions leave the txpool
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+    assert!(srv.tx_pool_contents().is_empty());
+}
+
+#[tokio::test]
+async fn await_transaction_committed_waits_for_separately_submitted_transaction() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Submitted before awaiting
+    let tx: Transaction =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&tx).await.unwrap();
+    let status = srv.await_transaction_committed(id).await.unwrap();
+    assert!(matches!(
+        status,
+        fuel_core::schema::tx::types::TransactionStatus::Success(_)
+    ));
+
+    // Submitted while awaiting
+    let tx: Transaction =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    let (status, submitted) =
+        tokio::join!(srv.await_transaction_committed(id), client.submit(&tx));
+    submitted.unwrap();
+    assert!(matches!(
+        status.unwrap(),
+        fuel_core::schema::tx::types::TransactionStatus::Success(_)
+    ));
+}
+
+#[tokio::test]
+async fn committed_transactions_are_readable_via_library_api() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let owner = Address::from([1; 32]);
+    let tx: Transaction = Transaction::script(
+        Default::default(),
+        1_000_000,
+        Default::default(),
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        vec![Input::coin_signed(
+            UtxoId::new([1; 32].into(), 0),
+            owner,
+            1000,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )],
+        vec![],
+        vec![vec![].into()],
+    )
+    .into();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    srv.submit_and_await_commit(tx.clone()).await.unwrap();
+
+    assert_eq!(srv.transaction(id).unwrap(), Some(tx.clone()));
+    assert_eq!(srv.transaction([2; 32].into()).unwrap(), None);
+
+    let owned: Vec<_> = srv
+        .transactions(owner, None, IterDirection::Forward)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned[0].1, tx);
+    let unrelated: Vec<_> = srv
+        .transactions(Address::from([2; 32]), None, IterDirection::Forward)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(unrelated.is_empty());
+}
+
+#[tokio::test]
+async fn submit_rejected_when_submission_queue_is_full() {
+    let mut config = Config::local_node();
+    config.submission_queue_depth = 0;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let err = client.submit(&create_mock_tx(0)).await.unwrap_err();
+    assert!(err.to_string().contains("submission queue is full"));
+}
+
+#[tokio::test]
+async fn submit_rejected_when_mutation_rate_limit_is_exceeded() {
+    let mut config = Config::local_node();
+    config.max_mutations_per_minute = Some(3);
+    config.max_queries_per_minute = Some(100);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The burst up to the limit is accepted
+    for i in 0..3 {
+        client.submit(&create_mock_tx(i)).await.unwrap();
+    }
+
+    let err = client.submit(&create_mock_tx(3)).await.unwrap_err();
+    assert!(
+        err.to_string().contains("Too many mutation requests"),
+        "got unexpected error {err}"
+    );
+
+    // The queries have their own limit
+    client.chain_info().await.unwrap();
+
+    let tx: String = create_mock_tx(4)
+        .to_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    let mutation = serde_json::json!({
+        "query": format!("mutation {{ submit(tx: \"0x{tx}\") {{ id }} }}")
+    });
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .body(mutation.to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    let extensions = &response["errors"][0]["extensions"];
+    assert_eq!(extensions["code"], "RATE_LIMITED");
+    let retry_after = extensions["retry_after"].as_u64().unwrap();
+    assert!(retry_after > 0 && retry_after <= 60, "got {retry_after}");
+
+    // The mutations sent to the subscription endpoint count against the same limit
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql-sub", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .body(mutation.to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(response.contains("RATE_LIMITED"), "got {response}");
+}
+
+#[tokio::test]
+async fn submit_and_await_rejected_when_mutation_rate_limit_is_exceeded() {
+    let mut config = Config::local_node();
+    config.max_mutations_per_minute = Some(2);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The submitting subscription counts against the limit of the mutations
+    for i in 0..2 {
+        client.submit_and_await_commit(&create_mock_tx(i)).await.unwrap();
+    }
+
+    let err = client
+        .submit_and_await_commit(&create_mock_tx(2))
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("Too many mutation requests"),
+        "got unexpected error {err}"
+    );
+    let err = client.submit(&create_mock_tx(3)).await.unwrap_err();
+    assert!(
+        err.to_string().contains("Too many mutation requests"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn submit_with_idempotency_key_first_submit() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = create_mock_tx(0);
+
+    let id = client
+        .submit_with_idempotency_key(&tx, "first-submit")
+        .await
+        .unwrap();
+    assert_eq!(id, tx.id(&ConsensusParameters::DEFAULT.chain_id));
+}
+
+#[tokio::test]
+async fn submit_with_duplicate_idempotency_key_and_same_tx_returns_original_result() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = create_mock_tx(0);
+
+    let first_id = client
+        .submit_with_idempotency_key(&tx, "same-tx")
+        .await
+        .unwrap();
+    let second_id = client
+        .submit_with_idempotency_key(&tx, "same-tx")
+        .await
+        .unwrap();
+    assert_eq!(first_id, second_id);
+}
+
+#[tokio::test]
+async fn submit_with_duplicate_idempotency_key_and_different_tx_is_rejected() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client
+        .submit_with_idempotency_key(&create_mock_tx(0), "different-tx")
+        .await
+        .unwrap();
+    let err = client
+        .submit_with_idempotency_key(&create_mock_tx(1), "different-tx")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("is already used"));
+}
+
+#[tokio::test]
+async fn submit_rejects_oversized_transaction_before_decoding() {
+    let mut config = Config::local_node();
+    config.max_tx_size = Some(1024);
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let submit = |size: usize| {
+        // Not a valid encoding of the transaction
+        let tx = "ff".repeat(size);
+        let mutation = serde_json::json!({
+            "query": format!("mutation {{ submit(tx: \"0x{tx}\") {{ id }} }}")
+        });
+        let url = format!("http://{}/graphql", srv.bound_address);
+        async move {
+            let response = reqwest::Client::new()
+                .post(url)
+                .header("Content-Type", "application/json")
+                .body(mutation.to_string())
+                .send()
+                .await
+                .unwrap()
+                .text()
+                .await
+                .unwrap();
+            serde_json::from_str::<serde_json::Value>(&response).unwrap()
+        }
+    };
+
+    // The oversized payload is rejected without the decoding error
+    let response = submit(2048).await;
+    let error = &response["errors"][0];
+    assert_eq!(error["extensions"]["code"], "TRANSACTION_TOO_LARGE");
+    assert_eq!(
+        error["message"],
+        "Transaction too large: 2048 bytes exceeds the limit of 1024 bytes"
+    );
+
+    // The payload within the limit reaches the decoding
+    let response = submit(512).await;
+    let error = &response["errors"][0];
+    assert!(error["extensions"]["code"].is_null());
+}
+
+#[tokio::test]
+async fn submit_accepts_contract_of_max_size_with_default_max_tx_size() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let contract_max_size = 64 * 1024;
+    let mut config = Config::local_node();
+    config.chain_conf.transaction_parameters.contract_max_size = contract_max_size;
+    config
+        .txpool
+        .chain_config
+        .transaction_parameters
+        .contract_max_size = contract_max_size;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let bytecode: Vec<u8> = (0..contract_max_size).map(|_| rng.gen()).collect();
+    let bytecode: Witness = bytecode.into();
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(bytecode.as_ref());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let deploy = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    // The transaction is larger than the contract it deploys
+    assert!(deploy.clone().to_bytes().len() > contract_max_size as usize);
+
+    let status = client.submit_and_await_commit(&deploy).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn submit_to_full_txpool_returns_pool_full_error() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.txpool.max_tx = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.submit(&create_mock_tx(0)).await.unwrap();
+    client.submit(&create_mock_tx(1)).await.unwrap();
+
+    let submit = |tx: Transaction| {
+        let tx: String = tx
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        let mutation = serde_json::json!({
+            "query": format!("mutation {{ submit(tx: \"0x{tx}\") {{ id }} }}")
+        });
+        let url = format!("http://{}/graphql", srv.bound_address);
+        async move {
+            let response = reqwest::Client::new()
+                .post(url)
+                .header("Content-Type", "application/json")
+                .body(mutation.to_string())
+                .send()
+                .await
+                .unwrap()
+                .text()
+                .await
+                .unwrap();
+            serde_json::from_str::<serde_json::Value>(&response).unwrap()
+        }
+    };
+
+    // The valid transaction doesn't fit into the full `TxPool`
+    let response = submit(create_mock_tx(2)).await;
+    let extensions = &response["errors"][0]["extensions"];
+    assert_eq!(extensions["code"], "POOL_FULL");
+    assert!(extensions["retry_after"].as_u64().unwrap() >= 1);
+
+    // The invalid transaction is still rejected with the validation error
+    let invalid = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx + 1)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let response = submit(invalid).await;
+    let error = &response["errors"][0];
+    assert!(!error.is_null());
+    assert!(error["extensions"]["code"].is_null());
+}
+
+#[tokio::test]
+async fn estimated_confirmation_time_is_shorter_for_higher_gas_price() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let transaction_with_price = |gas_price: u64| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_price(gas_price)
+            .gas_limit(10_000)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    };
+    let low_fee = transaction_with_price(1);
+    let high_fee = transaction_with_price(10);
+    client.submit(&low_fee).await.unwrap();
+    client.submit(&high_fee).await.unwrap();
+
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+    let low_fee_estimate = client
+        .estimated_confirmation_time(&low_fee.id(&chain_id))
+        .await
+        .unwrap()
+        .expect("The pending transaction should have an estimate");
+    let high_fee_estimate = client
+        .estimated_confirmation_time(&high_fee.id(&chain_id))
+        .await
+        .unwrap()
+        .expect("The pending transaction should have an estimate");
+    assert!(
+        high_fee_estimate < low_fee_estimate,
+        "{high_fee_estimate} should be less than {low_fee_estimate}"
+    );
+}
+
+#[tokio::test]
+async fn dry_run_then_submit_skips_submission_of_reverting_transaction() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::rvrt(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let result = client.dry_run_then_submit(&tx, Some(true)).await.unwrap();
+    assert!(result.submission.is_none());
+    let revert_reason = result.revert_reason.expect("The script should revert");
+    assert!(!revert_reason.is_panic);
+    assert_eq!(revert_reason.reason_code, 1);
+    assert!(matches!(
+        result.receipts.last(),
+        Some(Receipt::ScriptResult { .. })
+    ));
+    assert!(client.transaction(&id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn dry_run_then_submit_submits_successful_transaction() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let result = client.dry_run_then_submit(&tx, Some(true)).await.unwrap();
+    assert!(result.revert_reason.is_none());
+    assert!(!result.receipts.is_empty());
+    let submission = result
+        .submission
+        .expect("The transaction should be submitted");
+    assert!(submission.inserted);
+    assert!(matches!(
+        client.transaction(&id).await.unwrap().map(|tx| tx.status),
+        Some(TransactionStatus::Submitted { .. })
+    ));
+}
+
+#[tokio::test]
+async fn submit_with_preview_returns_dry_run_receipts() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let result = client.submit_with_preview(&tx).await.unwrap();
+    assert_eq!(result.id, id);
+    assert!(result.insertion.inserted);
+    assert!(result
+        .preview_receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Log { ra: 0xca, .. })));
+    assert!(result
+        .preview_receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::ScriptResult { .. })));
+
+    // The preview doesn't replace the submission
+    let pending = srv.tx_pool_contents();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].id(&ConsensusParameters::DEFAULT.chain_id), id);
+}
+
+#[tokio::test]
+async fn submit_reports_transactions_evicted_by_replacement() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Both transactions spend the same coin, the second one with the higher gas price
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let coin = Input::coin_predicate(
+        UtxoId::new([1; 32].into(), 0),
+        owner,
+        1000,
+        AssetId::BASE,
+        TxPointer::default(),
+        Default::default(),
+        Default::default(),
+        predicate,
+        vec![],
+    );
+    let transaction_with_price = |gas_price: u64| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_price(gas_price)
+            .gas_limit(10_000)
+            .add_input(coin.clone())
+            .finalize_as_transaction()
+    };
+    let original = transaction_with_price(1);
+    let replacement = transaction_with_price(2);
+
+    let status = client.submit_with_status(&original).await.unwrap();
+    assert!(status.inserted);
+    assert!(!status.already_known);
+    assert_eq!(status.gas_price, 1);
+    assert!(status.removed.is_empty());
+
+    // The repeated submission is reported as already known
+    let status = client.submit_with_status(&original).await.unwrap();
+    assert!(!status.inserted);
+    assert!(status.already_known);
+
+    let status = client.submit_with_status(&replacement).await.unwrap();
+    assert!(status.inserted);
+    assert_eq!(status.gas_price, 2);
+    assert_eq!(
+        status.removed,
+        vec![original.id(&ConsensusParameters::DEFAULT.chain_id)]
+    );
+}
+
+#[tokio::test]
+async fn submit_reports_whole_dependency_chain_evicted_by_replacement() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let coin = |utxo_id: UtxoId, amount: u64| {
+        Input::coin_predicate(
+            utxo_id,
+            owner,
+            amount,
+            AssetId::BASE,
+            TxPointer::default(),
+            Default::default(),
+            Default::default(),
+            predicate.clone(),
+            vec![],
+        )
+    };
+    // Each transaction of the chain spends the coin created by the previous one
+    let spend = |input: Input, gas_price: u64, amount: u64| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_price(gas_price)
+            .gas_limit(10_000)
+            .add_input(input)
+            .add_output(Output::coin(owner, amount, AssetId::BASE))
+            .finalize_as_transaction()
+    };
+    let id = |tx: &Transaction| tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let root_coin = coin(UtxoId::new([1; 32].into(), 0), 1_000_000);
+    let first = spend(root_coin.clone(), 1, 900_000);
+    let second = spend(coin(UtxoId::new(id(&first), 0), 900_000), 1, 800_000);
+    let third = spend(coin(UtxoId::new(id(&second), 0), 800_000), 1, 700_000);
+    for tx in [&first, &second, &third] {
+        let status = client.submit_with_status(tx).await.unwrap();
+        assert!(status.inserted);
+        assert!(status.removed.is_empty());
+    }
+
+    // The replacement spends the root coin of the chain with the higher gas price
+    let replacement = spend(root_coin, 2, 900_000);
+    let status = client.submit_with_status(&replacement).await.unwrap();
+    assert!(status.inserted);
+    assert_eq!(status.removed, vec![id(&first), id(&second), id(&third)]);
+}
+
+#[tokio::test]
+async fn resubmit_restores_pending_transactions_after_restart() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.manual_blocks_enabled = true;
+    config.resubmit_enabled = true;
+    let db = Database::default();
+    let srv = FuelService::from_database(db.clone(), config.clone())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let committed = create_mock_tx(0);
+    client.submit(&committed).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let pending = create_mock_tx(1);
+    client.submit(&pending).await.unwrap();
+
+    // The restart clears the `TxPool`
+    srv.stop_and_await().await.unwrap();
+    let srv = FuelService::from_database(db, config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+    assert!(client
+        .transaction(&pending.id(&chain_id))
+        .await
+        .unwrap()
+        .is_none());
+
+    let outcomes = client
+        .resubmit(&[committed.clone(), pending.clone()])
+        .await
+        .unwrap();
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[0].id, committed.id(&chain_id));
+    assert_eq!(outcomes[0].status, ResubmissionStatus::AlreadyCommitted);
+    assert_eq!(outcomes[1].id, pending.id(&chain_id));
+    assert_eq!(outcomes[1].status, ResubmissionStatus::Inserted);
+    assert!(client
+        .transaction(&pending.id(&chain_id))
+        .await
+        .unwrap()
+        .is_some());
+
+    let outcomes = client.resubmit(&[pending]).await.unwrap();
+    assert_eq!(outcomes[0].status, ResubmissionStatus::AlreadyPending);
+}
+
+#[tokio::test]
+async fn resubmit_to_full_pool_tells_when_to_retry() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.resubmit_enabled = true;
+    config.txpool.max_tx = 1;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let outcomes = client
+        .resubmit(&[create_mock_tx(0), create_mock_tx(1)])
+        .await
+        .unwrap();
+    assert_eq!(outcomes[0].status, ResubmissionStatus::Inserted);
+    assert_eq!(outcomes[1].status, ResubmissionStatus::Rejected);
+    let reason = outcomes[1].reason.as_ref().unwrap();
+    assert!(reason.contains("retry after"), "got unexpected reason {reason}");
+}
+
+#[tokio::test]
+async fn resubmit_requires_resubmission_enabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let err = client.resubmit(&[create_mock_tx(0)]).await.unwrap_err();
+    assert!(
+        err.to_string().contains("Resubmission must be enabled"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn submit_many_categorizes_each_transaction() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let known = create_mock_tx(0);
+    client.submit(&known).await.unwrap();
+
+    let new = create_mock_tx(1);
+    let invalid = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(ConsensusParameters::DEFAULT.max_gas_per_tx + 1)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let outcomes = client
+        .submit_many(&[new.clone(), known.clone(), new.clone(), invalid])
+        .await
+        .unwrap();
+
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+    assert_eq!(outcomes.len(), 4);
+    assert_eq!(outcomes[0].id, Some(new.id(&chain_id)));
+    assert_eq!(outcomes[0].category, BatchSubmissionCategory::NewlyInserted);
+    assert!(outcomes[0].insertion.as_ref().unwrap().inserted);
+    assert_eq!(outcomes[1].id, Some(known.id(&chain_id)));
+    assert_eq!(outcomes[1].category, BatchSubmissionCategory::AlreadyKnown);
+    assert!(outcomes[1].insertion.as_ref().unwrap().already_known);
+    assert_eq!(outcomes[2].id, Some(new.id(&chain_id)));
+    assert_eq!(
+        outcomes[2].category,
+        BatchSubmissionCategory::DuplicateInBatch
+    );
+    assert!(outcomes[2].insertion.is_none());
+    assert_eq!(outcomes[3].category, BatchSubmissionCategory::Rejected);
+    assert!(outcomes[3].reason.is_some());
+}
+
+#[tokio::test]
+async fn pending_transactions_spending_utxo_returns_spenders() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let utxo_id = UtxoId::new([1; 32].into(), 0);
+
+    let pending = transaction_spending_coin(utxo_id, 1);
+    client.submit(&pending).await.unwrap();
+
+    let spenders = client
+        .pending_transactions_spending_utxo(&utxo_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        spenders,
+        vec![pending.id(&ConsensusParameters::DEFAULT.chain_id)]
+    );
+
+    let unspent = UtxoId::new([2; 32].into(), 0);
+    let spenders = client
+        .pending_transactions_spending_utxo(&unspent)
+        .await
+        .unwrap();
+    assert!(spenders.is_empty());
+}
+
+#[tokio::test]
+async fn transactions_spending_utxo_returns_pending_holder() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let utxo_id = UtxoId::new([1; 32].into(), 0);
+
+    let holder = transaction_spending_coin(utxo_id, 1);
+    client.submit(&holder).await.unwrap();
+
+    let spenders = client.transactions_spending_utxo(&utxo_id).await.unwrap();
+    assert_eq!(spenders, vec![holder]);
+
+    let unspent = UtxoId::new([2; 32].into(), 0);
+    let spenders = client.transactions_spending_utxo(&unspent).await.unwrap();
+    assert!(spenders.is_empty());
+}
+
+#[tokio::test]
+async fn transaction_input_status_reports_reserved_inputs() {
+    let free = UtxoId::new([1; 32].into(), 0);
+    let reserved = UtxoId::new([2; 32].into(), 0);
+    let pending = transaction_spending_coin(reserved, 1);
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_price(2)
+        .gas_limit(10_000)
+        .add_input(predicate_coin(free))
+        .add_input(predicate_coin(reserved))
+        .finalize_as_transaction();
+
+    let owner = *predicate_coin(free).input_owner().unwrap();
+    let coins = [free, reserved]
+        .into_iter()
+        .map(|utxo_id| fuel_core::chain_config::CoinConfig {
+            tx_id: Some(*utxo_id.tx_id()),
+            output_index: Some(utxo_id.output_index()),
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount: 1000,
+            asset_id: AssetId::BASE,
+        })
+        .collect();
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.chain_conf.initial_state = Some(fuel_core::chain_config::StateConfig {
+        coins: Some(coins),
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit(&pending).await.unwrap();
+
+    let statuses = client.transaction_input_status(&tx).await.unwrap();
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0].index, 0);
+    assert_eq!(statuses[0].utxo_id, Some(free));
+    assert_eq!(statuses[0].state, InputUtxoState::Unspent);
+    assert!(statuses[0].reserved_by.is_empty());
+    assert_eq!(statuses[1].index, 1);
+    assert_eq!(statuses[1].utxo_id, Some(reserved));
+    assert_eq!(statuses[1].state, InputUtxoState::Reserved);
+    assert_eq!(
+        statuses[1].reserved_by,
+        vec![pending.id(&ConsensusParameters::DEFAULT.chain_id)]
+    );
+}
+
+#[tokio::test]
+async fn mempool_gas_price_distribution_bins_pending_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let buckets = client.mempool_gas_price_distribution().await.unwrap();
+    assert!(buckets.is_empty());
+
+    for (i, gas_price) in [1, 2, 3, 21].into_iter().enumerate() {
+        let utxo_id = UtxoId::new([i as u8 + 1; 32].into(), 0);
+        let tx = transaction_spending_coin(utxo_id, gas_price);
+        client.submit(&tx).await.unwrap();
+    }
+
+    // The range `1..=21` is split into buckets of the width 3
+    let buckets = client.mempool_gas_price_distribution().await.unwrap();
+    assert_eq!(buckets.len(), 7);
+    assert_eq!(
+        (
+            buckets[0].min_gas_price,
+            buckets[0].max_gas_price,
+            buckets[0].count
+        ),
+        (1, 3, 3)
+    );
+    assert_eq!(
+        (
+            buckets[6].min_gas_price,
+            buckets[6].max_gas_price,
+            buckets[6].count
+        ),
+        (19, 21, 1)
+    );
+    assert!(buckets[1..6].iter().all(|bucket| bucket.count == 0));
+}
+
+#[tokio::test]
+async fn gas_price_impact_counts_transactions_below_proposed_price() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let params = config.chain_conf.transaction_parameters;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut expected_fee = 0;
+    for (i, gas_price) in [1, 3, 5, 7].into_iter().enumerate() {
+        let utxo_id = UtxoId::new([i as u8 + 1; 32].into(), 0);
+        let tx = transaction_spending_coin(utxo_id, gas_price);
+        if gas_price < 5 {
+            let script = tx.as_script().unwrap();
+            expected_fee += TransactionFee::checked_from_tx(&params, script)
+                .unwrap()
+                .max_fee();
+        }
+        client.submit(&tx).await.unwrap();
+    }
+
+    let impact = client.gas_price_impact(5).await.unwrap();
+    assert_eq!(impact.evicted_count, 2);
+    assert_eq!(impact.total_fee, expected_fee);
+
+    let impact = client.gas_price_impact(1).await.unwrap();
+    assert_eq!(impact.evicted_count, 0);
+    assert_eq!(impact.total_fee, 0);
+
+    // The analysis doesn't change the `TxPool`
+    assert_eq!(srv.tx_pool_contents().len(), 4);
+}
+
+#[tokio::test]
+async fn average_confirmation_time_covers_recently_committed_transactions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    assert_eq!(client.average_confirmation_time(10).await.unwrap(), 0);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // The transaction is committed right after the submission
+    let average = client.average_confirmation_time(10).await.unwrap();
+    assert!(average < 60_000, "unexpected average {average}");
+
+    let result = client
+        .average_confirmation_time(MAX_CONFIRMATION_TIME_SCAN_BLOCKS + 1)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn decode_transaction_echoes_parsed_fields_without_submitting() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_price(7)
+        .gas_limit(12_345)
+        .maturity(3u32.into())
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let decoded = client.decode_transaction(&tx).await.unwrap();
+    assert_eq!(decoded.id, id);
+    assert_eq!(decoded.gas_price, Some(7));
+    assert_eq!(decoded.gas_limit, Some(12_345));
+    assert_eq!(decoded.maturity, Some(3));
+    assert!(decoded.is_script && !decoded.is_create && !decoded.is_mint);
+    assert_eq!(decoded.transaction, tx);
+
+    // Neither the `TxPool` nor the database knows the transaction
+    assert!(client.transaction(&id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn decode_transaction_reports_signature_requirements() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let predicate_only =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(10_000)
+            .add_input(predicate_coin(UtxoId::new([1; 32].into(), 0)))
+            .add_input(predicate_coin(UtxoId::new([2; 32].into(), 0)))
+            .finalize_as_transaction();
+    let mixed =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(10_000)
+            .add_input(predicate_coin(UtxoId::new([3; 32].into(), 0)))
+            .add_random_fee_input()
+            .finalize_as_transaction();
+
+    let decoded = client.decode_transaction(&predicate_only).await.unwrap();
+    assert_eq!(decoded.requires_signatures, Some(false));
+    assert_eq!(decoded.predicate_inputs_count, Some(2));
+    assert_eq!(decoded.is_predicate_only, Some(true));
+
+    let decoded = client.decode_transaction(&mixed).await.unwrap();
+    assert_eq!(decoded.requires_signatures, Some(true));
+    assert_eq!(decoded.predicate_inputs_count, Some(1));
+    assert_eq!(decoded.is_predicate_only, Some(false));
+}
+
+#[tokio::test]
+async fn validate_transaction_reports_gas_above_block_gas_limit() {
+    let mut config = Config::local_node();
+    config.chain_conf.block_gas_limit = 100_000;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = op::ret(RegId::ONE).to_bytes().to_vec();
+    let tx = TransactionBuilder::script(script.clone(), vec![])
+        .gas_limit(200_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let failures = client.validate_transaction(&tx).await.unwrap();
+    assert!(
+        failures
+            .iter()
+            .any(|failure| failure.path.as_deref() == Some("gasLimit")
+                && failure.reason.contains("block gas limit")),
+        "unexpected failures {failures:?}"
+    );
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let failures = client.validate_transaction(&tx).await.unwrap();
+    assert!(failures.is_empty(), "unexpected failures {failures:?}");
+}
+
+#[tokio::test]
+async fn validate_transaction_reports_all_independent_failures() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.min_gas_price = 10;
+    test_builder.utxo_validation = true;
+    let TestContext { client, .. } = test_builder.finalize().await;
+
+    let invalid_predicate = op::ret(RegId::ZERO).to_bytes().to_vec();
+    let owner = Input::predicate_owner(
+        &invalid_predicate,
+        &ConsensusParameters::DEFAULT.chain_id,
+    );
+    // The gas price is too low, the signed coin refers to a missing witness,
+    // the predicate fails and the inputs don't cover the fee
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_price(1)
+        .gas_limit(10_000)
+        .add_input(Input::coin_signed(
+            rng.gen(),
+            rng.gen(),
+            0,
+            AssetId::BASE,
+            Default::default(),
+            5,
+            Default::default(),
+        ))
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            0,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            invalid_predicate,
+            vec![],
+        ))
+        .finalize_as_transaction();
+
+    let failures = client.validate_transaction(&tx).await.unwrap();
+    let paths: Vec<_> = failures
+        .iter()
+        .filter_map(|failure| failure.path.as_deref())
+        .collect();
+    for expected in [
+        "gasPrice",
+        "inputs[0].witnessIndex",
+        "inputs[1].predicate",
+        "inputs",
+    ] {
+        assert!(
+            paths.contains(&expected),
+            "{expected} missing in {failures:?}"
+        );
+    }
+
+    // The validation doesn't insert the transaction into the pool
+    let status = client
+        .transaction_status(&tx.id(&ConsensusParameters::DEFAULT.chain_id))
+        .await;
+    assert!(status.is_err());
+}
+
+#[tokio::test]
+async fn fee_statistics_reports_gas_price_percentiles_of_recent_blocks() {
+    let mut config = Config::local_node();
+    config.max_fee_statistics_blocks = 100;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Each transaction is included into its own block
+    let gas_prices = [7, 2, 9, 4, 1, 10, 3, 6, 8, 5];
+    for (i, gas_price) in gas_prices.into_iter().enumerate() {
+        let utxo_id = UtxoId::new([i as u8 + 1; 32].into(), 0);
+        let tx = transaction_spending_coin(utxo_id, gas_price);
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+
+    // The genesis block and the blocks of all transactions
+    let statistics = client.fee_statistics(20).await.unwrap();
+    assert_eq!(statistics.block_count, 11);
+    assert_eq!(statistics.transaction_count, 10);
+    assert_eq!(
+        (
+            statistics.min_gas_price,
+            statistics.p25_gas_price,
+            statistics.median_gas_price,
+            statistics.p75_gas_price,
+            statistics.p90_gas_price,
+            statistics.max_gas_price,
+        ),
+        (1, 3, 5, 8, 9, 10)
+    );
+    assert!(statistics.average_transaction_size > 0);
+
+    // Only the blocks of the last three transactions
+    let statistics = client.fee_statistics(3).await.unwrap();
+    assert_eq!(statistics.block_count, 3);
+    assert_eq!(
+        (
+            statistics.min_gas_price,
+            statistics.median_gas_price,
+            statistics.max_gas_price,
+        ),
+        (5, 6, 8)
+    );
+
+    assert!(client.fee_statistics(101).await.is_err());
+}
+
+#[tokio::test]
+async fn transaction_lookup_reports_squeezed_out_transaction() {
+    use fuel_core_client::client::types::TransactionLookup;
+
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let utxo_id = UtxoId::new([1; 32].into(), 0);
+
+    // The transaction paying more for the same coin squeezes out the first one
+    let original = transaction_spending_coin(utxo_id, 1);
+    let original_id = original.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&original).await.unwrap();
+    let replacement = transaction_spending_coin(utxo_id, 2);
+    let replacement_id = replacement.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&replacement).await.unwrap();
+
+    assert!(client.transaction(&original_id).await.unwrap().is_none());
+    let lookup = client.transaction_lookup(&original_id).await.unwrap();
+    assert!(
+        matches!(lookup, TransactionLookup::SqueezedOut { .. }),
+        "{lookup:?}"
+    );
+
+    let lookup = client.transaction_lookup(&replacement_id).await.unwrap();
+    match lookup {
+        TransactionLookup::Found(found) => assert_eq!(found.transaction, replacement),
+        lookup => panic!("Expected the pending transaction, got {lookup:?}"),
+    }
+
+    let lookup = client.transaction_lookup(&[2; 32].into()).await.unwrap();
+    assert!(matches!(lookup, TransactionLookup::Unknown), "{lookup:?}");
+}
+
+#[tokio::test]
+async fn replace_transaction_evicts_conflicting_pending_transaction() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let utxo_id = UtxoId::new([1; 32].into(), 0);
+
+    let original = transaction_spending_coin(utxo_id, 1);
+    client.submit(&original).await.unwrap();
+
+    // The replacement must pay more than the original
+    let same_price = transaction_spending_coin(utxo_id, 1);
+    let err = client.replace_transaction(&same_price).await.unwrap_err();
+    assert!(err.to_string().contains("isn't higher"), "{err}");
+
+    let replacement = transaction_spending_coin(utxo_id, 2);
+    let evicted = client.replace_transaction(&replacement).await.unwrap();
+    assert_eq!(
+        evicted,
+        vec![original.id(&ConsensusParameters::DEFAULT.chain_id)]
+    );
+    let status = client
+        .last_seen_status(&original.id(&ConsensusParameters::DEFAULT.chain_id))
+        .await
+        .unwrap();
+    assert!(
+        matches!(status, Some(TransactionStatus::SqueezedOut { .. }) | None),
+        "{status:?}"
+    );
+}
+
+#[tokio::test]
+async fn replace_transaction_rejects_non_conflicting_transaction() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let pending = transaction_spending_coin(UtxoId::new([1; 32].into(), 0), 1);
+    client.submit(&pending).await.unwrap();
+
+    let unrelated = transaction_spending_coin(UtxoId::new([2; 32].into(), 0), 2);
+    let err = client.replace_transaction(&unrelated).await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("doesn't spend inputs of any pending transaction"),
+        "{err}"
+    );
+    // The rejected transaction isn't inserted
+    let status = client
+        .last_seen_status(&unrelated.id(&ConsensusParameters::DEFAULT.chain_id))
+        .await
+        .unwrap();
+    assert!(status.is_none());
+}
+
+#[tokio::test]
+async fn last_seen_status_for_pending_committed_and_unknown_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = create_mock_tx(0);
+    let id = client.submit(&tx).await.unwrap();
+
+    // The transaction is pending in the `TxPool`
+    let status = client.last_seen_status(&id).await.unwrap();
+    assert!(
+        matches!(status, Some(TransactionStatus::Submitted { .. })),
+        "{status:?}"
+    );
+
+    // The transaction is committed in the block
+    client.produce_blocks(1, None).await.unwrap();
+    let status = client.last_seen_status(&id).await.unwrap();
+    assert!(
+        matches!(
+            status,
+            Some(TransactionStatus::Success { .. } | TransactionStatus::Failure { .. })
+        ),
+        "{status:?}"
+    );
+
+    // The transaction is unknown
+    let unknown_id = create_mock_tx(1).id(&ConsensusParameters::DEFAULT.chain_id);
+    let status = client.last_seen_status(&unknown_id).await.unwrap();
+    assert!(status.is_none());
+}
+
+#[tokio::test]
+async fn transaction_statuses_of_committed_pending_and_unknown_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let committed = client.submit(&create_mock_tx(0)).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let pending = client.submit(&create_mock_tx(1)).await.unwrap();
+    let unknown = create_mock_tx(2).id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let ids = [unknown, pending, committed];
+    let statuses = client.transaction_statuses(&ids).await.unwrap();
+    assert_eq!(statuses.iter().map(|(id, _)| *id).collect_vec(), ids);
+    assert!(statuses[0].1.is_none());
+    assert!(
+        matches!(statuses[1].1, Some(TransactionStatus::Submitted { .. })),
+        "{statuses:?}"
+    );
+    assert!(
+        matches!(
+            statuses[2].1,
+            Some(TransactionStatus::Success { .. } | TransactionStatus::Failure { .. })
+        ),
+        "{statuses:?}"
+    );
+}
+
+#[tokio::test]
+async fn transaction_status_history_records_submission_and_commit() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = client.submit(&tx).await.unwrap();
+    let submitted_at = match client.transaction_status(&id).await.unwrap() {
+        TransactionStatus::Submitted { submitted_at, .. } => submitted_at,
+        status => panic!("unexpected status {status:?}"),
+    };
+    client.produce_blocks(1, None).await.unwrap();
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+
+    // The transitions are in the recorded order, each with its own time
+    let history = client.transaction_status_history(&id).await.unwrap();
+    assert_eq!(history.len(), 2, "{history:?}");
+    assert!(
+        matches!(history[0].status, TransactionStatus::Submitted { .. }),
+        "{history:?}"
+    );
+    assert_eq!(history[0].time, submitted_at);
+    assert!(
+        matches!(history[1].status, TransactionStatus::Success { .. }),
+        "{history:?}"
+    );
+    assert_eq!(history[1].time, block.header.time);
+
+    // Nothing is recorded for the unknown transaction
+    let unknown_id = create_mock_tx(1).id(&ConsensusParameters::DEFAULT.chain_id);
+    let history = client
+        .transaction_status_history(&unknown_id)
+        .await
+        .unwrap();
+    assert!(history.is_empty());
+}
+
+#[ignore]
+#[tokio::test]
+async fn transaction_status_submitted() {
+    // This test should ensure a transaction's status is Submitted while it is in the mempool
+    // This test should also ensure a transaction's time of submission is correct in the returned status
+    // Currently blocked until https://github.com/FuelLabs/fuel-core/issues/50 is resolved
+    // as execution must be separate from submission for a tx to persist inside of the txpool
+    // Merge with the submit_utxo_verified_tx test once utxo_verification is the default
+    todo!();
+}
+
+#[tokio::test]
+async fn receipts() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ConsensusParameters::DEFAULT.chain_id);
+    // setup server & client
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // submit tx
+    client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+    // run test
+    let receipts = client.receipts(&id).await.unwrap();
+    assert!(receipts.is_some());
+}
+
+#[tokio::test]
+async fn paginated_receipts() {
+    const LOGS: usize = 10;
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script: Vec<u8> =
+        iter::repeat(op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO))
+            .take(LOGS)
+            .chain(iter::once(op::ret(RegId::ONE)))
+            .flat_map(|op| u32::from(op).to_be_bytes())
+            .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let all_receipts = client.receipts(&id).await.unwrap().unwrap();
+    // The logs, the return and the script result
+    assert_eq!(all_receipts.len(), LOGS + 2);
+
+    let mut cursor = None;
+    let mut paged_receipts = vec![];
+    loop {
+        let page = client
+            .paginated_receipts(
+                &id,
+                PaginationRequest {
+                    cursor: cursor.clone(),
+                    results: 5,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(page.results.len() <= 5);
+        paged_receipts.extend(page.results);
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+    assert_eq!(paged_receipts, all_receipts);
+
+    let last_page = client
+        .paginated_receipts(
+            &id,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Backward,
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        last_page.results,
+        vec![all_receipts[11].clone(), all_receipts[10].clone()]
+    );
+}
+
+#[tokio::test]
+async fn receipts_over_the_limit_are_truncated() {
+    const LOGS: usize = 10;
+    let mut config = Config::local_node();
+    config.max_receipts = Some(5);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script: Vec<u8> =
+        iter::repeat(op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO))
+            .take(LOGS)
+            .chain(iter::once(op::ret(RegId::ONE)))
+            .flat_map(|op| u32::from(op).to_be_bytes())
+            .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let err = client.receipts(&id).await.unwrap_err();
+    assert!(
+        err.to_string().contains("use `paginated_receipts` instead"),
+        "got unexpected error {err}"
+    );
+
+    // The whole list is still available page by page
+    let page = client
+        .paginated_receipts(
+            &id,
+            PaginationRequest {
+                cursor: None,
+                results: 20,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(page.results.len(), LOGS + 2);
+}
+
+#[tokio::test]
+async fn receipts_decoding() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let actual_receipts = client.all_receipts().await.unwrap();
+    assert_eq!(actual_receipts, all_receipts())
+}
+
+#[tokio::test]
+async fn get_transaction_by_id() {
+    // setup test data in the node
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    // setup server & client
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // submit tx to api
+    client.submit_and_await_commit(&transaction).await.unwrap();
+
+    // run test
+    let transaction_response = client.transaction(&id).await.unwrap();
+    assert!(transaction_response.is_some());
+    if let Some(transaction_response) = transaction_response {
+        assert!(matches!(
+            transaction_response.status,
+            TransactionStatus::Success { .. }
+        ))
+    }
+}
+
+#[tokio::test]
+async fn transaction_block_is_returned_only_for_committed_transactions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let committed = create_mock_tx(0);
+    let id = committed.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit_and_await_commit(&committed).await.unwrap();
+
+    let block = client.transaction_block(&id).await.unwrap().unwrap();
+    match client.transaction_status(&id).await.unwrap() {
+        TransactionStatus::Success { block_id, time, .. } => {
+            assert_eq!(block_id.parse::<Bytes32>().unwrap(), block.id);
+            assert_eq!(time, block.header.time);
+        }
+        status => panic!("unexpected status {status:?}"),
+    }
+    assert!(block.transactions.contains(&id));
+
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let pending = create_mock_tx(1);
+    let id = pending.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&pending).await.unwrap();
+
+    assert!(client.transaction(&id).await.unwrap().is_some());
+    assert!(client.transaction_block(&id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn transactions_in_range_streams_blocks_in_order_up_to_the_tip() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut submitted = vec![];
+    for i in 0..3 {
+        let tx = create_mock_tx(i);
+        submitted.push(tx.id(&ConsensusParameters::DEFAULT.chain_id));
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+
+    // The end height is above the tip, so the stream ends at the latest block.
+    let streamed: Vec<_> = client
+        .transactions_in_range(1, 10)
+        .await
+        .unwrap()
+        .map_ok(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let mut expected = vec![];
+    for height in 1..=3 {
+        let block = client.block_by_height(height).await.unwrap().unwrap();
+        expected.extend(block.transactions);
+    }
+    assert_eq!(streamed, expected);
+    let streamed_submitted: Vec<_> = streamed
+        .into_iter()
+        .filter(|id| submitted.contains(id))
+        .collect();
+    assert_eq!(streamed_submitted, submitted);
+
+    let streamed: Vec<_> = client
+        .transactions_in_range(2, 2)
+        .await
+        .unwrap()
+        .map_ok(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .try_collect()
+        .await
+        .unwrap();
+    let block = client.block_by_height(2).await.unwrap().unwrap();
+    assert_eq!(streamed, block.transactions);
+}
+
+#[tokio::test]
+async fn get_transparent_transaction_by_id() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    // setup server & client
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // submit tx
+    let result = client.submit_and_await_commit(&transaction).await;
+    assert!(result.is_ok());
+
+    let opaque_tx = client
+        .transaction(&id)
+        .await
+        .unwrap()
+        .expect("expected some result")
+        .transaction;
+
+    // run test
+    let transparent_transaction = client
+        .transparent_transaction(&id)
+        .await
+        .unwrap()
+        .expect("expected some value");
+
+    // verify transaction round-trips via transparent graphql
+    assert_eq!(opaque_tx, transparent_transaction);
+}
+
+#[tokio::test]
+async fn get_transactions() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+    let tx4 = context.transfer(bob, charlie, 3).await.unwrap();
+    let tx5 = context.transfer(charlie, alice, 1).await.unwrap();
+    let tx6 = context.transfer(alice, charlie, 1).await.unwrap();
+
+    // there are 12 transactions
+    // [
+    //  coinbase_tx1, tx1, coinbase_tx2, tx2, coinbase_tx3, tx3,
+    //  coinbase_tx4, tx4, coinbase_tx5, tx5, coinbase_tx6, tx6
+    // ]
+
+    // Query for first 6: [coinbase_tx1, tx1, coinbase_tx2, tx2, coinbase_tx3, tx3]
+    let client = context.client;
+    let page_request = PaginationRequest {
+        cursor: None,
+        results: 6,
+        direction: PageDirection::Forward,
+    };
+
+    let response = client.transactions(page_request.clone()).await.unwrap();
+    let transactions = &response
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .collect_vec();
+    // coinbase_tx1
+    assert_eq!(transactions[1], tx1);
+    // coinbase_tx2
+    assert_eq!(transactions[3], tx2);
+    // coinbase_tx3
+    assert_eq!(transactions[5], tx3);
+    // Check pagination state for first page
+    assert!(response.has_next_page);
+    assert!(!response.has_previous_page);
 
     // Query for second page 2 with last given cursor: [coinbase_tx4, tx4, coinbase_tx5, tx5]
     let page_request_middle_page = PaginationRequest {
@@ -325,320 +3359,934 @@ async fn get_transactions() {
         direction: PageDirection::Forward,
     };
 
-    // Query backwards from last given cursor [3]: [coinbase_tx3, tx2, coinbase_tx2, tx1, coinbase_tx1]
-    let page_request_backwards = PaginationRequest {
-        cursor: response.cursor.clone(),
-        results: 6,
+    // Query backwards from last given cursor [3]: [coinbase_tx3, tx2, coinbase_tx2, tx1, coinbase_tx1]
+    let page_request_backwards = PaginationRequest {
+        cursor: response.cursor.clone(),
+        results: 6,
+        direction: PageDirection::Backward,
+    };
+
+    // Query forwards from last given cursor [3]: [coinbase_tx4, tx4, coinbase_tx5, tx5, coinbase_tx6, tx6]
+    let page_request_forwards = PaginationRequest {
+        cursor: response.cursor,
+        results: 6,
+        direction: PageDirection::Forward,
+    };
+
+    let response = client.transactions(page_request_middle_page).await.unwrap();
+    let transactions = &response
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .collect_vec();
+    // coinbase_tx4
+    assert_eq!(transactions[1], tx4);
+    // coinbase_tx5
+    assert_eq!(transactions[3], tx5);
+    // Check pagination state for middle page
+    // it should have next and previous page
+    assert!(response.has_next_page);
+    assert!(response.has_previous_page);
+
+    let response = client.transactions(page_request_backwards).await.unwrap();
+    let transactions = &response
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .collect_vec();
+    // transactions[0] - coinbase_tx3
+    assert_eq!(transactions[1], tx2);
+    // transactions[2] - coinbase_tx2
+    assert_eq!(transactions[3], tx1);
+    // transactions[4] - coinbase_tx1
+    // Check pagination state for last page
+    assert!(!response.has_next_page);
+    assert!(response.has_previous_page);
+
+    let response = client.transactions(page_request_forwards).await.unwrap();
+    let transactions = &response
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .collect_vec();
+    // coinbase_tx4
+    assert_eq!(transactions[1], tx4);
+    // coinbase_tx5
+    assert_eq!(transactions[3], tx5);
+    // coinbase_tx6
+    assert_eq!(transactions[5], tx6);
+    // Check pagination state for last page
+    assert!(!response.has_next_page);
+    assert!(response.has_previous_page);
+}
+
+#[tokio::test]
+async fn get_transactions_by_owner_forward_and_backward_iterations() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let _ = context.transfer(alice, bob, 1).await.unwrap();
+    let _ = context.transfer(alice, bob, 2).await.unwrap();
+    let _ = context.transfer(alice, bob, 3).await.unwrap();
+    let _ = context.transfer(alice, bob, 4).await.unwrap();
+    let _ = context.transfer(alice, bob, 5).await.unwrap();
+
+    let client = context.client;
+
+    let all_transactions_forward = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let response = client
+        .transactions_by_owner(&bob, all_transactions_forward)
+        .await
+        .unwrap();
+    let transactions_forward = response
+        .results
+        .into_iter()
+        .map(|tx| {
+            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+            tx.transaction
+        })
+        .collect_vec();
+    assert_eq!(transactions_forward.len(), 5);
+
+    let all_transactions_backward = PaginationRequest {
+        cursor: None,
+        results: 10,
         direction: PageDirection::Backward,
     };
+    let response = client
+        .transactions_by_owner(&bob, all_transactions_backward)
+        .await;
+    // Backward request is not supported right now.
+    assert!(response.is_err());
 
-    // Query forwards from last given cursor [3]: [coinbase_tx4, tx4, coinbase_tx5, tx5, coinbase_tx6, tx6]
-    let page_request_forwards = PaginationRequest {
-        cursor: response.cursor,
-        results: 6,
+    ///////////////// Iteration
+
+    let forward_iter_three = PaginationRequest {
+        cursor: None,
+        results: 3,
         direction: PageDirection::Forward,
     };
+    let response_after_iter_three = client
+        .transactions_by_owner(&bob, forward_iter_three)
+        .await
+        .unwrap();
+    let transactions_forward_iter_three = response_after_iter_three
+        .results
+        .into_iter()
+        .map(|tx| {
+            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+            tx.transaction
+        })
+        .collect_vec();
+    assert_eq!(transactions_forward_iter_three.len(), 3);
+    assert_eq!(transactions_forward_iter_three[0], transactions_forward[0]);
+    assert_eq!(transactions_forward_iter_three[1], transactions_forward[1]);
+    assert_eq!(transactions_forward_iter_three[2], transactions_forward[2]);
 
-    let response = client.transactions(page_request_middle_page).await.unwrap();
+    let forward_iter_next_two = PaginationRequest {
+        cursor: response_after_iter_three.cursor.clone(),
+        results: 2,
+        direction: PageDirection::Forward,
+    };
+    let response = client
+        .transactions_by_owner(&bob, forward_iter_next_two)
+        .await
+        .unwrap();
+    let transactions_forward_iter_next_two = response
+        .results
+        .into_iter()
+        .map(|tx| {
+            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
+            tx.transaction
+        })
+        .collect_vec();
+    assert_eq!(
+        transactions_forward_iter_next_two[0],
+        transactions_forward[3]
+    );
+    assert_eq!(
+        transactions_forward_iter_next_two[1],
+        transactions_forward[4]
+    );
+}
+
+#[tokio::test]
+async fn transactions_by_contract_lists_transactions_calling_contract() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.contract_transactions_index_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the contract
+    let bytecode: Witness = op::ret(RegId::ONE).to_bytes().to_vec().into();
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(bytecode.as_ref());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let deploy = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&deploy).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let mut call = || {
+        TransactionBuilder::script(
+            script.clone(),
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction()
+    };
+    let mut calls = vec![];
+    for tx in [call(), call(), call()] {
+        let status = client.submit_and_await_commit(&tx).await.unwrap();
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+        calls.push(tx.id(&ConsensusParameters::DEFAULT.chain_id));
+    }
+
+    // The transaction that doesn't call the contract is not listed
+    let unrelated = create_mock_tx(0);
+    client.submit_and_await_commit(&unrelated).await.unwrap();
+
+    let first_page = client
+        .transactions_by_contract(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(first_page.has_next_page);
+    let second_page = client
+        .transactions_by_contract(
+            &contract_id,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(!second_page.has_next_page);
+
+    let listed = first_page
+        .results
+        .into_iter()
+        .chain(second_page.results)
+        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .collect_vec();
+    assert_eq!(listed, calls);
+}
+
+#[tokio::test]
+async fn transactions_by_contract_requires_index_enabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let err = client
+        .transactions_by_contract(
+            &ContractId::zeroed(),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("must be enabled to use this endpoint"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn get_transactions_from_manual_blocks() {
+    let (executor, db) = get_executor_and_db();
+    // get access to a client
+    let context = initialize_client(db).await;
+
+    // create 10 txs
+    let txs: Vec<Transaction> = (0..10).map(create_mock_tx).collect();
+
+    // make 1st test block
+    let first_test_block = PartialFuelBlock {
+        header: PartialBlockHeader {
+            consensus: ConsensusHeader {
+                height: 1u32.into(),
+                time: Tai64::now(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+
+        // set the first 5 ids of the manually saved txs
+        transactions: txs.iter().take(5).cloned().collect(),
+    };
+
+    // make 2nd test block
+    let second_test_block = PartialFuelBlock {
+        header: PartialBlockHeader {
+            consensus: ConsensusHeader {
+                height: 2u32.into(),
+                time: Tai64::now(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        // set the last 5 ids of the manually saved txs
+        transactions: txs.iter().skip(5).take(5).cloned().collect(),
+    };
+
+    // process blocks and save block height
+    executor
+        .execute_and_commit(
+            ExecutionBlock::Production(first_test_block),
+            Default::default(),
+        )
+        .unwrap();
+    executor
+        .execute_and_commit(
+            ExecutionBlock::Production(second_test_block),
+            Default::default(),
+        )
+        .unwrap();
+
+    // Query for first 4: [coinbase_tx1, 0, 1, 2]
+    let page_request_forwards = PaginationRequest {
+        cursor: None,
+        results: 4,
+        direction: PageDirection::Forward,
+    };
+    let response = context
+        .client
+        .transactions(page_request_forwards)
+        .await
+        .unwrap();
     let transactions = &response
         .results
         .iter()
         .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
         .collect_vec();
-    // coinbase_tx4
-    assert_eq!(transactions[1], tx4);
-    // coinbase_tx5
-    assert_eq!(transactions[3], tx5);
-    // Check pagination state for middle page
-    // it should have next and previous page
-    assert!(response.has_next_page);
-    assert!(response.has_previous_page);
+    // coinbase_tx1
+    assert_eq!(
+        transactions[1],
+        txs[0].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[2],
+        txs[1].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[3],
+        txs[2].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
 
-    let response = client.transactions(page_request_backwards).await.unwrap();
+    // Query forwards from last given cursor [2]: [3, 4, coinbase_tx2, 5, 6]
+    let next_page_request_forwards = PaginationRequest {
+        cursor: response.cursor,
+        results: 5,
+        direction: PageDirection::Forward,
+    };
+    let response = context
+        .client
+        .transactions(next_page_request_forwards)
+        .await
+        .unwrap();
     let transactions = &response
         .results
         .iter()
         .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
         .collect_vec();
-    // transactions[0] - coinbase_tx3
-    assert_eq!(transactions[1], tx2);
-    // transactions[2] - coinbase_tx2
-    assert_eq!(transactions[3], tx1);
-    // transactions[4] - coinbase_tx1
-    // Check pagination state for last page
-    assert!(!response.has_next_page);
-    assert!(response.has_previous_page);
+    assert_eq!(
+        transactions[0],
+        txs[3].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[1],
+        txs[4].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    // coinbase_tx2
+    assert_eq!(
+        transactions[3],
+        txs[5].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[4],
+        txs[6].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
 
-    let response = client.transactions(page_request_forwards).await.unwrap();
+    // Query backwards from last given cursor [8]: [5, coinbase_tx2, 4, 3, 2, 1, 0, coinbase_tx1]
+    let page_request_backwards = PaginationRequest {
+        cursor: response.cursor,
+        results: 10,
+        direction: PageDirection::Backward,
+    };
+    let response = context
+        .client
+        .transactions(page_request_backwards)
+        .await
+        .unwrap();
     let transactions = &response
         .results
         .iter()
         .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
         .collect_vec();
-    // coinbase_tx4
-    assert_eq!(transactions[1], tx4);
-    // coinbase_tx5
-    assert_eq!(transactions[3], tx5);
-    // coinbase_tx6
-    assert_eq!(transactions[5], tx6);
-    // Check pagination state for last page
-    assert!(!response.has_next_page);
-    assert!(response.has_previous_page);
+    assert_eq!(
+        transactions[0],
+        txs[5].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    // transactions[1] coinbase_tx2
+    assert_eq!(
+        transactions[2],
+        txs[4].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[3],
+        txs[3].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[4],
+        txs[2].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[5],
+        txs[1].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    assert_eq!(
+        transactions[6],
+        txs[0].id(&ConsensusParameters::DEFAULT.chain_id)
+    );
+    // transactions[7] coinbase_tx1
 }
 
 #[tokio::test]
-async fn get_transactions_by_owner_forward_and_backward_iterations() {
-    let alice = Address::from([1; 32]);
-    let bob = Address::from([2; 32]);
+async fn get_transactions_in_time_range() {
+    let (executor, db) = get_executor_and_db();
+    let context = initialize_client(db).await;
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+    let start_time = Tai64::now();
 
-    let mut context = TestContext::new(100).await;
-    let _ = context.transfer(alice, bob, 1).await.unwrap();
-    let _ = context.transfer(alice, bob, 2).await.unwrap();
-    let _ = context.transfer(alice, bob, 3).await.unwrap();
-    let _ = context.transfer(alice, bob, 4).await.unwrap();
-    let _ = context.transfer(alice, bob, 5).await.unwrap();
+    // Blocks one hour apart, with two transactions each
+    let txs: Vec<Transaction> = (0..6).map(create_mock_tx).collect();
+    for (height, block_txs) in txs.chunks(2).enumerate() {
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: (height as u32 + 1).into(),
+                    time: Tai64(start_time.0 + 3600 * height as u64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: block_txs.to_vec(),
+        };
+        executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+    }
 
-    let client = context.client;
+    // Only the middle block is within the range
+    let range_start = Some(start_time.0 + 1800);
+    let range_end = Some(start_time.0 + 3600);
+    let expected = [txs[2].id(&chain_id), txs[3].id(&chain_id)];
+    for direction in [PageDirection::Forward, PageDirection::Backward] {
+        let request = PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction,
+        };
+        let response = context
+            .client
+            .transactions_in_time_range(range_start, range_end, request)
+            .await
+            .unwrap();
+        let transactions = response
+            .results
+            .iter()
+            .map(|tx| tx.transaction.id(&chain_id))
+            .filter(|id| txs.iter().any(|tx| &tx.id(&chain_id) == id))
+            .sorted()
+            .collect_vec();
+        assert_eq!(
+            transactions,
+            expected.iter().copied().sorted().collect_vec()
+        );
+        assert!(!response.has_next_page);
+    }
 
-    let all_transactions_forward = PaginationRequest {
+    // Without the end of the range, the later blocks are included
+    let request = PaginationRequest {
         cursor: None,
         results: 10,
         direction: PageDirection::Forward,
     };
-    let response = client
-        .transactions_by_owner(&bob, all_transactions_forward)
+    let response = context
+        .client
+        .transactions_in_time_range(range_start, None, request)
         .await
         .unwrap();
-    let transactions_forward = response
+    let transactions = response
         .results
-        .into_iter()
-        .map(|tx| {
-            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
-            tx.transaction
-        })
+        .iter()
+        .map(|tx| tx.transaction.id(&chain_id))
         .collect_vec();
-    assert_eq!(transactions_forward.len(), 5);
+    assert!(txs[2..]
+        .iter()
+        .all(|tx| transactions.contains(&tx.id(&chain_id))));
+    assert!(!transactions.contains(&txs[1].id(&chain_id)));
+}
 
-    let all_transactions_backward = PaginationRequest {
-        cursor: None,
-        results: 10,
-        direction: PageDirection::Backward,
+#[tokio::test]
+async fn committed_transaction_skips_pending_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&tx).await.unwrap();
+
+    // The pending transaction is only in the `TxPool`
+    assert!(client.transaction(&id).await.unwrap().is_some());
+    assert!(client.committed_transaction(&id).await.unwrap().is_none());
+
+    client.produce_blocks(1, None).await.unwrap();
+    let committed = client
+        .committed_transaction(&id)
+        .await
+        .unwrap()
+        .expect("The transaction should be committed");
+    assert_eq!(
+        committed
+            .transaction
+            .id(&ConsensusParameters::DEFAULT.chain_id),
+        id
+    );
+    assert!(matches!(
+        committed.status,
+        TransactionStatus::Success { .. }
+    ));
+}
+
+#[tokio::test]
+async fn transaction_receipts_match_receipts_of_transaction() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let receipts = client
+        .transaction_receipts(&id)
+        .await
+        .unwrap()
+        .expect("The transaction should be committed");
+    let expected = client.receipts(&id).await.unwrap().unwrap();
+    assert!(!receipts.is_empty());
+    assert_eq!(receipts, expected);
+
+    let unknown = TxId::from([1; 32]);
+    assert!(client
+        .transaction_receipts(&unknown)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn get_transactions_never_returns_pending_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+
+    let new_tx = |gas_price| {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .gas_limit(10_000)
+            .gas_price(gas_price)
+            .add_random_fee_input()
+            .finalize_as_transaction()
     };
-    let response = client
-        .transactions_by_owner(&bob, all_transactions_backward)
-        .await;
-    // Backward request is not supported right now.
-    assert!(response.is_err());
+    let committed = new_tx(1);
+    client.submit(&committed).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    // The pending transaction would be the first by the gas price
+    let pending = new_tx(100);
+    client.submit(&pending).await.unwrap();
 
-    ///////////////// Iteration
+    for order in [TransactionOrder::BlockOrder, TransactionOrder::GasPriceDesc] {
+        let request = PaginationRequest {
+            cursor: None,
+            results: 100,
+            direction: PageDirection::Forward,
+        };
+        let transactions = client
+            .transactions_ordered_by(order, request)
+            .await
+            .unwrap()
+            .results;
 
-    let forward_iter_three = PaginationRequest {
+        assert!(transactions.iter().all(|tx| matches!(
+            tx.status,
+            TransactionStatus::Success { .. } | TransactionStatus::Failure { .. }
+        )));
+        let ids = transactions
+            .iter()
+            .map(|tx| tx.transaction.id(&chain_id))
+            .collect_vec();
+        assert!(ids.contains(&committed.id(&chain_id)), "{order:?}");
+        assert!(!ids.contains(&pending.id(&chain_id)), "{order:?}");
+    }
+}
+
+#[tokio::test]
+async fn get_transactions_as_cbor_equals_json() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, bob, 1).await.unwrap();
+    context.transfer(bob, alice, 2).await.unwrap();
+
+    let page_request = PaginationRequest {
         cursor: None,
-        results: 3,
+        results: 10,
         direction: PageDirection::Forward,
     };
-    let response_after_iter_three = client
-        .transactions_by_owner(&bob, forward_iter_three)
+    let json = context
+        .client
+        .transactions(page_request.clone())
         .await
         .unwrap();
-    let transactions_forward_iter_three = response_after_iter_three
-        .results
-        .into_iter()
-        .map(|tx| {
-            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
-            tx.transaction
-        })
-        .collect_vec();
-    assert_eq!(transactions_forward_iter_three.len(), 3);
-    assert_eq!(transactions_forward_iter_three[0], transactions_forward[0]);
-    assert_eq!(transactions_forward_iter_three[1], transactions_forward[1]);
-    assert_eq!(transactions_forward_iter_three[2], transactions_forward[2]);
-
-    let forward_iter_next_two = PaginationRequest {
-        cursor: response_after_iter_three.cursor.clone(),
-        results: 2,
-        direction: PageDirection::Forward,
-    };
-    let response = client
-        .transactions_by_owner(&bob, forward_iter_next_two)
+    let cbor = context
+        .client
+        .transactions_cbor(page_request)
         .await
         .unwrap();
-    let transactions_forward_iter_next_two = response
-        .results
-        .into_iter()
-        .map(|tx| {
-            assert!(matches!(tx.status, TransactionStatus::Success { .. }));
-            tx.transaction
-        })
-        .collect_vec();
-    assert_eq!(
-        transactions_forward_iter_next_two[0],
-        transactions_forward[3]
-    );
+
+    assert!(!cbor.results.is_empty());
     assert_eq!(
-        transactions_forward_iter_next_two[1],
-        transactions_forward[4]
+        serde_json::to_value(&cbor.results).unwrap(),
+        serde_json::to_value(&json.results).unwrap()
     );
+    assert_eq!(cbor.cursor, json.cursor);
+    assert_eq!(cbor.has_next_page, json.has_next_page);
+    assert_eq!(cbor.has_previous_page, json.has_previous_page);
 }
 
 #[tokio::test]
-async fn get_transactions_from_manual_blocks() {
-    let (executor, db) = get_executor_and_db();
-    // get access to a client
-    let context = initialize_client(db).await;
+async fn get_transactions_page_is_cut_short_by_byte_budget() {
+    const SCRIPT_DATA_SIZE: usize = 10_000;
+    let mut config = Config::local_node();
+    // Fits two transactions with the script data, but not three
+    config.max_page_bytes = Some(SCRIPT_DATA_SIZE * 2 + SCRIPT_DATA_SIZE / 2);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
 
-    // create 10 txs
-    let txs: Vec<Transaction> = (0..10).map(create_mock_tx).collect();
+    let mut submitted = vec![];
+    for _ in 0..5 {
+        let tx = TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+            vec![0xAB; SCRIPT_DATA_SIZE],
+        )
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        submitted.push(tx.id(&chain_id));
+    }
 
-    // make 1st test block
-    let first_test_block = PartialFuelBlock {
-        header: PartialBlockHeader {
-            consensus: ConsensusHeader {
-                height: 1u32.into(),
-                time: Tai64::now(),
-                ..Default::default()
-            },
-            ..Default::default()
-        },
+    let mut transactions = vec![];
+    let mut cursor = None;
+    loop {
+        let request = PaginationRequest {
+            cursor,
+            results: 10,
+            direction: PageDirection::Forward,
+        };
+        let response = client.transactions(request).await.unwrap();
+        // The count allows all the transactions with the mints, the byte budget
+        // cuts the page after two large transactions
+        let large = response
+            .results
+            .iter()
+            .filter(|tx| submitted.contains(&tx.transaction.id(&chain_id)))
+            .count();
+        assert!(large <= 2);
+        if transactions.is_empty() {
+            assert!(response.has_next_page);
+        }
+        transactions.extend(response.results);
+        if !response.has_next_page {
+            break
+        }
+        cursor = response.cursor;
+    }
 
-        // set the first 5 ids of the manually saved txs
-        transactions: txs.iter().take(5).cloned().collect(),
-    };
+    let ids = transactions
+        .iter()
+        .map(|tx| tx.transaction.id(&chain_id))
+        .collect_vec();
+    assert!(submitted.iter().all(|id| ids.contains(id)));
+}
 
-    // make 2nd test block
-    let second_test_block = PartialFuelBlock {
-        header: PartialBlockHeader {
-            consensus: ConsensusHeader {
-                height: 2u32.into(),
-                time: Tai64::now(),
+#[tokio::test]
+async fn get_transactions_ordered_by_gas_price() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+
+    let mut submitted = vec![];
+    for gas_price in [3, 7, 1, 5] {
+        let tx =
+            TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+                .gas_price(gas_price)
+                .gas_limit(10_000)
+                .add_random_fee_input()
+                .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        submitted.push((tx.id(&chain_id), gas_price));
+    }
+
+    // The pages are taken from the same sorted window
+    let mut transactions = vec![];
+    let mut cursor = None;
+    loop {
+        let request = PaginationRequest {
+            cursor,
+            results: 3,
+            direction: PageDirection::Forward,
+        };
+        let response = client
+            .transactions_ordered_by(TransactionOrder::GasPriceDesc, request)
+            .await
+            .unwrap();
+        transactions.extend(response.results);
+        if !response.has_next_page {
+            break
+        }
+        cursor = response.cursor;
+    }
+
+    // The `Mint` transactions have no gas price and come last
+    let gas_prices = transactions
+        .iter()
+        .filter_map(|tx| {
+            let id = tx.transaction.id(&chain_id);
+            submitted
+                .iter()
+                .find(|(submitted_id, _)| *submitted_id == id)
+                .map(|(_, gas_price)| *gas_price)
+        })
+        .collect_vec();
+    assert_eq!(gas_prices, vec![7, 5, 3, 1]);
+    assert!(transactions[4..].iter().all(|tx| tx.transaction.is_mint()));
+}
+
+#[tokio::test]
+async fn get_transactions_with_gzip_response() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let txs: Vec<Transaction> = (0..20).map(create_mock_tx).collect();
+    for tx in &txs {
+        client.submit_and_await_commit(tx).await.unwrap();
+    }
+
+    let query = serde_json::json!({
+        "query": concat!(
+            "{ transactions(first: 100) ",
+            "{ nodes { id rawPayload receipts { receiptType gasUsed } } } }"
+        )
+    });
+    let http = reqwest::Client::new();
+    let response = http
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .header("Accept-Encoding", "gzip")
+        .body(query.to_string())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["content-encoding"], "gzip");
+
+    let compressed = response.bytes().await.unwrap();
+    let mut body = String::new();
+    flate2::read::GzDecoder::new(&compressed[..])
+        .read_to_string(&mut body)
+        .unwrap();
+    assert!(body.len() > compressed.len());
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let nodes = body["data"]["transactions"]["nodes"].as_array().unwrap();
+    for tx in &txs {
+        let id = format!("{:#x}", tx.id(&ConsensusParameters::DEFAULT.chain_id));
+        assert!(nodes.iter().any(|node| node["id"] == id.as_str()));
+    }
+
+    // The subscriptions are streamed without the compression
+    let id = format!("{:#x}", txs[0].id(&ConsensusParameters::DEFAULT.chain_id));
+    let subscription = serde_json::json!({
+        "query": format!("subscription {{ statusChange(id: \"{id}\") {{ __typename }} }}")
+    });
+    let response = http
+        .post(format!("http://{}/graphql-sub", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .header("Accept-Encoding", "gzip")
+        .body(subscription.to_string())
+        .send()
+        .await
+        .unwrap();
+    assert!(response.headers().get("content-encoding").is_none());
+}
+
+#[tokio::test]
+async fn get_transactions_in_block_forward_and_backward() {
+    let (executor, db) = get_executor_and_db();
+    let context = initialize_client(db).await;
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+
+    // The first block contains 5 transactions and the second one contains 3
+    let txs: Vec<Transaction> = (0..8).map(create_mock_tx).collect();
+    for (height, txs) in [(1u32, &txs[..5]), (2u32, &txs[5..])] {
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: height.into(),
+                    time: Tai64::now(),
+                    ..Default::default()
+                },
                 ..Default::default()
             },
-            ..Default::default()
-        },
-        // set the last 5 ids of the manually saved txs
-        transactions: txs.iter().skip(5).take(5).cloned().collect(),
-    };
+            transactions: txs.to_vec(),
+        };
+        executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+    }
+    let expected = context
+        .client
+        .block_by_height(1)
+        .await
+        .unwrap()
+        .unwrap()
+        .transactions;
+    // The coinbase transaction and 5 mock transactions
+    assert_eq!(expected.len(), 6);
 
-    // process blocks and save block height
-    executor
-        .execute_and_commit(
-            ExecutionBlock::Production(first_test_block),
-            Default::default(),
-        )
-        .unwrap();
-    executor
-        .execute_and_commit(
-            ExecutionBlock::Production(second_test_block),
-            Default::default(),
+    // Page forward through the first block, 4 transactions at a time
+    let first_page = context
+        .client
+        .transactions_in_block(
+            1,
+            PaginationRequest {
+                cursor: None,
+                results: 4,
+                direction: PageDirection::Forward,
+            },
         )
+        .await
         .unwrap();
-
-    // Query for first 4: [coinbase_tx1, 0, 1, 2]
-    let page_request_forwards = PaginationRequest {
-        cursor: None,
-        results: 4,
-        direction: PageDirection::Forward,
-    };
-    let response = context
+    assert!(first_page.has_next_page);
+    assert!(!first_page.has_previous_page);
+    let second_page = context
         .client
-        .transactions(page_request_forwards)
+        .transactions_in_block(
+            1,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 4,
+                direction: PageDirection::Forward,
+            },
+        )
         .await
         .unwrap();
-    let transactions = &response
+    assert!(!second_page.has_next_page);
+    let forward = first_page
         .results
         .iter()
-        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .chain(second_page.results.iter())
+        .map(|tx| tx.transaction.id(&chain_id))
         .collect_vec();
-    // coinbase_tx1
-    assert_eq!(
-        transactions[1],
-        txs[0].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[2],
-        txs[1].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[3],
-        txs[2].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
+    assert_eq!(forward, expected);
 
-    // Query forwards from last given cursor [2]: [3, 4, coinbase_tx2, 5, 6]
-    let next_page_request_forwards = PaginationRequest {
-        cursor: response.cursor,
-        results: 5,
-        direction: PageDirection::Forward,
-    };
-    let response = context
+    // Page backward from the end of the first page
+    let backward = context
         .client
-        .transactions(next_page_request_forwards)
+        .transactions_in_block(
+            1,
+            PaginationRequest {
+                cursor: first_page.cursor,
+                results: 4,
+                direction: PageDirection::Backward,
+            },
+        )
         .await
-        .unwrap();
-    let transactions = &response
+        .unwrap()
         .results
         .iter()
-        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
+        .map(|tx| tx.transaction.id(&chain_id))
         .collect_vec();
-    assert_eq!(
-        transactions[0],
-        txs[3].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[1],
-        txs[4].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    // coinbase_tx2
-    assert_eq!(
-        transactions[3],
-        txs[5].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[4],
-        txs[6].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
+    let expected_backward = expected[..3].iter().rev().copied().collect_vec();
+    assert_eq!(backward, expected_backward);
 
-    // Query backwards from last given cursor [8]: [5, coinbase_tx2, 4, 3, 2, 1, 0, coinbase_tx1]
-    let page_request_backwards = PaginationRequest {
-        cursor: response.cursor,
-        results: 10,
-        direction: PageDirection::Backward,
-    };
-    let response = context
+    // The transactions of the second block are not mixed with the first one
+    let second_block = context
         .client
-        .transactions(page_request_backwards)
+        .transactions_in_block(
+            2,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Backward,
+            },
+        )
         .await
         .unwrap();
-    let transactions = &response
-        .results
-        .iter()
-        .map(|tx| tx.transaction.id(&ConsensusParameters::DEFAULT.chain_id))
-        .collect_vec();
-    assert_eq!(
-        transactions[0],
-        txs[5].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    // transactions[1] coinbase_tx2
-    assert_eq!(
-        transactions[2],
-        txs[4].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
+    assert_eq!(second_block.results.len(), 4);
     assert_eq!(
-        transactions[3],
-        txs[3].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[4],
-        txs[2].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[5],
-        txs[1].id(&ConsensusParameters::DEFAULT.chain_id)
-    );
-    assert_eq!(
-        transactions[6],
-        txs[0].id(&ConsensusParameters::DEFAULT.chain_id)
+        second_block.results[0].transaction.id(&chain_id),
+        txs[7].id(&chain_id)
     );
-    // transactions[7] coinbase_tx1
 }
 
 #[tokio::test]
@@ -691,6 +4339,76 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn get_owned_transactions_with_owner_roles() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    // Bob sends the coin to Charlie and receives the change
+    let tx2: Transaction = Transaction::script(
+        Default::default(),
+        1_000_000,
+        Default::default(),
+        op::ret(0x10).to_bytes().to_vec(),
+        vec![],
+        vec![Input::coin_signed(
+            context.rng.gen(),
+            bob,
+            10,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )],
+        vec![
+            Output::coin(charlie, 2, Default::default()),
+            Output::change(bob, 0, Default::default()),
+        ],
+        vec![vec![].into()],
+    )
+    .into();
+    context.client.submit_and_await_commit(&tx2).await.unwrap();
+    let tx2 = tx2.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let client = context.client;
+    let page_request = PaginationRequest {
+        cursor: None,
+        results: 5,
+        direction: PageDirection::Forward,
+    };
+    let roles = |owner: Address| {
+        let client = &client;
+        let page_request = page_request.clone();
+        async move {
+            client
+                .transactions_by_owner_with_roles(&owner, page_request)
+                .await
+                .unwrap()
+                .results
+                .into_iter()
+                .map(|tx| {
+                    (
+                        tx.transaction
+                            .transaction
+                            .id(&ConsensusParameters::DEFAULT.chain_id),
+                        tx.owner_role,
+                    )
+                })
+                .collect_vec()
+        }
+    };
+
+    assert_eq!(roles(alice).await, vec![(tx1, OwnerRole::Sender)]);
+    assert_eq!(roles(bob).await, vec![(tx2, OwnerRole::Both)]);
+    assert_eq!(
+        roles(charlie).await,
+        vec![(tx1, OwnerRole::Recipient), (tx2, OwnerRole::Recipient)]
+    );
+}
+
 impl TestContext {
     async fn transfer(
         &mut self,
@@ -758,3 +4476,356 @@ fn create_mock_tx(val: u64) -> Transaction {
         .add_random_fee_input()
         .finalize_as_transaction()
 }
+
+// spend the coin with the always-true predicate, so the transactions can collide
+fn predicate_coin(utxo_id: UtxoId) -> Input {
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    Input::coin_predicate(
+        utxo_id,
+        owner,
+        1000,
+        AssetId::BASE,
+        TxPointer::default(),
+        Default::default(),
+        Default::default(),
+        predicate,
+        vec![],
+    )
+}
+
+fn transaction_spending_coin(utxo_id: UtxoId, gas_price: u64) -> Transaction {
+    TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_price(gas_price)
+        .gas_limit(10_000)
+        .add_input(predicate_coin(utxo_id))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn dry_run_many_executes_in_submitted_order() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = true;
+    let secret_key: SecretKey = rng.gen();
+    let owner = Input::owner(&secret_key.public_key());
+    let amount = 1000;
+
+    // `tx2` spends the output of `tx1`
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret_key,
+            rng.gen(),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&tx1]);
+    let tx1: Transaction = tx1.into();
+    let tx2: Transaction = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret_key,
+            UtxoId::new(tx1.id(&ConsensusParameters::DEFAULT.chain_id), 0),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(rng.gen(), amount, AssetId::BASE))
+        .finalize_as_transaction();
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let results = client
+        .dry_run_many(&[tx1.clone(), tx2.clone()], None, None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results
+        .iter()
+        .all(|receipts| matches!(receipts.last(), Some(Receipt::ScriptResult { .. }))));
+
+    // The spender can't be executed before the transaction it depends on
+    let result = client
+        .dry_run_many(&[tx2.clone(), tx1.clone()], None, None)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn dry_run_many_sorts_out_of_order_batch_by_dependencies() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = true;
+    let secret_key: SecretKey = rng.gen();
+    let owner = Input::owner(&secret_key.public_key());
+    let amount = 1000;
+    let returning = |val: u32| -> Vec<u8> {
+        [op::movi(0x10, val), op::ret(0x10)]
+            .iter()
+            .flat_map(|op| u32::from(*op).to_be_bytes())
+            .collect()
+    };
+
+    // `tx2` spends the output of `tx1` and `tx3` spends the output of `tx2`
+    let tx1 = TransactionBuilder::script(returning(1), vec![])
+        .gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret_key,
+            rng.gen(),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&tx1]);
+    let tx1: Transaction = tx1.into();
+    let spender = |parent: &Transaction, script: Vec<u8>| -> Transaction {
+        TransactionBuilder::script(script, vec![])
+            .gas_limit(10_000)
+            .add_unsigned_coin_input(
+                secret_key,
+                UtxoId::new(parent.id(&ConsensusParameters::DEFAULT.chain_id), 0),
+                amount,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::coin(owner, amount, AssetId::BASE))
+            .finalize_as_transaction()
+    };
+    let tx2 = spender(&tx1, returning(2));
+    let tx3 = spender(&tx2, returning(3));
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let batch = [tx3.clone(), tx1.clone(), tx2.clone()];
+    let result = client.dry_run_many(&batch, None, Some(false)).await;
+    assert!(result.is_err());
+
+    let results = client.dry_run_many(&batch, None, Some(true)).await.unwrap();
+
+    // The receipts are returned in the submitted order
+    let returned = |receipts: &[Receipt]| {
+        receipts.iter().find_map(|receipt| match receipt {
+            Receipt::Return { val, .. } => Some(*val),
+            _ => None,
+        })
+    };
+    assert_eq!(results.len(), 3);
+    assert_eq!(returned(&results[0]), Some(3));
+    assert_eq!(returned(&results[1]), Some(1));
+    assert_eq!(returned(&results[2]), Some(2));
+}
+
+#[tokio::test]
+async fn dry_run_parallel_transactions_see_the_original_state() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![
+        // Log and increment the slot with the key equal to the contract id
+        op::srw(0x10, 0x11, RegId::FP),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::addi(0x10, 0x10, 1),
+        op::sww(RegId::FP, 0x11, 0x10),
+        op::ret(RegId::ONE),
+    ];
+    let contract_code: Vec<u8> = contract_code
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, None, None, None);
+    let mut slot = [0u8; 32];
+    slot[..8].copy_from_slice(&7u64.to_be_bytes());
+    test_builder.contracts.get_mut(&contract_id).unwrap().state =
+        Some(vec![(Bytes32::new(*contract_id), Bytes32::new(slot))]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script: Vec<u8> = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .iter()
+    .flat_map(|op| u32::from(*op).to_be_bytes())
+    .collect();
+    let mut increment = || {
+        TransactionBuilder::script(
+            script.clone(),
+            Call::new(contract_id, 0, 0).to_bytes(),
+        )
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction()
+    };
+    let batch = [increment(), increment()];
+
+    let logged = |receipts: &[Receipt]| {
+        receipts.iter().find_map(|receipt| match receipt {
+            Receipt::Log { ra, .. } => Some(*ra),
+            _ => None,
+        })
+    };
+
+    // Both transactions read the original value of the slot
+    let results = client.dry_run_parallel(&batch, Some(false)).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(logged(&results[0]), Some(7));
+    assert_eq!(logged(&results[1]), Some(7));
+
+    // While in `dry_run_many` the second one sees the write of the first one
+    let results = client
+        .dry_run_many(&batch, Some(false), None)
+        .await
+        .unwrap();
+    assert_eq!(logged(&results[0]), Some(7));
+    assert_eq!(logged(&results[1]), Some(8));
+}
+
+#[tokio::test]
+async fn simulate_block_excludes_transactions_over_gas_limit() {
+    use fuel_core_client::client::types::SimulatedTransactionStatus;
+
+    let mut config = Config::local_node();
+    config.chain_conf.block_gas_limit = 250_000;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Only two of the transactions fit into the block
+    let txs: Vec<Transaction> = (0..3)
+        .map(|_| {
+            TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+                .gas_limit(100_000)
+                .add_random_fee_input()
+                .finalize_as_transaction()
+        })
+        .collect();
+    let height_before = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    let simulation = client.simulate_block(&txs, None).await.unwrap();
+
+    assert_eq!(simulation.height, height_before + 1);
+    let statuses: Vec<_> = simulation.transactions.iter().map(|tx| tx.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            SimulatedTransactionStatus::Success,
+            SimulatedTransactionStatus::Success,
+            SimulatedTransactionStatus::Excluded,
+        ]
+    );
+    let ids: Vec<_> = simulation.transactions.iter().map(|tx| tx.id).collect();
+    let expected_ids: Vec<_> = txs
+        .iter()
+        .map(|tx| tx.id(&ConsensusParameters::DEFAULT.chain_id))
+        .collect();
+    assert_eq!(ids, expected_ids);
+    assert!(!simulation.transactions[0].receipts.is_empty());
+    assert!(simulation.transactions[2].receipts.is_empty());
+    assert!(simulation.gas_used > 0);
+
+    // The simulated block isn't committed
+    let height_after = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert_eq!(height_after, height_before);
+}
+
+#[tokio::test]
+async fn simulate_block_skips_repeated_transaction() {
+    use fuel_core_client::client::types::SimulatedTransactionStatus;
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(100_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let txs = vec![tx.clone(), tx];
+
+    let simulation = client.simulate_block(&txs, None).await.unwrap();
+
+    let statuses: Vec<_> = simulation.transactions.iter().map(|tx| tx.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            SimulatedTransactionStatus::Success,
+            SimulatedTransactionStatus::Skipped,
+        ]
+    );
+    assert!(!simulation.transactions[0].receipts.is_empty());
+    assert!(simulation.transactions[1].receipts.is_empty());
+}
+
+#[tokio::test]
+async fn simulate_block_rejects_height_other_than_next() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(100_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let next_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height
+        + 1;
+
+    let simulation = client
+        .simulate_block(&[tx.clone()], Some(next_height))
+        .await
+        .unwrap();
+    assert_eq!(simulation.height, next_height);
+
+    let err = client
+        .simulate_block(&[tx], Some(next_height + 5))
+        .await
+        .expect_err("Only the next block height can be simulated");
+    assert!(
+        err.to_string().contains("Only the next block height"),
+        "got unexpected error {err}"
+    );
+}
@@ -1,8 +1,19 @@
 // Tests related to the predicate execution feature
 
 use crate::helpers::TestSetupBuilder;
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
     fuel_asm::*,
+    fuel_crypto::{
+        Message,
+        PublicKey,
+        SecretKey,
+        Signature,
+    },
     fuel_tx::{
         field::{
             Inputs,
@@ -165,3 +176,345 @@ async fn transaction_with_predicates_that_exhaust_gas_limit_are_rejected() {
         "got unexpected error {err}"
     )
 }
+
+#[tokio::test]
+async fn predicates_valid_reports_each_predicate_input() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let asset_id = rng.gen();
+    let valid_predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let invalid_predicate = op::ret(RegId::ZERO).to_bytes().to_vec();
+    let predicate_input = |rng: &mut StdRng, predicate: Vec<u8>| {
+        let owner =
+            Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+        Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        )
+    };
+    let predicate_tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(predicate_input(&mut rng, valid_predicate.clone()))
+        .add_input(predicate_input(&mut rng, invalid_predicate))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    let context = TestSetupBuilder::default().finalize().await;
+
+    let validation = context
+        .client
+        .predicates_valid(&predicate_tx)
+        .await
+        .unwrap();
+    assert!(!validation.all_valid);
+    assert_eq!(validation.per_input.len(), 2);
+    assert_eq!(validation.per_input[0].index, 0);
+    assert!(validation.per_input[0].valid);
+    assert_eq!(validation.per_input[1].index, 1);
+    assert!(!validation.per_input[1].valid);
+
+    let valid_tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(predicate_input(&mut rng, valid_predicate))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+    let validation = context.client.predicates_valid(&valid_tx).await.unwrap();
+    assert!(validation.all_valid);
+    assert_eq!(validation.per_input.len(), 1);
+}
+
+#[tokio::test]
+async fn estimate_predicates_and_fees_reports_predicate_and_signature_gas() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let asset_id = rng.gen();
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let secret_key: SecretKey = rng.gen();
+    let tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_unsigned_coin_input(
+            secret_key,
+            rng.gen(),
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+        )
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    let context = TestSetupBuilder::default().finalize().await;
+
+    let estimate = context
+        .client
+        .estimate_predicates_and_fees(&tx)
+        .await
+        .unwrap();
+
+    let mut expected = tx.clone();
+    expected
+        .estimate_predicates(&ConsensusParameters::DEFAULT, &GasCosts::default())
+        .expect("Predicate check failed");
+    assert_eq!(estimate.transaction, expected);
+    let predicate_gas = match &expected {
+        Transaction::Script(script) => match &script.inputs()[1] {
+            Input::CoinPredicate(coin) => coin.predicate_gas_used,
+            input => panic!("unexpected input {input:?}"),
+        },
+        _ => unreachable!(),
+    };
+    assert_ne!(predicate_gas, 0);
+    assert_eq!(estimate.predicate_gas, predicate_gas);
+    assert_eq!(estimate.signature_gas, GasCosts::default().eck1);
+    assert_ne!(estimate.signature_gas, 0);
+    assert_eq!(
+        estimate.verification_gas,
+        estimate.predicate_gas + estimate.signature_gas
+    );
+}
+
+#[tokio::test]
+async fn service_estimate_predicates_matches_graphql() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let predicate = [
+        op::addi(0x10, RegId::ZERO, 5),
+        op::addi(0x11, 0x10, 1),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let asset_id = rng.gen();
+    let tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    let mut in_process = tx.clone();
+    srv.estimate_predicates(&mut in_process).unwrap();
+    let mut over_graphql = tx;
+    client.estimate_predicates(&mut over_graphql).await.unwrap();
+
+    assert_ne!(
+        in_process.as_script().unwrap().inputs()[0].predicate_gas_used(),
+        Some(0)
+    );
+    assert_eq!(in_process, over_graphql);
+}
+
+#[tokio::test]
+async fn estimate_predicates_stops_predicate_exceeding_gas_limit() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let mut config = Config::local_node();
+    config.estimate_predicates_gas_limit = Some(10_000);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let asset_id = rng.gen();
+    let predicate_input = |rng: &mut StdRng, predicate: Vec<u8>| {
+        let owner =
+            Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+        Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        )
+    };
+    // The second predicate jumps in the infinite loop
+    let mut tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(predicate_input(
+            &mut rng,
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+        ))
+        .add_input(predicate_input(
+            &mut rng,
+            op::jmp(RegId::ZERO).to_bytes().to_vec(),
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    let err = client
+        .estimate_predicates(&mut tx)
+        .await
+        .expect_err("The estimation should be stopped");
+    assert!(
+        err.to_string()
+            .contains("The predicate of the input 1 exceeded the gas limit of 10000"),
+        "got unexpected error {err}"
+    );
+}
+
+#[tokio::test]
+async fn estimate_missing_predicates_keeps_provided_gas() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let asset_id = rng.gen();
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let predicate_input = |rng: &mut StdRng, predicate_gas_used: Word| {
+        Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            predicate_gas_used,
+            predicate.clone(),
+            vec![],
+        )
+    };
+    let provided_gas = 12_345;
+    let mut tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(predicate_input(&mut rng, provided_gas))
+        .add_input(predicate_input(&mut rng, 0))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    client.estimate_missing_predicates(&mut tx).await.unwrap();
+
+    let inputs = tx.as_script().unwrap().inputs();
+    assert_eq!(inputs[0].predicate_gas_used().unwrap(), provided_gas);
+    let estimated = inputs[1].predicate_gas_used().unwrap();
+    assert_ne!(estimated, 0);
+    assert_ne!(estimated, provided_gas);
+}
+
+#[tokio::test]
+async fn predicates_valid_accepts_test_witness_of_signed_message() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let secret = SecretKey::random(&mut rng);
+    let message = Message::new(b"off-chain order");
+    // The predicate data is the public key of the signer and the signed message
+    let predicate_data = [secret.public_key().as_ref(), message.as_ref()].concat();
+    // The predicate recovers the public key from the signature in the first witness
+    // and compares it with the public key from the predicate data
+    let predicate: Vec<u8> = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::InputCoinPredicateData),
+        op::gtf_args(0x11, RegId::ZERO, GTFArgs::WitnessData),
+        op::movi(0x12, PublicKey::LEN as u32),
+        op::aloc(0x12),
+        op::addi(0x13, 0x10, PublicKey::LEN as u16),
+        op::eck1(RegId::HP, 0x11, 0x13),
+        op::meq(0x14, RegId::HP, 0x10, 0x12),
+        op::ret(0x14),
+    ]
+    .into_iter()
+    .collect();
+    let owner =
+        Input::predicate_owner(&predicate, &ConsensusParameters::DEFAULT.chain_id);
+    let asset_id = rng.gen();
+    let tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            500,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            predicate_data,
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .gas_limit(1000)
+        .finalize_as_transaction();
+
+    let mut config = Config::local_node();
+    config.test_witnesses_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The transaction has no signature, so the predicate fails
+    let validation = client.predicates_valid(&tx).await.unwrap();
+    assert!(!validation.all_valid);
+
+    // The injected signature of the message makes the predicate valid
+    let signature = Signature::sign(&secret, &message);
+    let validation = client
+        .predicates_valid_with_test_witnesses(&tx, &[(0, signature.as_ref().to_vec())])
+        .await
+        .unwrap();
+    assert!(validation.all_valid);
+
+    // The signature of the other key is still rejected
+    let other_signature = Signature::sign(&SecretKey::random(&mut rng), &message);
+    let validation = client
+        .predicates_valid_with_test_witnesses(
+            &tx,
+            &[(0, other_signature.as_ref().to_vec())],
+        )
+        .await
+        .unwrap();
+    assert!(!validation.all_valid);
+}
+
+#[tokio::test]
+async fn test_witnesses_are_rejected_unless_enabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let test_witnesses = [(0, vec![0; 64])];
+    let result = client
+        .dry_run_with_test_witnesses(&tx, Some(false), &test_witnesses)
+        .await;
+    assert!(result.is_err());
+    let result = client
+        .predicates_valid_with_test_witnesses(&tx, &test_witnesses)
+        .await;
+    assert!(result.is_err());
+}
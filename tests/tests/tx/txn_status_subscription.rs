@@ -233,3 +233,315 @@ fn submit_and_await_model(tx: &Transaction) -> bool {
         _ => true,
     }
 }
+
+#[tokio::test]
+async fn subscribe_owner_transactions_receives_payment_to_owner() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let owner = Address::from([5; 32]);
+    let chain_id = ConsensusParameters::DEFAULT.chain_id;
+
+    let transactions = client.subscribe_owner_transactions(&owner).await.unwrap();
+    futures::pin_mut!(transactions);
+
+    // The subscription is established in the background, so the payments
+    // are repeated until one of them arrives.
+    let mut submitted = vec![];
+    let received = loop {
+        assert!(submitted.len() < 10, "The payment wasn't received");
+        let tx =
+            TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+                .gas_limit(10_000)
+                .add_input(Input::coin_signed(
+                    UtxoId::new([submitted.len() as u8; 32].into(), 0),
+                    Default::default(),
+                    1000,
+                    AssetId::BASE,
+                    Default::default(),
+                    0,
+                    Default::default(),
+                ))
+                .add_witness(Default::default())
+                .add_output(Output::coin(owner, 1000, AssetId::BASE))
+                .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        submitted.push(tx.id(&chain_id));
+
+        let next = tokio::time::timeout(Duration::from_millis(500), transactions.next());
+        if let Ok(received) = next.await {
+            break received.unwrap().unwrap()
+        }
+    };
+
+    assert!(submitted.contains(&received.transaction.id(&chain_id)));
+    assert!(matches!(
+        received.status,
+        fuel_core_client::client::types::TransactionStatus::Success { .. }
+    ));
+}
+
+#[tokio::test]
+async fn status_change_of_unknown_transaction_ends_after_grace_period() {
+    let mut config = Config::local_node();
+    config.tx_status_grace_period = Some(Duration::from_millis(500));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let stream = srv.transaction_status_change(rng.gen()).await;
+    let statuses =
+        tokio::time::timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+            .await
+            .expect("The stream should end after the grace period");
+
+    assert_eq!(statuses.len(), 1);
+    let err = statuses.into_iter().next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("will not be processed"), "{err}");
+}
+
+#[tokio::test]
+async fn status_change_is_rejected_above_subscription_limit() {
+    let mut config = Config::local_node();
+    config.txpool.number_of_active_subscription = 2;
+    config.txpool.subscription_idle_timeout = Duration::from_secs(60);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let _first = srv.transaction_status_change(rng.gen()).await;
+    let _second = srv.transaction_status_change(rng.gen()).await;
+    let rejected = srv.transaction_status_change(rng.gen()).await;
+    let statuses =
+        tokio::time::timeout(Duration::from_secs(5), rejected.collect::<Vec<_>>())
+            .await
+            .expect("The rejected stream should end immediately");
+
+    assert_eq!(statuses.len(), 1);
+    let err = statuses.into_iter().next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("Too many subscriptions"), "{err}");
+}
+
+#[tokio::test]
+async fn idle_status_change_is_evicted_above_subscription_limit() {
+    let mut config = Config::local_node();
+    config.txpool.number_of_active_subscription = 2;
+    // Every subscription without updates is idle at once
+    config.txpool.subscription_idle_timeout = Duration::ZERO;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let first = srv.transaction_status_change(rng.gen()).await;
+    let _second = srv.transaction_status_change(rng.gen()).await;
+    let _third = srv.transaction_status_change(rng.gen()).await;
+
+    // The least recently updated subscription is evicted for the new one
+    let statuses =
+        tokio::time::timeout(Duration::from_secs(5), first.collect::<Vec<_>>())
+            .await
+            .expect("The evicted stream should end");
+    assert_eq!(statuses.len(), 1);
+    let err = statuses.into_iter().next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("evicted"), "{err}");
+}
+
+#[tokio::test]
+async fn submitted_status_reports_age_at_emission() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&tx).await.unwrap();
+
+    let submitted_status = || async {
+        let stream = client.subscribe_transaction_status(&id).await.unwrap();
+        futures::pin_mut!(stream);
+        match stream.next().await.unwrap().unwrap() {
+            fuel_core_client::client::types::TransactionStatus::Submitted {
+                age_seconds,
+                queue_position,
+                ..
+            } => (age_seconds, queue_position),
+            status => panic!("Unexpected status {status:?}"),
+        }
+    };
+
+    let (first_age, queue_position) = submitted_status().await;
+    assert_eq!(queue_position, Some(0));
+
+    // The time of the submission has the precision of seconds
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let (second_age, _) = submitted_status().await;
+    assert!(second_age > first_age, "{second_age} <= {first_age}");
+}
+
+#[tokio::test]
+async fn idle_subscription_receives_keep_alive_messages() {
+    let mut config = Config::local_node();
+    config.subscription_keep_alive_interval = Duration::from_millis(100);
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // Nothing is sent to the owner, so the subscription stays idle
+    let owner = format!("{:#x}", Address::from([1; 32]));
+    let subscription = serde_json::json!({
+        "query": format!("subscription {{ ownerTransactions(owner: \"{owner}\") {{ id }} }}")
+    });
+    let mut response = reqwest::Client::new()
+        .post(format!("http://{}/graphql-sub", srv.bound_address))
+        .header("Content-Type", "application/json")
+        .body(subscription.to_string())
+        .send()
+        .await
+        .unwrap();
+
+    let keep_alives = async {
+        let mut keep_alives = 0;
+        while keep_alives < 3 {
+            let chunk = response
+                .chunk()
+                .await
+                .unwrap()
+                .expect("The subscription ended");
+            let chunk = String::from_utf8_lossy(&chunk);
+            assert!(!chunk.contains("data:"), "got unexpected event {chunk}");
+            keep_alives += chunk.matches(":keep-alive-text").count();
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), keep_alives)
+        .await
+        .expect("The keep-alive messages should be sent during the idle subscription");
+}
+
+#[tokio::test]
+async fn resumed_status_subscription_replays_missed_statuses() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&tx).await.unwrap();
+
+    // The first subscription is dropped after the `Submitted` status
+    let cursor = {
+        let stream = client.subscribe_transaction_status(&id).await.unwrap();
+        futures::pin_mut!(stream);
+        let status = stream.next().await.unwrap().unwrap();
+        assert!(
+            matches!(
+                status,
+                fuel_core_client::client::types::TransactionStatus::Submitted { .. }
+            ),
+            "{status:?}"
+        );
+        status.cursor().unwrap()
+    };
+
+    // The transaction is committed while nobody is subscribed
+    client.produce_blocks(1, None).await.unwrap();
+
+    let stream = client
+        .subscribe_transaction_status_from(&id, &cursor)
+        .await
+        .unwrap();
+    let statuses =
+        tokio::time::timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+            .await
+            .expect("The stream should end after the final status");
+
+    assert_eq!(statuses.len(), 1, "{statuses:?}");
+    assert!(matches!(
+        statuses[0],
+        Ok(fuel_core_client::client::types::TransactionStatus::Success { .. })
+    ));
+}
+
+#[tokio::test]
+async fn resumed_status_subscription_with_unknown_cursor_returns_current_status() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+    client.submit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The late subscriber resumes after a status the history doesn't have
+    let stream = client
+        .subscribe_transaction_status_from(&id, "SUBMITTED#0")
+        .await
+        .unwrap();
+    let statuses =
+        tokio::time::timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+            .await
+            .expect("The stream should end after the final status");
+
+    assert_eq!(statuses.len(), 1, "{statuses:?}");
+    assert!(matches!(
+        statuses[0],
+        Ok(fuel_core_client::client::types::TransactionStatus::Success { .. })
+    ));
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn resumed_status_subscription_returns_current_status_after_restart() {
+    use fuel_core::database::Database;
+    use tempfile::TempDir;
+
+    let tmp_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.block_production = fuel_core::service::config::Trigger::Never;
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .gas_limit(10_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = tx.id(&ConsensusParameters::DEFAULT.chain_id);
+
+    let cursor = {
+        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let srv = FuelService::from_database(database, config.clone())
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        client.submit(&tx).await.unwrap();
+        let stream = client.subscribe_transaction_status(&id).await.unwrap();
+        futures::pin_mut!(stream);
+        let status = stream.next().await.unwrap().unwrap();
+        client.produce_blocks(1, None).await.unwrap();
+        srv.stop_and_await().await.unwrap();
+        status.cursor().unwrap()
+    };
+
+    // The in-memory history of the `TxPool` with the `Submitted` status is lost
+    let database = Database::open(tmp_dir.path(), None).unwrap();
+    let srv = FuelService::from_database(database, config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let stream = client
+        .subscribe_transaction_status_from(&id, &cursor)
+        .await
+        .unwrap();
+    let statuses =
+        tokio::time::timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+            .await
+            .expect("The stream should end after the final status");
+
+    assert_eq!(statuses.len(), 1, "{statuses:?}");
+    assert!(matches!(
+        statuses[0],
+        Ok(fuel_core_client::client::types::TransactionStatus::Success { .. })
+    ));
+}